@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Bounded can only be derived for enums",
+            ))
+        }
+    };
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(Error::new_spanned(
+            variant,
+            "Bounded can only be derived for enums without fields",
+        ));
+    }
+    let (first, last) = match (data.variants.first(), data.variants.last()) {
+        (Some(first), Some(last)) => (&first.ident, &last.ident),
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Bounded can only be derived for enums with at least one variant",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::fp_std::bounded::Bounded for #name #ty_generics #where_clause {
+            fn min_value() -> Self {
+                #name::#first
+            }
+
+            fn max_value() -> Self {
+                #name::#last
+            }
+        }
+    })
+}
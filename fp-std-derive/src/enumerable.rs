@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Enumerable can only be derived for enums",
+            ))
+        }
+    };
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(Error::new_spanned(
+            variant,
+            "Enumerable can only be derived for enums without fields",
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+
+    let succ_arms = variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| match variants.get(i + 1) {
+            Some(next) => quote! { #name::#variant => ::core::option::Option::Some(#name::#next), },
+            None => quote! { #name::#variant => ::core::option::Option::None, },
+        });
+    let pred_arms = variants.iter().enumerate().map(|(i, variant)| {
+        match i.checked_sub(1).map(|i| variants[i]) {
+            Some(prev) => quote! { #name::#variant => ::core::option::Option::Some(#name::#prev), },
+            None => quote! { #name::#variant => ::core::option::Option::None, },
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::fp_std::enumerable::Enumerable for #name #ty_generics #where_clause {
+            fn succ(&self) -> ::core::option::Option<Self> {
+                match *self {
+                    #(#succ_arms)*
+                }
+            }
+
+            fn pred(&self) -> ::core::option::Option<Self> {
+                match *self {
+                    #(#pred_arms)*
+                }
+            }
+        }
+    })
+}
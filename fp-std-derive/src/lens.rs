@@ -0,0 +1,105 @@
+use proc_macro2::{Literal, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::{Error, Ident, Result, Type};
+
+enum Segment {
+    Field(Ident),
+    Index(usize, Span),
+}
+
+/// The type which stands for the name of a field, as a tuple of its characters, so that a
+/// field can be looked up by name at the type level.
+pub fn field_name(field: &Ident) -> TokenStream {
+    let name = field.to_string();
+    let chars = name
+        .trim_start_matches("r#")
+        .chars()
+        .map(Literal::character);
+    quote! { (#(::fp_std::lens::__Char<#chars>,)*) }
+}
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let mut tokens = input.into_iter().peekable();
+    let mut root = TokenStream::new();
+    while let Some(token) = tokens.next_if(|token| !is_dot(token)) {
+        root.extend([token]);
+    }
+    if root.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "expected a type at the start of the path",
+        ));
+    }
+    let root: Type = syn::parse2(root)?;
+
+    let mut segments = Vec::new();
+    while let Some(dot) = tokens.next() {
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) => segments.push(Segment::Field(ident)),
+            Some(TokenTree::Literal(literal)) => {
+                let span = literal.span();
+                for index in literal.to_string().split('.') {
+                    let index = index
+                        .parse()
+                        .map_err(|_| Error::new(span, "expected a field name or a tuple index"))?;
+                    segments.push(Segment::Index(index, span));
+                }
+            }
+            Some(token) => {
+                return Err(Error::new_spanned(
+                    token,
+                    "expected a field name or a tuple index",
+                ))
+            }
+            None => {
+                return Err(Error::new_spanned(
+                    dot,
+                    "expected a field name or a tuple index",
+                ))
+            }
+        }
+        if let Some(token) = tokens.next_if(|token| !is_dot(token)) {
+            return Err(Error::new_spanned(token, "expected `.`"));
+        }
+    }
+    if segments.is_empty() {
+        return Err(Error::new_spanned(
+            root,
+            "expected a path of fields after the type",
+        ));
+    }
+
+    let mut focus = quote! { #root };
+    let mut steps = Vec::new();
+    for segment in segments {
+        let (lens, next) = match segment {
+            Segment::Field(field) => {
+                let name = field_name(&field);
+                (
+                    quote! { <#focus as ::fp_std::lens::__Field<#name>>::Lens },
+                    quote! { <#focus as ::fp_std::lens::__Field<#name>>::Focus },
+                )
+            }
+            Segment::Index(index, span) => {
+                let index = Literal::usize_unsuffixed(index);
+                let nth = quote::quote_spanned! { span => ::fp_std::tuple::Nth<#index> };
+                (
+                    quote! { ::fp_std::tuple::LensNth<#index> },
+                    quote! { <#focus as #nth>::Element },
+                )
+            }
+        };
+        steps.push((lens, next.clone()));
+        focus = next;
+    }
+
+    let (last, _) = steps.pop().unwrap();
+    let composed = steps.into_iter().rev().fold(last, |inner, (outer, focus)| {
+        quote! { ::fp_std::lens::ComposeLens<#outer, #inner, #focus> }
+    });
+    Ok(quote! { <#composed as ::core::default::Default>::default() })
+}
+
+fn is_dot(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == '.')
+}
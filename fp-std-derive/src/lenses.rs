@@ -0,0 +1,158 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, Result, Visibility};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Lenses can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Lenses can only be derived for structs",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "Lenses can only be derived for structs without generic parameters",
+        ));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let lenses_name = format_ident!("{}Lenses", name);
+    let field_names: Vec<_> = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+    let lens_names: Vec<_> = field_names
+        .iter()
+        .map(|field| format_ident!("Lens{}{}", name, camel_case(&field.to_string())))
+        .collect();
+
+    let lens_types = fields.iter().zip(&lens_names).map(|(field, lens)| {
+        let field_vis = &field.vis;
+        let field_name = field.ident.as_ref().unwrap();
+        let field_type = &field.ty;
+        let doc = format!(
+            "A lens for the `{}` field of [{}].",
+            field_name.to_string().trim_start_matches("r#"),
+            name,
+        );
+        quote! {
+            #[doc = #doc]
+            #[derive(
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::fmt::Debug,
+                ::core::default::Default,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #field_vis struct #lens;
+
+            impl ::fp_std::__fp_core::lens::Lens<#name, #field_type> for #lens {
+                fn get(s: &#name) -> ::core::option::Option<&#field_type> {
+                    ::core::option::Option::Some(&s.#field_name)
+                }
+
+                fn set(a: #field_type, s: &#name) -> #name {
+                    #name {
+                        #field_name: a,
+                        ..::core::clone::Clone::clone(s)
+                    }
+                }
+            }
+
+            impl ::fp_std::lens::LensMut<#name, #field_type> for #lens {
+                fn get_mut(s: &mut #name) -> ::core::option::Option<&mut #field_type> {
+                    ::core::option::Option::Some(&mut s.#field_name)
+                }
+
+                fn set_mut(s: &mut #name, a: #field_type) {
+                    s.#field_name = a;
+                }
+            }
+        }
+    });
+    // A field's lens can only be named by the public `__Field` trait if it is at least as
+    // visible as the struct, or the impl would expose a private type.
+    let field_impls = fields
+        .iter()
+        .zip(&lens_names)
+        .filter(|(field, _)| at_least_as_visible(&field.vis, vis))
+        .map(|(field, lens)| {
+            let field_type = &field.ty;
+            let field_name = crate::lens::field_name(field.ident.as_ref().unwrap());
+            quote! {
+                impl ::fp_std::lens::__Field<#field_name> for #name {
+                    type Lens = #lens;
+                    type Focus = #field_type;
+                }
+            }
+        });
+    let lenses_doc = format!("The lenses for the fields of [{}].", name);
+
+    Ok(quote! {
+        #(#lens_types)*
+
+        #(#field_impls)*
+
+        #[doc = #lenses_doc]
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::default::Default,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+        )]
+        #vis struct #lenses_name {
+            #(#field_vis #field_names: #lens_names,)*
+        }
+
+        impl ::fp_std::lens::Lenses for #name {
+            type Lenses = #lenses_name;
+
+            fn lenses() -> #lenses_name {
+                #lenses_name {
+                    #(#field_names: #lens_names,)*
+                }
+            }
+        }
+    })
+}
+
+fn at_least_as_visible(field: &Visibility, within: &Visibility) -> bool {
+    match (field, within) {
+        (_, Visibility::Inherited) | (Visibility::Public(_), _) => true,
+        (field, within) => quote!(#field).to_string() == quote!(#within).to_string(),
+    }
+}
+
+fn camel_case(field: &str) -> String {
+    field
+        .trim_start_matches("r#")
+        .split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars))
+                .into_iter()
+                .flatten()
+        })
+        .collect()
+}
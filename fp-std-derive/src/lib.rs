@@ -0,0 +1,64 @@
+//! Derive macros for [fp-std][]. These are re-exported by `fp-std` when its `derive` feature is
+//! enabled, and should be used from there rather than depending on this crate directly.
+//!
+//! [fp-std]: https://crates.io/crates/fp-std
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod bounded;
+mod enumerable;
+mod lens;
+mod lenses;
+mod traverse;
+
+/// Derives `fp_std::bounded::Bounded` for a fieldless enum, taking the first declared variant as
+/// the least value and the last as the greatest.
+#[proc_macro_derive(Bounded)]
+pub fn derive_bounded(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    bounded::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `fp_std::enumerable::Enumerable` for a fieldless enum, stepping through the variants
+/// in declaration order.
+#[proc_macro_derive(Enumerable)]
+pub fn derive_enumerable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    enumerable::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `fp_std::lens::Lenses` for a struct with named fields, generating a lens type for
+/// each field, named after the struct and the field, and a struct holding one of each.
+#[proc_macro_derive(Lenses)]
+pub fn derive_lenses(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    lenses::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Composes the lenses for a path of struct fields and tuple elements. See `fp_std::lens!`.
+#[proc_macro]
+pub fn lens(input: TokenStream) -> TokenStream {
+    lens::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `fp_std::traversal::Traverse` for a struct, visiting each of its fields in
+/// declaration order. Either every field must have the same type, or the type of the fields to
+/// visit must be given with `#[traverse(Type)]`, in which case the other fields are left alone.
+#[proc_macro_derive(Traverse, attributes(traverse))]
+pub fn derive_traverse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    traverse::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
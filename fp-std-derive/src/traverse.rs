@@ -0,0 +1,109 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, Index, Result, Type};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Traverse can only be derived for structs",
+            ))
+        }
+    };
+    let fields: Vec<_> = data.fields.iter().collect();
+    let explicit: Option<Type> = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("traverse"))
+        .map(|attr| attr.parse_args())
+        .transpose()?;
+    let focus = match explicit {
+        Some(focus) => focus,
+        None => {
+            let first = fields.first().ok_or_else(|| {
+                Error::new_spanned(
+                    &input.ident,
+                    "Traverse can only be derived for structs with fields",
+                )
+            })?;
+            if let Some(field) = fields.iter().find(|field| !same_type(&field.ty, &first.ty)) {
+                return Err(Error::new_spanned(
+                    &field.ty,
+                    "fields have different types; use #[traverse(Type)] to choose which to visit",
+                ));
+            }
+            first.ty.clone()
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let bindings: Vec<_> = (0..fields.len())
+        .map(|i| format_ident!("field_{}", i))
+        .collect();
+    let visited = fields.iter().map(|field| same_type(&field.ty, &focus));
+
+    let visits = bindings
+        .iter()
+        .zip(visited.clone())
+        .filter(|(_, visited)| *visited)
+        .map(|(binding, _)| quote! { f(#binding); });
+    let mapped: Vec<_> = bindings
+        .iter()
+        .zip(visited)
+        .map(|(binding, visited)| {
+            if visited {
+                quote! { f(#binding) }
+            } else {
+                quote! { #binding }
+            }
+        })
+        .collect();
+
+    let (pattern, construction) = match &data.fields {
+        Fields::Named(..) => {
+            let names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+            (
+                quote! { #name { #(#names: #bindings),* } },
+                quote! { #name { #(#names: #mapped),* } },
+            )
+        }
+        Fields::Unnamed(..) => {
+            let indices = (0..fields.len()).map(Index::from);
+            (
+                quote! { #name { #(#indices: #bindings),* } },
+                quote! { #name(#(#mapped),*) },
+            )
+        }
+        Fields::Unit => (quote! { #name }, quote! { #name }),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::fp_std::traversal::Traverse<#focus> for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn for_each_field<'__fp_std, __FpStdF>(&'__fp_std self, mut f: __FpStdF)
+            where
+                __FpStdF: ::core::ops::FnMut(&'__fp_std #focus),
+                #focus: '__fp_std,
+            {
+                let #pattern = self;
+                #(#visits)*
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn map_fields<__FpStdF>(self, mut f: __FpStdF) -> Self
+            where
+                __FpStdF: ::core::ops::FnMut(#focus) -> #focus,
+            {
+                let #pattern = self;
+                #construction
+            }
+        }
+    })
+}
+
+fn same_type(a: &Type, b: &Type) -> bool {
+    quote!(#a).to_string() == quote!(#b).to_string()
+}
@@ -0,0 +1,575 @@
+//! Implementations of `Arbitrary` for the types in this crate, so that they can be generated
+//! by [proptest](https://crates.io/crates/proptest) and
+//! [quickcheck](https://crates.io/crates/quickcheck).
+
+/* PROPTEST *******************************************************************/
+#[cfg(feature = "proptest")]
+mod proptest_impls {
+    use crate::either::{Either, These};
+    use crate::id::Identity;
+    use crate::interval::{Interval, IntervalSet};
+    use crate::list::Edit;
+    use crate::map::Counter;
+    use crate::monoid::{Concat, First, Last, Max, Maybe, Min, Pair, Product, Sum};
+    use crate::nonempty::NonEmpty;
+    use crate::tagged::Tagged;
+    use crate::tree::Tree;
+    use crate::units::Quantity;
+    use crate::validation::Validation;
+    use crate::writer::Writer;
+    use core::fmt::Debug;
+    use core::hash::Hash;
+    use proptest::arbitrary::{any, Arbitrary};
+    use proptest::collection::vec;
+    use proptest::prop_oneof;
+    use proptest::strategy::{BoxedStrategy, Map, Strategy};
+
+    impl<L, R> Arbitrary for Either<L, R>
+    where
+        L: Arbitrary + 'static,
+        R: Arbitrary + 'static,
+    {
+        type Parameters = (L::Parameters, R::Parameters);
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with((left, right): Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                L::arbitrary_with(left).prop_map(Either::Left),
+                R::arbitrary_with(right).prop_map(Either::Right),
+            ]
+            .boxed()
+        }
+    }
+
+    impl<L, R> Arbitrary for These<L, R>
+    where
+        L: Arbitrary + 'static,
+        R: Arbitrary + 'static,
+        L::Parameters: Clone,
+        R::Parameters: Clone,
+    {
+        type Parameters = (L::Parameters, R::Parameters);
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with((left, right): Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                L::arbitrary_with(left.clone()).prop_map(These::Left),
+                R::arbitrary_with(right.clone()).prop_map(These::Right),
+                (L::arbitrary_with(left), R::arbitrary_with(right))
+                    .prop_map(|(l, r)| These::Both(l, r)),
+            ]
+            .boxed()
+        }
+    }
+
+    impl<T> Arbitrary for Identity<T>
+    where
+        T: Arbitrary,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = Map<T::Strategy, fn(T) -> Self>;
+
+        fn arbitrary_with(parameters: T::Parameters) -> Self::Strategy {
+            T::arbitrary_with(parameters).prop_map(Identity)
+        }
+    }
+
+    impl<T> Arbitrary for Interval<T>
+    where
+        T: Arbitrary + Ord + Clone + Debug + 'static,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            any::<(T, T)>()
+                .prop_filter_map("interval must not be empty", |(a, b)| {
+                    if a < b {
+                        Interval::new(a, b)
+                    } else {
+                        Interval::new(b, a)
+                    }
+                })
+                .boxed()
+        }
+    }
+
+    impl<T> Arbitrary for IntervalSet<T>
+    where
+        T: Arbitrary + Ord + Clone + Debug + 'static,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            vec(any::<Interval<T>>(), 0..8)
+                .prop_map(|intervals| intervals.into_iter().collect())
+                .boxed()
+        }
+    }
+
+    impl<T> Arbitrary for Edit<T>
+    where
+        T: Arbitrary + 'static,
+        T::Parameters: Clone,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(parameters: T::Parameters) -> Self::Strategy {
+            prop_oneof![
+                T::arbitrary_with(parameters.clone()).prop_map(Edit::Keep),
+                T::arbitrary_with(parameters.clone()).prop_map(Edit::Delete),
+                T::arbitrary_with(parameters).prop_map(Edit::Insert),
+            ]
+            .boxed()
+        }
+    }
+
+    impl<K> Arbitrary for Counter<K>
+    where
+        K: Arbitrary + Eq + Hash + 'static,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            vec(any::<K>(), 0..16)
+                .prop_map(|keys| keys.into_iter().collect())
+                .boxed()
+        }
+    }
+
+    impl<T> Arbitrary for NonEmpty<T>
+    where
+        T: Arbitrary + 'static,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            (any::<T>(), vec(any::<T>(), 0..8))
+                .prop_map(|(head, tail)| NonEmpty::new(head, tail))
+                .boxed()
+        }
+    }
+
+    impl<Tag, T> Arbitrary for Tagged<Tag, T>
+    where
+        T: Arbitrary,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = Map<T::Strategy, fn(T) -> Self>;
+
+        fn arbitrary_with(parameters: T::Parameters) -> Self::Strategy {
+            T::arbitrary_with(parameters).prop_map(Tagged::new)
+        }
+    }
+
+    impl<T> Arbitrary for Tree<T>
+    where
+        T: Arbitrary + 'static,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            any::<T>()
+                .prop_map(Tree::leaf)
+                .prop_recursive(4, 32, 4, |subtree| {
+                    (any::<T>(), vec(subtree, 0..4))
+                        .prop_map(|(value, children)| Tree::new(value, children))
+                })
+                .boxed()
+        }
+    }
+
+    impl<T, U> Arbitrary for Quantity<T, U>
+    where
+        T: Arbitrary,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = Map<T::Strategy, fn(T) -> Self>;
+
+        fn arbitrary_with(parameters: T::Parameters) -> Self::Strategy {
+            T::arbitrary_with(parameters).prop_map(Quantity::new)
+        }
+    }
+
+    impl<E, T> Arbitrary for Validation<E, T>
+    where
+        E: Arbitrary + 'static,
+        T: Arbitrary + 'static,
+    {
+        type Parameters = (E::Parameters, T::Parameters);
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with((errors, value): Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                T::arbitrary_with(value).prop_map(Validation::Valid),
+                E::arbitrary_with(errors).prop_map(Validation::Invalid),
+            ]
+            .boxed()
+        }
+    }
+
+    impl<W, A> Arbitrary for Writer<W, A>
+    where
+        W: Arbitrary,
+        A: Arbitrary,
+    {
+        type Parameters = (W::Parameters, A::Parameters);
+        type Strategy = Map<(W::Strategy, A::Strategy), fn((W, A)) -> Self>;
+
+        fn arbitrary_with((log, value): Self::Parameters) -> Self::Strategy {
+            (W::arbitrary_with(log), A::arbitrary_with(value))
+                .prop_map(|(log, value)| Writer::new(value, log))
+        }
+    }
+
+    macro_rules! wrapper_impls {
+        ($($wrapper:ident($inner:ty)),*) => ($(
+            impl<T> Arbitrary for $wrapper<T>
+            where
+                T: Arbitrary,
+            {
+                type Parameters = <$inner as Arbitrary>::Parameters;
+                type Strategy = Map<<$inner as Arbitrary>::Strategy, fn($inner) -> Self>;
+
+                fn arbitrary_with(parameters: Self::Parameters) -> Self::Strategy {
+                    <$inner>::arbitrary_with(parameters).prop_map($wrapper)
+                }
+            }
+        )*)
+    }
+
+    wrapper_impls!(
+        Sum(T),
+        Product(T),
+        Concat(T),
+        Max(T),
+        Min(T),
+        First(Option<T>),
+        Last(Option<T>),
+        Maybe(Option<T>)
+    );
+
+    impl<A, B> Arbitrary for Pair<A, B>
+    where
+        A: Arbitrary,
+        B: Arbitrary,
+    {
+        type Parameters = (A::Parameters, B::Parameters);
+        type Strategy = Map<(A::Strategy, B::Strategy), fn((A, B)) -> Self>;
+
+        fn arbitrary_with((a, b): Self::Parameters) -> Self::Strategy {
+            (A::arbitrary_with(a), B::arbitrary_with(b)).prop_map(|(a, b)| Pair(a, b))
+        }
+    }
+}
+
+/* QUICKCHECK *****************************************************************/
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls {
+    use crate::either::{Either, These};
+    use crate::id::Identity;
+    use crate::interval::{Interval, IntervalSet};
+    use crate::list::Edit;
+    use crate::map::Counter;
+    use crate::monoid::{Concat, First, Last, Max, Maybe, Min, Pair, Product, Sum};
+    use crate::nonempty::NonEmpty;
+    use crate::tagged::Tagged;
+    use crate::tree::Tree;
+    use crate::units::Quantity;
+    use crate::validation::Validation;
+    use crate::writer::Writer;
+    use core::hash::Hash;
+    use quickcheck::{Arbitrary, Gen};
+
+    impl<L, R> Arbitrary for Either<L, R>
+    where
+        L: Arbitrary,
+        R: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            if bool::arbitrary(g) {
+                Either::Left(L::arbitrary(g))
+            } else {
+                Either::Right(R::arbitrary(g))
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            match self {
+                Either::Left(l) => Box::new(l.shrink().map(Either::Left)),
+                Either::Right(r) => Box::new(r.shrink().map(Either::Right)),
+            }
+        }
+    }
+
+    impl<L, R> Arbitrary for These<L, R>
+    where
+        L: Arbitrary,
+        R: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            match u8::arbitrary(g) % 3 {
+                0 => These::Left(L::arbitrary(g)),
+                1 => These::Right(R::arbitrary(g)),
+                _ => These::Both(L::arbitrary(g), R::arbitrary(g)),
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            match self {
+                These::Left(l) => Box::new(l.shrink().map(These::Left)),
+                These::Right(r) => Box::new(r.shrink().map(These::Right)),
+                These::Both(l, r) => {
+                    let both = (l.clone(), r.clone());
+                    Box::new(both.shrink().map(|(l, r)| These::Both(l, r)))
+                }
+            }
+        }
+    }
+
+    impl<T> Arbitrary for Identity<T>
+    where
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Identity(T::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.0.shrink().map(Identity))
+        }
+    }
+
+    impl<T> Arbitrary for Interval<T>
+    where
+        T: Arbitrary + Ord,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            loop {
+                let (a, b) = <(T, T)>::arbitrary(g);
+                if let Some(interval) =
+                    Interval::new(a.clone(), b.clone()).or_else(|| Interval::new(b, a))
+                {
+                    return interval;
+                }
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let bounds = (self.start().clone(), self.end().clone());
+            Box::new(
+                bounds
+                    .shrink()
+                    .filter_map(|(start, end)| Interval::new(start, end)),
+            )
+        }
+    }
+
+    impl<T> Arbitrary for IntervalSet<T>
+    where
+        T: Arbitrary + Ord,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Vec::<Interval<T>>::arbitrary(g).into_iter().collect()
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let intervals = self.intervals().to_vec();
+            Box::new(
+                intervals
+                    .shrink()
+                    .map(|intervals| intervals.into_iter().collect()),
+            )
+        }
+    }
+
+    impl<T> Arbitrary for Edit<T>
+    where
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            match u8::arbitrary(g) % 3 {
+                0 => Edit::Keep(T::arbitrary(g)),
+                1 => Edit::Delete(T::arbitrary(g)),
+                _ => Edit::Insert(T::arbitrary(g)),
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            match self {
+                Edit::Keep(t) => Box::new(t.shrink().map(Edit::Keep)),
+                Edit::Delete(t) => Box::new(t.shrink().map(Edit::Delete)),
+                Edit::Insert(t) => Box::new(t.shrink().map(Edit::Insert)),
+            }
+        }
+    }
+
+    impl<K> Arbitrary for Counter<K>
+    where
+        K: Arbitrary + Eq + Hash,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Vec::<K>::arbitrary(g).into_iter().collect()
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let keys: Vec<K> = self
+                .clone()
+                .into_iter()
+                .flat_map(|(key, count)| core::iter::repeat_n(key, count))
+                .collect();
+            Box::new(keys.shrink().map(|keys| keys.into_iter().collect()))
+        }
+    }
+
+    impl<T> Arbitrary for NonEmpty<T>
+    where
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            NonEmpty::new(T::arbitrary(g), Vec::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let parts = (self.head.clone(), self.tail.clone());
+            Box::new(parts.shrink().map(|(head, tail)| NonEmpty::new(head, tail)))
+        }
+    }
+
+    impl<Tag: 'static, T> Arbitrary for Tagged<Tag, T>
+    where
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Tagged::new(T::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new((**self).shrink().map(Tagged::new))
+        }
+    }
+
+    impl<T> Arbitrary for Tree<T>
+    where
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            fn tree<T: Arbitrary>(g: &mut Gen, depth: usize) -> Tree<T> {
+                let width = if depth == 0 {
+                    0
+                } else {
+                    usize::arbitrary(g) % 4
+                };
+                let children = (0..width).map(|_| tree(g, depth - 1)).collect();
+                Tree::new(T::arbitrary(g), children)
+            }
+            tree(g, 4)
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let parts = (self.value.clone(), self.children.clone());
+            Box::new(
+                parts
+                    .shrink()
+                    .map(|(value, children)| Tree::new(value, children)),
+            )
+        }
+    }
+
+    impl<T, U: 'static> Arbitrary for Quantity<T, U>
+    where
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Quantity::new(T::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.value().shrink().map(Quantity::new))
+        }
+    }
+
+    impl<E, T> Arbitrary for Validation<E, T>
+    where
+        E: Arbitrary,
+        T: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            if bool::arbitrary(g) {
+                Validation::Valid(T::arbitrary(g))
+            } else {
+                Validation::Invalid(E::arbitrary(g))
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            match self {
+                Validation::Valid(t) => Box::new(t.shrink().map(Validation::Valid)),
+                Validation::Invalid(e) => Box::new(e.shrink().map(Validation::Invalid)),
+            }
+        }
+    }
+
+    impl<W, A> Arbitrary for Writer<W, A>
+    where
+        W: Arbitrary,
+        A: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Writer::new(A::arbitrary(g), W::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let parts = (self.value.clone(), self.log.clone());
+            Box::new(parts.shrink().map(|(value, log)| Writer::new(value, log)))
+        }
+    }
+
+    macro_rules! wrapper_impls {
+        ($($wrapper:ident($inner:ty)),*) => ($(
+            impl<T> Arbitrary for $wrapper<T>
+            where
+                T: Arbitrary,
+            {
+                fn arbitrary(g: &mut Gen) -> Self {
+                    $wrapper(<$inner>::arbitrary(g))
+                }
+
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                    Box::new(self.0.shrink().map($wrapper))
+                }
+            }
+        )*)
+    }
+
+    wrapper_impls!(
+        Sum(T),
+        Product(T),
+        Concat(T),
+        Max(T),
+        Min(T),
+        First(Option<T>),
+        Last(Option<T>),
+        Maybe(Option<T>)
+    );
+
+    impl<A, B> Arbitrary for Pair<A, B>
+    where
+        A: Arbitrary,
+        B: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Pair(A::arbitrary(g), B::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let parts = (self.0.clone(), self.1.clone());
+            Box::new(parts.shrink().map(|(a, b)| Pair(a, b)))
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Functions for manipulating fixed-size arrays, without allocating.
+
+/* BASIC FUNCTIONS ************************************************************/
+
+/// Creates a function which transforms a borrowed array, element by element, into a new array.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::array::map_ref;
+/// let names = ["ann".to_owned(), "bo".to_owned()];
+/// assert_eq!(map_ref(String::len)(&names), [3, 2]);
+/// ```
+pub fn map_ref<T, U, F, const N: usize>(f: F) -> impl Fn(&[T; N]) -> [U; N]
+where
+    F: Fn(&T) -> U,
+{
+    move |array| core::array::from_fn(|i| f(&array[i]))
+}
+
+/// Combines two arrays of the same length into an array of pairs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::array::zip;
+/// assert_eq!(zip([1, 2], ['a', 'b']), [(1, 'a'), (2, 'b')]);
+/// ```
+pub fn zip<A, B, const N: usize>(a: [A; N], b: [B; N]) -> [(A, B); N] {
+    let mut b = IntoIterator::into_iter(b);
+    a.map(|a| (a, b.next().unwrap()))
+}
+
+/// Splits an array of pairs into an array of the first elements and an array of the second.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::array::unzip;
+/// assert_eq!(unzip([(1, 'a'), (2, 'b')]), ([1, 2], ['a', 'b']));
+/// ```
+pub fn unzip<A, B, const N: usize>(array: [(A, B); N]) -> ([A; N], [B; N]) {
+    let mut pairs = array.map(|(a, b)| (Some(a), Some(b)));
+    let a = core::array::from_fn(|i| pairs[i].0.take().unwrap());
+    let b = core::array::from_fn(|i| pairs[i].1.take().unwrap());
+    (a, b)
+}
+
+/// Creates a function which folds the elements of an array into an accumulator, from first to
+/// last.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::array::fold;
+/// let sum = fold(0, |acc, x: u32| acc + x);
+/// assert_eq!(sum([1, 2, 3]), 6);
+/// ```
+pub fn fold<T, B, F, const N: usize>(init: B, f: F) -> impl Fn([T; N]) -> B
+where
+    B: Clone,
+    F: Fn(B, T) -> B,
+{
+    move |array| IntoIterator::into_iter(array).fold(init.clone(), &f)
+}
+
+/// Creates an array whose elements are each computed from their index, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::array::from_fn_indexed;
+/// let squares: [usize; 4] = from_fn_indexed(|i| i * i);
+/// assert_eq!(squares, [0, 1, 4, 9]);
+/// ```
+pub fn from_fn_indexed<T, F, const N: usize>(f: F) -> [T; N]
+where
+    F: FnMut(usize) -> T,
+{
+    core::array::from_fn(f)
+}
+
+/* TUPLES *********************************************************************/
+
+/// Arrays which can be converted to and from tuples of the same length. This is implemented
+/// for arrays of up to 12 elements, as are the traits of the [tuple](crate::tuple) module.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::array::ToTuple;
+/// assert_eq!([1, 2, 3].to_tuple(), (1, 2, 3));
+/// assert_eq!(<[u8; 2]>::from_tuple((4, 5)), [4, 5]);
+/// ```
+pub trait ToTuple {
+    /// The tuple with as many elements as this array.
+    type Tuple;
+
+    /// Converts this array into a tuple.
+    fn to_tuple(self) -> Self::Tuple;
+
+    /// Converts a tuple into an array.
+    fn from_tuple(tuple: Self::Tuple) -> Self;
+}
+
+macro_rules! to_tuple_impl {
+    ($n:literal: $($t:ident)*) => {
+        impl<T> ToTuple for [T; $n] {
+            type Tuple = ($(to_tuple_impl!(@elem $t T),)*);
+
+            #[allow(non_snake_case)]
+            fn to_tuple(self) -> Self::Tuple {
+                let [$($t,)*] = self;
+                ($($t,)*)
+            }
+
+            #[allow(non_snake_case)]
+            fn from_tuple(tuple: Self::Tuple) -> Self {
+                let ($($t,)*) = tuple;
+                [$($t,)*]
+            }
+        }
+    };
+    (@elem $t:ident $elem:ident) => {
+        $elem
+    };
+}
+
+to_tuple_impl!(1: A);
+to_tuple_impl!(2: A B);
+to_tuple_impl!(3: A B C);
+to_tuple_impl!(4: A B C D);
+to_tuple_impl!(5: A B C D E);
+to_tuple_impl!(6: A B C D E F);
+to_tuple_impl!(7: A B C D E F G);
+to_tuple_impl!(8: A B C D E F G H);
+to_tuple_impl!(9: A B C D E F G H I);
+to_tuple_impl!(10: A B C D E F G H I J);
+to_tuple_impl!(11: A B C D E F G H I J K);
+to_tuple_impl!(12: A B C D E F G H I J K L);
+
+/// Converts an array into a tuple of the same length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::array::to_tuple;
+/// # use fp_std::tuple::third;
+/// let rgb = to_tuple([255u8, 128, 0]);
+/// assert_eq!(third(rgb), 0);
+/// ```
+pub fn to_tuple<A: ToTuple>(array: A) -> A::Tuple {
+    array.to_tuple()
+}
@@ -0,0 +1,170 @@
+//! Types which have a least and a greatest value.
+
+/* BOUNDED ********************************************************************/
+use crate::enumerable::Enumerable;
+
+/// A type which has a least value and a greatest value.
+///
+/// With the `derive` feature enabled, `#[derive(Bounded)]` implements this trait for enums
+/// without fields, taking the first declared variant as the least and the last as the greatest.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::bounded::Bounded;
+/// assert_eq!(u8::min_value(), 0);
+/// assert_eq!(i16::max_value(), 32767);
+/// assert_eq!(<bool as Bounded>::max_value(), true);
+/// ```
+pub trait Bounded: Sized {
+    /// The least value of this type.
+    fn min_value() -> Self;
+
+    /// The greatest value of this type.
+    fn max_value() -> Self;
+}
+
+#[cfg(feature = "derive")]
+pub use fp_std_derive::Bounded;
+
+macro_rules! integer_bounded_impl {
+    ($($t:ident)*) => ($(
+        impl Bounded for $t {
+            fn min_value() -> Self {
+                $t::MIN
+            }
+
+            fn max_value() -> Self {
+                $t::MAX
+            }
+        }
+    )*)
+}
+
+integer_bounded_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+impl Bounded for char {
+    fn min_value() -> Self {
+        '\0'
+    }
+
+    fn max_value() -> Self {
+        char::MAX
+    }
+}
+
+impl Bounded for bool {
+    fn min_value() -> Self {
+        false
+    }
+
+    fn max_value() -> Self {
+        true
+    }
+}
+
+impl Bounded for () {
+    fn min_value() -> Self {}
+
+    fn max_value() -> Self {}
+}
+
+/* STEPPING *******************************************************************/
+
+/// Returns the value following the one provided, wrapping around to the least value after the
+/// greatest.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::bounded::succ_wrap;
+/// assert_eq!(succ_wrap(3u8), 4);
+/// assert_eq!(succ_wrap(255u8), 0);
+/// ```
+///
+/// With the `derive` feature, this steps cyclically through the variants of an enum:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// # use fp_std::bounded::{succ_wrap, Bounded};
+/// # use fp_std::enumerable::Enumerable;
+/// #[derive(Bounded, Enumerable, Copy, Clone, PartialEq, Debug)]
+/// enum Light { Green, Yellow, Red }
+///
+/// let cycle: Vec<_> = core::iter::successors(Some(Light::Green), |l| Some(succ_wrap(*l)))
+///     .take(4)
+///     .collect();
+/// assert_eq!(cycle, vec![Light::Green, Light::Yellow, Light::Red, Light::Green]);
+/// # }
+/// ```
+pub fn succ_wrap<A>(a: A) -> A
+where
+    A: Bounded + Enumerable,
+{
+    a.succ().unwrap_or_else(A::min_value)
+}
+
+/// Returns the value preceding the one provided, wrapping around to the greatest value before
+/// the least.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::bounded::pred_wrap;
+/// assert_eq!(pred_wrap(3u8), 2);
+/// assert_eq!(pred_wrap(false), true);
+/// ```
+pub fn pred_wrap<A>(a: A) -> A
+where
+    A: Bounded + Enumerable,
+{
+    a.pred().unwrap_or_else(A::max_value)
+}
+
+/// Returns the value following the one provided, or the same value if it is the greatest.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::bounded::succ_sat;
+/// assert_eq!(succ_sat(3i8), 4);
+/// assert_eq!(succ_sat(i8::MAX), i8::MAX);
+/// ```
+pub fn succ_sat<A>(a: A) -> A
+where
+    A: Bounded + Enumerable,
+{
+    a.succ().unwrap_or(a)
+}
+
+/// Returns the value preceding the one provided, or the same value if it is the least.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::bounded::pred_sat;
+/// assert_eq!(pred_sat('b'), 'a');
+/// assert_eq!(pred_sat('\0'), '\0');
+/// ```
+pub fn pred_sat<A>(a: A) -> A
+where
+    A: Bounded + Enumerable,
+{
+    a.pred().unwrap_or(a)
+}
+
+/// Iterates over every value of a type, from the least to the greatest.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::bounded::enum_all;
+/// assert_eq!(enum_all::<bool>().collect::<Vec<_>>(), vec![false, true]);
+/// assert_eq!(enum_all::<u8>().count(), 256);
+/// ```
+pub fn enum_all<A>() -> impl Iterator<Item = A>
+where
+    A: Bounded + Enumerable,
+{
+    crate::enumerable::enum_from(A::min_value())
+}
@@ -0,0 +1,74 @@
+//! Versions of other functions in this crate which can be evaluated in constant expressions.
+//!
+//! Constant evaluation cannot run destructors, so these functions are restricted to `Copy`
+//! values, which never need to be dropped. Functions which can be `const` without any extra
+//! restrictions are `const` where they are defined, such as
+//! [tuple::cons](crate::tuple::cons) and [function::identity](crate::function::identity).
+//!
+//! Combinators which return closures, such as [function::flip](crate::function::flip), cannot
+//! be used in constant expressions, since calling a closure there is not yet supported.
+//!
+//! ```rust
+//! # use fp_std::constants::{duplicate, first, swap};
+//! # use fp_std::tuple::cons;
+//! static SQUARES: [(u8, u16); 4] = [cons(1, 1), cons(2, 4), cons(3, 9), cons(4, 16)];
+//! const ROOT_OF_NINE: (u16, u8) = swap(SQUARES[2]);
+//! const DIAGONAL: [(u8, u8); 2] = [duplicate(first(SQUARES[0])), duplicate(first(SQUARES[1]))];
+//!
+//! assert_eq!(ROOT_OF_NINE, (9, 3));
+//! assert_eq!(DIAGONAL, [(1, 1), (2, 2)]);
+//! ```
+
+/* TUPLES *********************************************************************/
+
+/// Returns the first element in a 2-tuple.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::constants::first;
+/// const ONE: u8 = first((1, 'a'));
+/// assert_eq!(ONE, 1);
+/// ```
+pub const fn first<A: Copy, B: Copy>(pair: (A, B)) -> A {
+    pair.0
+}
+
+/// Returns the second element in a 2-tuple.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::constants::second;
+/// const A: char = second((1, 'a'));
+/// assert_eq!(A, 'a');
+/// ```
+pub const fn second<A: Copy, B: Copy>(pair: (A, B)) -> B {
+    pair.1
+}
+
+/// Swaps the elements of a 2-tuple.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::constants::swap;
+/// const SWAPPED: (char, u8) = swap((1, 'a'));
+/// assert_eq!(SWAPPED, ('a', 1));
+/// ```
+pub const fn swap<A: Copy, B: Copy>(pair: (A, B)) -> (B, A) {
+    (pair.1, pair.0)
+}
+
+/// Constructs a tuple of two copies of the same value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::constants::duplicate;
+/// const ORIGIN: (i32, i32) = duplicate(0);
+/// assert_eq!(ORIGIN, (0, 0));
+/// ```
+pub const fn duplicate<A: Copy>(a: A) -> (A, A) {
+    (a, a)
+}
@@ -0,0 +1,328 @@
+//! Functions for converting functions of several arguments into chains of functions of one
+//! argument each, which can be supplied one at a time.
+//!
+//! ```rust
+//! # use fp_std::curry::curry3;
+//! let clamp = curry3(|lo: i32, hi: i32, x: i32| x.max(lo).min(hi));
+//! let percent = clamp(0)(100);
+//! let clamped: Vec<i32> = vec![-5, 50, 105].into_iter().map(percent).collect();
+//! assert_eq!(clamped, vec![0, 50, 100]);
+//! ```
+//!
+//! Since each stage of a curried function can be called any number of times, the function and
+//! the arguments supplied so far are cloned into every stage after them.
+
+/* CURRIED FUNCTIONS **********************************************************/
+
+/// A function which takes its first argument, and returns a function of the second. This is
+/// implemented by every such function, and exists to name the result of
+/// [curry2](crate::curry::curry2).
+pub trait Curried2<A, B, C>: Fn(A) -> <Self as Curried2<A, B, C>>::Next {
+    /// The function of the second argument.
+    type Next: Fn(B) -> C;
+}
+
+impl<A, B, C, F, G> Curried2<A, B, C> for F
+where
+    F: Fn(A) -> G,
+    G: Fn(B) -> C,
+{
+    type Next = G;
+}
+
+/// A function which takes its first argument, and returns a [Curried2](crate::curry::Curried2)
+/// function of the remaining two. This is implemented by every such function, and exists to
+/// name the result of [curry3](crate::curry::curry3).
+pub trait Curried3<A, B, C, D>: Fn(A) -> <Self as Curried3<A, B, C, D>>::Next {
+    /// The function of the remaining arguments.
+    type Next: Curried2<B, C, D>;
+}
+
+impl<A, B, C, D, F, G> Curried3<A, B, C, D> for F
+where
+    F: Fn(A) -> G,
+    G: Curried2<B, C, D>,
+{
+    type Next = G;
+}
+
+/// A function which takes its first argument, and returns a [Curried3](crate::curry::Curried3)
+/// function of the remaining three. This is implemented by every such function, and exists to
+/// name the result of [curry4](crate::curry::curry4).
+pub trait Curried4<A, B, C, D, E>: Fn(A) -> <Self as Curried4<A, B, C, D, E>>::Next {
+    /// The function of the remaining arguments.
+    type Next: Curried3<B, C, D, E>;
+}
+
+impl<A, B, C, D, E, F, G> Curried4<A, B, C, D, E> for F
+where
+    F: Fn(A) -> G,
+    G: Curried3<B, C, D, E>,
+{
+    type Next = G;
+}
+
+/// A function which takes its first argument, and returns a [Curried4](crate::curry::Curried4)
+/// function of the remaining four. This is implemented by every such function, and exists to
+/// name the result of [curry5](crate::curry::curry5).
+pub trait Curried5<A, B, C, D, E, G>: Fn(A) -> <Self as Curried5<A, B, C, D, E, G>>::Next {
+    /// The function of the remaining arguments.
+    type Next: Curried4<B, C, D, E, G>;
+}
+
+impl<A, B, C, D, E, G, F, H> Curried5<A, B, C, D, E, G> for F
+where
+    F: Fn(A) -> H,
+    H: Curried4<B, C, D, E, G>,
+{
+    type Next = H;
+}
+
+/* CURRYING *******************************************************************/
+
+/// Converts a 2-arity function into a function which takes the first argument, and returns a
+/// function of the second.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::curry::curry2;
+/// let add = curry2(|a: u32, b: u32| a + b);
+/// let add1 = add(1);
+/// assert_eq!(add1(2), 3);
+/// assert_eq!(add(10)(5), 15);
+/// ```
+pub fn curry2<A, B, C, F>(f: F) -> impl Curried2<A, B, C>
+where
+    A: Clone,
+    F: Fn(A, B) -> C + Clone,
+{
+    move |a: A| {
+        let f = f.clone();
+        move |b: B| f(a.clone(), b)
+    }
+}
+
+/// Converts a 3-arity function into a chain of three functions, each of which takes one
+/// argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::curry::curry3;
+/// let greet = curry3(|greeting: &str, name: &str, punctuation: char| {
+///     format!("{}, {}{}", greeting, name, punctuation)
+/// });
+/// let hello = greet("Hello");
+/// assert_eq!(hello("Alice")('!'), "Hello, Alice!");
+/// assert_eq!(hello("Bob")('?'), "Hello, Bob?");
+/// ```
+pub fn curry3<A, B, C, D, F>(f: F) -> impl Curried3<A, B, C, D>
+where
+    A: Clone,
+    B: Clone,
+    F: Fn(A, B, C) -> D + Clone,
+{
+    move |a: A| {
+        let f = f.clone();
+        move |b: B| {
+            let f = f.clone();
+            let a = a.clone();
+            move |c: C| f(a.clone(), b.clone(), c)
+        }
+    }
+}
+
+/// Converts a 4-arity function into a chain of four functions, each of which takes one
+/// argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::curry::curry4;
+/// let rgba = curry4(|r: u8, g: u8, b: u8, a: u8| u32::from_be_bytes([r, g, b, a]));
+/// let opaque_grey = |level| rgba(level)(level)(level)(255);
+/// assert_eq!(opaque_grey(0x80), 0x808080ff);
+/// ```
+pub fn curry4<A, B, C, D, E, F>(f: F) -> impl Curried4<A, B, C, D, E>
+where
+    A: Clone,
+    B: Clone,
+    C: Clone,
+    F: Fn(A, B, C, D) -> E + Clone,
+{
+    move |a: A| {
+        let f = f.clone();
+        move |b: B| {
+            let f = f.clone();
+            let a = a.clone();
+            move |c: C| {
+                let f = f.clone();
+                let a = a.clone();
+                let b = b.clone();
+                move |d: D| f(a.clone(), b.clone(), c.clone(), d)
+            }
+        }
+    }
+}
+
+/// Converts a 5-arity function into a chain of five functions, each of which takes one
+/// argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::curry::curry5;
+/// struct Request {
+///     method: &'static str,
+///     host: &'static str,
+///     port: u16,
+///     path: String,
+///     body: bool,
+/// }
+///
+/// let request = curry5(|method, host, port, path, body| Request {
+///     method,
+///     host,
+///     port,
+///     path,
+///     body,
+/// });
+/// let local_get = request("GET")("localhost")(8080);
+/// let index = local_get("/".to_owned())(false);
+/// assert_eq!((index.host, index.port, index.path.as_str()), ("localhost", 8080, "/"));
+/// ```
+pub fn curry5<A, B, C, D, E, G, F>(f: F) -> impl Curried5<A, B, C, D, E, G>
+where
+    A: Clone,
+    B: Clone,
+    C: Clone,
+    D: Clone,
+    F: Fn(A, B, C, D, E) -> G + Clone,
+{
+    move |a: A| {
+        let f = f.clone();
+        move |b: B| {
+            let f = f.clone();
+            let a = a.clone();
+            move |c: C| {
+                let f = f.clone();
+                let a = a.clone();
+                let b = b.clone();
+                move |d: D| {
+                    let f = f.clone();
+                    let a = a.clone();
+                    let b = b.clone();
+                    let c = c.clone();
+                    move |e: E| f(a.clone(), b.clone(), c.clone(), d.clone(), e)
+                }
+            }
+        }
+    }
+}
+
+/// Writes a curried closure, as [curry2](crate::curry::curry2) through
+/// [curry5](crate::curry::curry5) would produce from the same closure written normally.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::curry;
+/// let volume = curry!(|w: u32, h: u32, d: u32| w * h * d);
+/// let square_base = volume(2)(2);
+/// assert_eq!(square_base(3), 12);
+///
+/// let repeat = curry!(|s: &str, n: usize| s.repeat(n));
+/// assert_eq!(repeat("ab")(2), "abab");
+/// ```
+#[macro_export]
+macro_rules! curry {
+    (|$a:ident $(: $a_ty:ty)?| $body:expr) => {
+        move |$a $(: $a_ty)?| $body
+    };
+    (|$a:ident $(: $a_ty:ty)?, $b:ident $(: $b_ty:ty)? $(,)?| $body:expr) => {
+        $crate::curry::curry2(move |$a $(: $a_ty)?, $b $(: $b_ty)?| $body)
+    };
+    (|$a:ident $(: $a_ty:ty)?, $b:ident $(: $b_ty:ty)?, $c:ident $(: $c_ty:ty)? $(,)?| $body:expr) => {
+        $crate::curry::curry3(move |$a $(: $a_ty)?, $b $(: $b_ty)?, $c $(: $c_ty)?| $body)
+    };
+    (
+        |$a:ident $(: $a_ty:ty)?,
+        $b:ident $(: $b_ty:ty)?,
+        $c:ident $(: $c_ty:ty)?,
+        $d:ident $(: $d_ty:ty)? $(,)?| $body:expr
+    ) => {
+        $crate::curry::curry4(
+            move |$a $(: $a_ty)?, $b $(: $b_ty)?, $c $(: $c_ty)?, $d $(: $d_ty)?| $body
+        )
+    };
+    (
+        |$a:ident $(: $a_ty:ty)?,
+        $b:ident $(: $b_ty:ty)?,
+        $c:ident $(: $c_ty:ty)?,
+        $d:ident $(: $d_ty:ty)?,
+        $e:ident $(: $e_ty:ty)? $(,)?| $body:expr
+    ) => {
+        $crate::curry::curry5(
+            move |$a $(: $a_ty)?, $b $(: $b_ty)?, $c $(: $c_ty)?, $d $(: $d_ty)?, $e $(: $e_ty)?| {
+                $body
+            }
+        )
+    };
+}
+
+/* UNCURRYING *****************************************************************/
+
+macro_rules! uncurry_n {
+    (
+        [$uncurry:ident, $curry:ident, $curried:ident]($($arg:ident: $ty:ident),+) -> $ret:ident,
+        $($example:literal)+
+    ) => {
+        #[doc = concat!(
+            "Converts a curried function, which takes its arguments one at a time, into a",
+            " function which takes all of them at once. This undoes [",
+            stringify!($curry),
+            "](crate::curry::",
+            stringify!($curry),
+            ")."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        #[doc = concat!("# use fp_std::curry::", stringify!($uncurry), ";")]
+        $(#[doc = $example])+
+        /// ```
+        pub fn $uncurry<$($ty,)+ $ret, F>(f: F) -> impl Fn($($ty),+) -> $ret
+        where
+            F: $curried<$($ty,)+ $ret>,
+        {
+            move |$($arg),+| f$(($arg))+
+        }
+    };
+}
+
+uncurry_n!(
+    [uncurry2, curry2, Curried2](a: A, b: B) -> C,
+    "let add = uncurry2(|a: u32| move |b: u32| a + b);"
+    "assert_eq!(add(1, 2), 3);"
+);
+uncurry_n!(
+    [uncurry3, curry3, Curried3](a: A, b: B, c: C) -> D,
+    "let between = uncurry3(|lo: u32| move |hi: u32| move |x: u32| lo <= x && x <= hi);"
+    "assert!(between(1, 5, 3));"
+    "assert!(!between(1, 5, 6));"
+);
+uncurry_n!(
+    [uncurry4, curry4, Curried4](a: A, b: B, c: C, d: D) -> E,
+    "# use fp_std::curry::curry4;"
+    "let ip = curry4(|a: u8, b: u8, c: u8, d: u8| format!(\"{}.{}.{}.{}\", a, b, c, d));"
+    "let ip = uncurry4(ip);"
+    "assert_eq!(ip(127, 0, 0, 1), \"127.0.0.1\");"
+);
+uncurry_n!(
+    [uncurry5, curry5, Curried5](a: A, b: B, c: C, d: D, e: E) -> G,
+    "# use fp_std::curry::curry5;"
+    "let sum = uncurry5(curry5(|a: u32, b: u32, c: u32, d: u32, e: u32| a + b + c + d + e));"
+    "assert_eq!(sum(1, 2, 3, 4, 5), 15);"
+);
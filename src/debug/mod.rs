@@ -0,0 +1,82 @@
+//! Functions for inspecting values in the middle of a pipeline.
+
+/* SHOWING VALUES *************************************************************/
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Debug;
+
+/// Formats a value using its `Debug` representation.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::debug::show;
+/// # use fp_std::either::Either;
+/// assert_eq!(show((1, "a")), r#"(1, "a")"#);
+/// assert_eq!(show(Either::<u8, char>::Left(3)), "Left(3)");
+/// let shown: Vec<String> = vec![Some(1), None].into_iter().map(show).collect();
+/// assert_eq!(shown, vec!["Some(1)", "None"]);
+/// ```
+pub fn show<A: Debug>(a: A) -> String {
+    format!("{:?}", a)
+}
+
+/// Formats a value using its pretty-printed `Debug` representation, which places nested values
+/// on their own lines.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::debug::show_pretty;
+/// # use fp_std::either::Either;
+/// assert_eq!(show_pretty((1, "a")), "(\n    1,\n    \"a\",\n)");
+/// assert_eq!(show_pretty(Either::<u8, char>::Right('b')), "Right(\n    'b',\n)");
+/// ```
+pub fn show_pretty<A: Debug>(a: A) -> String {
+    format!("{:#?}", a)
+}
+
+/* INSPECTING VALUES **********************************************************/
+#[cfg(feature = "std")]
+use core::fmt::Display;
+
+/// Creates a function which prints its argument to standard error, as formatted by the given
+/// function, then returns it unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::debug::inspect_with;
+/// let total: u32 = vec![1, 2, 3]
+///     .into_iter()
+///     .map(inspect_with(|x: &u32| format!("item: {}", x)))
+///     .sum();
+/// assert_eq!(total, 6);
+/// ```
+#[cfg(feature = "std")]
+pub fn inspect_with<A, S, F>(formatter: F) -> impl Fn(A) -> A
+where
+    S: Display,
+    F: Fn(&A) -> S,
+{
+    move |a| {
+        std::eprintln!("{}", formatter(&a));
+        a
+    }
+}
+
+/// Prints a value's pretty-printed `Debug` representation to standard error, then returns it
+/// unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::debug::inspect;
+/// let pairs: Vec<(u32, char)> = vec![(1, 'a'), (2, 'b')].into_iter().map(inspect).collect();
+/// assert_eq!(pairs, vec![(1, 'a'), (2, 'b')]);
+/// ```
+#[cfg(feature = "std")]
+pub fn inspect<A: Debug>(a: A) -> A {
+    std::eprintln!("{:#?}", a);
+    a
+}
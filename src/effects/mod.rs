@@ -0,0 +1,144 @@
+//! Functions for running effects which hold on to resources, making sure that those resources
+//! are always released.
+//!
+//! ```rust
+//! # use fp_std::effects::bracket;
+//! let mut log = vec![];
+//! let total = bracket(
+//!     || vec![1, 2, 3],
+//!     |buffer| buffer.iter().sum::<u32>(),
+//!     |buffer| log.push(format!("released {} items", buffer.len())),
+//! );
+//! assert_eq!(total, 6);
+//! assert_eq!(log, vec!["released 3 items"]);
+//! ```
+
+/* BRACKETS *******************************************************************/
+
+struct Release<R, L: FnOnce(R)>(Option<(R, L)>);
+
+impl<R, L: FnOnce(R)> Drop for Release<R, L> {
+    fn drop(&mut self) {
+        if let Some((resource, release)) = self.0.take() {
+            release(resource);
+        }
+    }
+}
+
+/// Acquires a resource, uses it, and then releases it, returning the result of using it.
+///
+/// The resource is released even if using it panics, in which case the panic continues once
+/// it has been released.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::effects::bracket;
+/// use std::cell::Cell;
+/// use std::panic::{catch_unwind, AssertUnwindSafe};
+///
+/// let open = Cell::new(0);
+/// let result = catch_unwind(AssertUnwindSafe(|| {
+///     bracket(
+///         || open.set(open.get() + 1),
+///         |_| panic!("lost the connection"),
+///         |_| open.set(open.get() - 1),
+///     )
+/// }));
+/// assert!(result.is_err());
+/// assert_eq!(open.get(), 0);
+/// ```
+pub fn bracket<R, T, A, U, L>(acquire: A, use_fn: U, release: L) -> T
+where
+    A: FnOnce() -> R,
+    U: FnOnce(&mut R) -> T,
+    L: FnOnce(R),
+{
+    let mut guard = Release(Some((acquire(), release)));
+    let (resource, _) = guard.0.as_mut().unwrap();
+    use_fn(resource)
+}
+
+/// Acquires a resource if possible, uses it, and then releases it, returning the result of
+/// using it. Whether using the resource succeeds, fails, or panics, it is released. If the
+/// resource could not be acquired, there is nothing to release, and the error is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::effects::try_bracket;
+/// let mut released = vec![];
+/// let parsed = try_bracket(
+///     || Ok("42".to_owned()),
+///     |text| text.parse::<u32>().map_err(|e| e.to_string()),
+///     |text| released.push(text),
+/// );
+/// assert_eq!(parsed, Ok(42));
+///
+/// let parsed = try_bracket(
+///     || Ok("x".to_owned()),
+///     |text| text.parse::<u32>().map_err(|e| e.to_string()),
+///     |text| released.push(text),
+/// );
+/// assert_eq!(parsed, Err("invalid digit found in string".to_owned()));
+/// assert_eq!(released, vec!["42", "x"]);
+/// ```
+pub fn try_bracket<R, T, E, A, U, L>(acquire: A, use_fn: U, release: L) -> Result<T, E>
+where
+    A: FnOnce() -> Result<R, E>,
+    U: FnOnce(&mut R) -> Result<T, E>,
+    L: FnOnce(R),
+{
+    let resource = acquire()?;
+    bracket(|| resource, use_fn, release)
+}
+
+/* ASYNC BRACKETS *************************************************************/
+#[cfg(feature = "async")]
+use futures::future::{BoxFuture, FutureExt};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::panic::{self, AssertUnwindSafe};
+
+/// Acquires a resource asynchronously, uses it, and then releases it, returning the result of
+/// using it. Like [bracket](crate::effects::bracket), the resource is released even if using
+/// it panics.
+///
+/// Since the future which uses the resource borrows it, that future must be boxed.
+///
+/// Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::effects::bracket_async;
+/// # use futures::executor::block_on;
+/// use futures::future::FutureExt;
+/// use std::sync::Mutex;
+///
+/// let pool = &Mutex::new(vec!["conn"]);
+/// let rows = block_on(bracket_async(
+///     async { pool.lock().unwrap().pop().unwrap() },
+///     |conn| async move { format!("rows from {}", conn) }.boxed(),
+///     |conn| async move { pool.lock().unwrap().push(conn) },
+/// ));
+/// assert_eq!(rows, "rows from conn");
+/// assert_eq!(*pool.lock().unwrap(), vec!["conn"]);
+/// ```
+#[cfg(feature = "async")]
+pub async fn bracket_async<R, T, A, U, L, Rel>(acquire: A, use_fn: U, release: L) -> T
+where
+    A: Future<Output = R>,
+    U: for<'r> FnOnce(&'r mut R) -> BoxFuture<'r, T>,
+    L: FnOnce(R) -> Rel,
+    Rel: Future<Output = ()>,
+{
+    let mut resource = acquire.await;
+    let result = AssertUnwindSafe(use_fn(&mut resource)).catch_unwind().await;
+    release(resource).await;
+    match result {
+        Ok(t) => t,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
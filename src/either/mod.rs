@@ -0,0 +1,479 @@
+//! Values which are one of two possible types, or, with [These](crate::either::These), both.
+
+/* EITHER *********************************************************************/
+
+/// A value which is either a `Left` or a `Right`. Unlike `Result`, neither side is considered
+/// to be a failure.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::Either;
+/// let values: Vec<Either<u32, &str>> = vec![Either::Left(1), Either::Right("a")];
+/// assert!(values[0].is_left());
+/// assert!(values[1].is_right());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    /// The left value.
+    Left(L),
+    /// The right value.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Checks whether this is a `Left`.
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(..))
+    }
+
+    /// Checks whether this is a `Right`.
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(..))
+    }
+
+    /// Returns the left value, if this is a `Left`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// assert_eq!(Either::<u32, &str>::Left(1).left(), Some(1));
+    /// assert_eq!(Either::<u32, &str>::Right("a").left(), None);
+    /// ```
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(l) => Some(l),
+            Either::Right(..) => None,
+        }
+    }
+
+    /// Returns the right value, if this is a `Right`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// assert_eq!(Either::<u32, &str>::Left(1).right(), None);
+    /// assert_eq!(Either::<u32, &str>::Right("a").right(), Some("a"));
+    /// ```
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(..) => None,
+            Either::Right(r) => Some(r),
+        }
+    }
+
+    /// Transforms the right value, leaving a left value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// assert_eq!(Either::<u32, u32>::Right(2).map(|x| x * 10), Either::Right(20));
+    /// assert_eq!(Either::<u32, u32>::Left(2).map(|x| x * 10), Either::Left(2));
+    /// ```
+    pub fn map<B, F>(self, f: F) -> Either<L, B>
+    where
+        F: FnOnce(R) -> B,
+    {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(r) => Either::Right(f(r)),
+        }
+    }
+
+    /// Transforms the right value into another `Either`, leaving a left value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// let halve = |x: u32| if x % 2 == 0 { Either::Right(x / 2) } else { Either::Left("odd") };
+    /// assert_eq!(Either::Right(4).and_then(halve), Either::Right(2));
+    /// assert_eq!(Either::Right(3).and_then(halve), Either::Left("odd"));
+    /// ```
+    pub fn and_then<B, F>(self, f: F) -> Either<L, B>
+    where
+        F: FnOnce(R) -> Either<L, B>,
+    {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(r) => f(r),
+        }
+    }
+
+    /// Applies an optional function to the right value, moving the `Option` to the outside. A
+    /// left value is always `Some`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// let parse = |s: &str| s.parse::<u32>().ok();
+    /// assert_eq!(Either::<(), _>::Right("3").traverse(parse), Some(Either::Right(3)));
+    /// assert_eq!(Either::<(), _>::Right("x").traverse(parse), None);
+    /// assert_eq!(Either::<_, &str>::Left(()).traverse(parse), Some(Either::Left(())));
+    /// ```
+    pub fn traverse<B, F>(self, f: F) -> Option<Either<L, B>>
+    where
+        F: FnOnce(R) -> Option<B>,
+    {
+        match self {
+            Either::Left(l) => Some(Either::Left(l)),
+            Either::Right(r) => f(r).map(Either::Right),
+        }
+    }
+
+    /// Iterates over the right value, which is either one item or none.
+    pub fn iter(&self) -> core::option::IntoIter<&R> {
+        match self {
+            Either::Left(..) => None.into_iter(),
+            Either::Right(r) => Some(r).into_iter(),
+        }
+    }
+
+    /// Borrows the value inside, whichever side it is on.
+    pub fn as_ref(&self) -> Either<&L, &R> {
+        match self {
+            Either::Left(l) => Either::Left(l),
+            Either::Right(r) => Either::Right(r),
+        }
+    }
+
+    /// Transforms the left value, leaving a right value unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// assert_eq!(Either::<u32, u32>::Left(2).map_left(|x| x * 10), Either::Left(20));
+    /// assert_eq!(Either::<u32, u32>::Right(2).map_left(|x| x * 10), Either::Right(2));
+    /// ```
+    pub fn map_left<B, F>(self, f: F) -> Either<B, R>
+    where
+        F: FnOnce(L) -> B,
+    {
+        match self {
+            Either::Left(l) => Either::Left(f(l)),
+            Either::Right(r) => Either::Right(r),
+        }
+    }
+
+    /// Transforms the right value, leaving a left value unchanged. This is the same as
+    /// [map](crate::either::Either::map).
+    pub fn map_right<B, F>(self, f: F) -> Either<L, B>
+    where
+        F: FnOnce(R) -> B,
+    {
+        self.map(f)
+    }
+
+    /// Transforms the value with `f` if it is a `Left`, or with `g` if it is a `Right`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// let value: Either<u32, &str> = Either::Right("ab");
+    /// assert_eq!(value.bimap(|n| n + 1, str::len), Either::Right(2));
+    /// ```
+    pub fn bimap<A, B, F, G>(self, f: F, g: G) -> Either<A, B>
+    where
+        F: FnOnce(L) -> A,
+        G: FnOnce(R) -> B,
+    {
+        match self {
+            Either::Left(l) => Either::Left(f(l)),
+            Either::Right(r) => Either::Right(g(r)),
+        }
+    }
+
+    /// Reduces the value to a single type, with `f` if it is a `Left`, or with `g` if it is a
+    /// `Right`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// let describe = |value: Either<u32, &str>| value.fold(|n| n.to_string(), str::to_owned);
+    /// assert_eq!(describe(Either::Left(4)), "4");
+    /// assert_eq!(describe(Either::Right("four")), "four");
+    /// ```
+    pub fn fold<B, F, G>(self, f: F, g: G) -> B
+    where
+        F: FnOnce(L) -> B,
+        G: FnOnce(R) -> B,
+    {
+        match self {
+            Either::Left(l) => f(l),
+            Either::Right(r) => g(r),
+        }
+    }
+
+    /// Exchanges the sides, turning a `Left` into a `Right` and a `Right` into a `Left`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// assert_eq!(Either::<u32, &str>::Left(1).swap(), Either::Right(1));
+    /// ```
+    pub fn swap(self) -> Either<R, L> {
+        match self {
+            Either::Left(l) => Either::Right(l),
+            Either::Right(r) => Either::Left(r),
+        }
+    }
+
+    /// Converts a `Result` into an `Either`, with the error on the left and the success on the
+    /// right.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// assert_eq!(Either::from_result("7".parse::<u8>()), Either::Right(7));
+    /// assert!(Either::from_result("x".parse::<u8>()).is_left());
+    /// ```
+    pub fn from_result(result: Result<R, L>) -> Self {
+        match result {
+            Ok(r) => Either::Right(r),
+            Err(l) => Either::Left(l),
+        }
+    }
+
+    /// Converts this into a `Result`, treating the left value as the error and the right value
+    /// as the success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::Either;
+    /// assert_eq!(Either::<&str, u32>::Right(1).into_result(), Ok(1));
+    /// assert_eq!(Either::<&str, u32>::Left("no").into_result(), Err("no"));
+    /// ```
+    pub fn into_result(self) -> Result<R, L> {
+        match self {
+            Either::Left(l) => Err(l),
+            Either::Right(r) => Ok(r),
+        }
+    }
+}
+
+/// Iterates over the right value, which is either one item or none.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::Either;
+/// let values = vec![Either::Left("a"), Either::Right(1), Either::Right(2)];
+/// let rights: Vec<u32> = values.into_iter().flatten().collect();
+/// assert_eq!(rights, vec![1, 2]);
+/// ```
+impl<L, R> IntoIterator for Either<L, R> {
+    type Item = R;
+    type IntoIter = core::option::IntoIter<R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.right().into_iter()
+    }
+}
+
+impl<'a, L, R> IntoIterator for &'a Either<L, R> {
+    type Item = &'a R;
+    type IntoIter = core::option::IntoIter<&'a R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<L, R> From<Result<R, L>> for Either<L, R> {
+    fn from(result: Result<R, L>) -> Self {
+        Either::from_result(result)
+    }
+}
+
+impl<L, R> From<Either<L, R>> for Result<R, L> {
+    fn from(either: Either<L, R>) -> Self {
+        either.into_result()
+    }
+}
+
+/* ITERATORS ******************************************************************/
+
+/// Iterates over the left values among some `Either`s, skipping the right values.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::{lefts, Either};
+/// let values = vec![Either::Left("a"), Either::Right(1), Either::Left("b")];
+/// assert_eq!(lefts(values).collect::<Vec<_>>(), vec!["a", "b"]);
+/// ```
+pub fn lefts<I, L, R>(iter: I) -> impl Iterator<Item = L>
+where
+    I: IntoIterator<Item = Either<L, R>>,
+{
+    iter.into_iter().filter_map(Either::left)
+}
+
+/// Iterates over the right values among some `Either`s, skipping the left values.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::{rights, Either};
+/// let values = vec![Either::Left("a"), Either::Right(1), Either::Right(2)];
+/// assert_eq!(rights(values).collect::<Vec<_>>(), vec![1, 2]);
+/// ```
+pub fn rights<I, L, R>(iter: I) -> impl Iterator<Item = R>
+where
+    I: IntoIterator<Item = Either<L, R>>,
+{
+    iter.into_iter().filter_map(Either::right)
+}
+
+/* THESE **********************************************************************/
+
+/// A value which is a `Left`, a `Right`, or `Both`. This is what each key of a full join of
+/// two maps has, as produced by [map::full_join](crate::map::full_join).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::These;
+/// let both: These<u32, &str> = These::Both(1, "a");
+/// assert_eq!(both.left(), Some(1));
+/// assert_eq!(These::<u32, &str>::Right("b").left(), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum These<L, R> {
+    /// Only a left value.
+    Left(L),
+    /// Only a right value.
+    Right(R),
+    /// Both a left and a right value.
+    Both(L, R),
+}
+
+impl<L, R> These<L, R> {
+    /// Checks whether this has both values.
+    pub fn is_both(&self) -> bool {
+        matches!(self, These::Both(..))
+    }
+
+    /// Returns the left value, if there is one.
+    pub fn left(self) -> Option<L> {
+        match self {
+            These::Left(l) | These::Both(l, _) => Some(l),
+            These::Right(..) => None,
+        }
+    }
+
+    /// Returns the right value, if there is one.
+    pub fn right(self) -> Option<R> {
+        match self {
+            These::Right(r) | These::Both(_, r) => Some(r),
+            These::Left(..) => None,
+        }
+    }
+
+    /// Splits into the left and right values, either of which may be missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::These;
+    /// assert_eq!(These::<u32, char>::Both(1, 'a').into_options(), (Some(1), Some('a')));
+    /// assert_eq!(These::<u32, char>::Left(1).into_options(), (Some(1), None));
+    /// ```
+    pub fn into_options(self) -> (Option<L>, Option<R>) {
+        match self {
+            These::Left(l) => (Some(l), None),
+            These::Right(r) => (None, Some(r)),
+            These::Both(l, r) => (Some(l), Some(r)),
+        }
+    }
+
+    /// Combines an optional left and right value, if there is at least one of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::These;
+    /// assert_eq!(These::from_options(Some(1), Some('a')), Some(These::Both(1, 'a')));
+    /// assert_eq!(These::<u32, char>::from_options(None, None), None);
+    /// ```
+    pub fn from_options(left: Option<L>, right: Option<R>) -> Option<Self> {
+        match (left, right) {
+            (Some(l), Some(r)) => Some(These::Both(l, r)),
+            (Some(l), None) => Some(These::Left(l)),
+            (None, Some(r)) => Some(These::Right(r)),
+            (None, None) => None,
+        }
+    }
+
+    /// Transforms each of the values which is present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::either::These;
+    /// let lengths = These::Both("ab", "c").bimap(str::len, str::len);
+    /// assert_eq!(lengths, These::Both(2, 1));
+    /// ```
+    pub fn bimap<A, B, F, G>(self, f: F, g: G) -> These<A, B>
+    where
+        F: FnOnce(L) -> A,
+        G: FnOnce(R) -> B,
+    {
+        match self {
+            These::Left(l) => These::Left(f(l)),
+            These::Right(r) => These::Right(g(r)),
+            These::Both(l, r) => These::Both(f(l), g(r)),
+        }
+    }
+}
+
+/* INSTANCES ******************************************************************/
+#[cfg(feature = "std")]
+use fp_core::foldable::Foldable;
+#[cfg(feature = "std")]
+use fp_core::hkt::HKT;
+
+#[cfg(feature = "std")]
+impl<L, R, U> HKT<U> for Either<L, R> {
+    type Current = R;
+    type Target = Either<L, U>;
+}
+
+/// Folds over the right value, as if it were a list of one item or none.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::Either;
+/// use fp_core::foldable::fold_map;
+/// let total: i64 = fold_map(Either::<&str, i64>::Right(3), |x| *x);
+/// assert_eq!(total, 3);
+/// let total: i64 = fold_map(Either::<&str, i64>::Left("none"), |x| *x);
+/// assert_eq!(total, 0);
+/// ```
+#[cfg(feature = "std")]
+impl<L, R, B> Foldable<B> for Either<L, R> {
+    fn reduce<F>(self, b: B, f: F) -> B
+    where
+        F: Fn(B, &R) -> B,
+    {
+        self.iter().fold(b, f)
+    }
+
+    fn reduce_right<F>(self, b: B, f: F) -> B
+    where
+        F: Fn(&R, B) -> B,
+    {
+        self.iter().fold(b, |b, r| f(r, b))
+    }
+}
@@ -0,0 +1,164 @@
+//! Types whose values can be stepped through in order.
+
+/* ENUMERABLE *****************************************************************/
+
+/// A type whose values form a sequence, in which each value has at most one successor and at
+/// most one predecessor.
+///
+/// With the `derive` feature enabled, `#[derive(Enumerable)]` implements this trait for enums
+/// without fields, stepping through the variants in the order in which they are declared.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::enumerable::Enumerable;
+/// assert_eq!(3u8.succ(), Some(4));
+/// assert_eq!(255u8.succ(), None);
+/// assert_eq!('b'.pred(), Some('a'));
+/// ```
+pub trait Enumerable: Sized {
+    /// The value following this one, if there is one.
+    fn succ(&self) -> Option<Self>;
+
+    /// The value preceding this one, if there is one.
+    fn pred(&self) -> Option<Self>;
+}
+
+#[cfg(feature = "derive")]
+pub use fp_std_derive::Enumerable;
+
+macro_rules! integer_enumerable_impl {
+    ($($t:ty)*) => ($(
+        impl Enumerable for $t {
+            fn succ(&self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn pred(&self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+        }
+    )*)
+}
+
+integer_enumerable_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+impl Enumerable for char {
+    fn succ(&self) -> Option<Self> {
+        match *self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            c => core::char::from_u32(c as u32 + 1),
+        }
+    }
+
+    fn pred(&self) -> Option<Self> {
+        match *self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            c => (c as u32).checked_sub(1).and_then(core::char::from_u32),
+        }
+    }
+}
+
+impl Enumerable for bool {
+    fn succ(&self) -> Option<Self> {
+        if *self {
+            None
+        } else {
+            Some(true)
+        }
+    }
+
+    fn pred(&self) -> Option<Self> {
+        if *self {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+impl Enumerable for () {
+    fn succ(&self) -> Option<Self> {
+        None
+    }
+
+    fn pred(&self) -> Option<Self> {
+        None
+    }
+}
+
+/* FUNCTIONS ******************************************************************/
+
+/// Returns the value following the one provided, if there is one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::enumerable::succ;
+/// assert_eq!(Some(3).and_then(succ), Some(4));
+/// assert_eq!(succ(i8::MAX), None);
+/// ```
+pub fn succ<A: Enumerable>(a: A) -> Option<A> {
+    a.succ()
+}
+
+/// Returns the value preceding the one provided, if there is one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::enumerable::pred;
+/// assert_eq!(Some(3).and_then(pred), Some(2));
+/// assert_eq!(pred(0u32), None);
+/// ```
+pub fn pred<A: Enumerable>(a: A) -> Option<A> {
+    a.pred()
+}
+
+/// Iterates over a value and all of its successors.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::enumerable::enum_from;
+/// let values: Vec<u8> = enum_from(253).collect();
+/// assert_eq!(values, vec![253, 254, 255]);
+/// ```
+pub fn enum_from<A: Enumerable>(from: A) -> impl Iterator<Item = A> {
+    core::iter::successors(Some(from), Enumerable::succ)
+}
+
+/// Iterates over every value from `from` to `to`, inclusive. If `from` comes after `to`, the
+/// iterator is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::enumerable::enum_from_to;
+/// let letters: String = enum_from_to('a', 'e').collect();
+/// assert_eq!(letters, "abcde");
+/// assert_eq!(enum_from_to(5, 1).count(), 0);
+/// ```
+///
+/// With the `derive` feature, this works for enums too:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// # use fp_std::enumerable::{enum_from_to, Enumerable};
+/// #[derive(Enumerable, Copy, Clone, PartialEq, PartialOrd, Debug)]
+/// enum Weekday { Mon, Tue, Wed, Thu, Fri, Sat, Sun }
+///
+/// let weekdays: Vec<_> = enum_from_to(Weekday::Mon, Weekday::Fri).collect();
+/// assert_eq!(
+///     weekdays,
+///     vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+/// );
+/// assert_eq!(Weekday::Sun.succ(), None);
+/// # }
+/// ```
+pub fn enum_from_to<A>(from: A, to: A) -> impl Iterator<Item = A>
+where
+    A: Enumerable + PartialOrd,
+{
+    enum_from(from).take_while(move |a| a <= &to)
+}
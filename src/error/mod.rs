@@ -0,0 +1,68 @@
+//! The errors produced by the fallible functions in this crate.
+
+/* ERRORS *********************************************************************/
+use core::fmt::{self, Display, Formatter};
+
+/// An error produced by one of the fallible functions in this crate.
+///
+/// Conversions between [Validation](crate::validation::Validation) and `Result` keep the
+/// validation's own error type, and so never produce this error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::error::Error;
+/// # use fp_std::interval::Interval;
+/// assert_eq!(Interval::try_new(3, 1), Err(Error::EmptyInterval));
+/// assert_eq!(Error::EmptyInterval.to_string(), "interval is empty");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// An interval was constructed with a start which is not before its end.
+    EmptyInterval,
+    /// An interval was parsed from a string which is not of the form `start..end`.
+    MalformedInterval,
+    /// A bound of an interval could not be parsed.
+    InvalidBound,
+    /// A template referred to a key for which no value was provided.
+    MissingKey,
+    /// A computation was run through a lens which focuses on nothing.
+    MissingFocus,
+    /// A patch was applied to a list which does not contain the items it expects.
+    PatchMismatch,
+    /// A parser failed, or did not consume all of its input.
+    ParseFailed,
+    /// A rate-limited function was called more often than its limit allows.
+    RateLimited,
+    /// A refined value was constructed from a value which does not satisfy its refinement.
+    RefinementFailed,
+    /// A non-empty list was constructed from a list with no items.
+    Empty,
+    /// Multiple items were indexed by the same key.
+    DuplicateKey,
+    /// An affine iso was constructed with a scale of zero, so could not convert backward.
+    ZeroScale,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::EmptyInterval => write!(f, "interval is empty"),
+            Error::MalformedInterval => write!(f, "interval must be of the form `start..end`"),
+            Error::InvalidBound => write!(f, "interval bound is invalid"),
+            Error::MissingKey => write!(f, "template key is missing"),
+            Error::MissingFocus => write!(f, "lens does not focus on anything"),
+            Error::PatchMismatch => write!(f, "patch does not match the list it was applied to"),
+            Error::ParseFailed => write!(f, "input could not be parsed"),
+            Error::RateLimited => write!(f, "rate limit would be exceeded"),
+            Error::RefinementFailed => write!(f, "value does not satisfy its refinement"),
+            Error::Empty => write!(f, "list is empty"),
+            Error::DuplicateKey => write!(f, "key is duplicated"),
+            Error::ZeroScale => write!(f, "scale is zero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
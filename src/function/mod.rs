@@ -2,7 +2,23 @@
 
 /* BASIC FUNCTIONS ************************************************************/
 
-/// Creates a function that always returns the same value.
+/// Returns its argument unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::identity;
+/// assert_eq!(identity(3), 3);
+///
+/// const THREE: u32 = identity(3);
+/// assert_eq!(THREE, 3);
+/// ```
+pub const fn identity<A>(a: A) -> A {
+    a
+}
+
+/// Creates a function that always returns the same value. This is a thunk, the same as
+/// [supply](crate::function::supply) creates, and so works anywhere a thunk is expected.
 ///
 /// # Examples
 ///
@@ -10,9 +26,10 @@
 /// # use fp_std::function::always;
 /// let one = always(1);
 /// assert_eq!(one(), 1);
+/// assert_eq!(None.unwrap_or_else(always(2)), 2);
 /// ```
 pub fn always<A: Clone>(a: A) -> impl Fn() -> A {
-    move || a.clone()
+    supply(a)
 }
 
 /// Flips the arguments of a 2-arity function.
@@ -32,6 +49,23 @@ where
     move |a, b| f(b, a)
 }
 
+/// Flips the elements of the pair taken by a function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::flip_tupled;
+/// let divide = |(a, b): (f64, f64)| a / b;
+/// let divide_into = flip_tupled(divide);
+/// assert_eq!(divide_into((2.0, 6.0)), 3.0);
+/// ```
+pub fn flip_tupled<A, B, C, F>(f: F) -> impl Fn((B, A)) -> C
+where
+    F: Fn((A, B)) -> C,
+{
+    move |(b, a)| f((a, b))
+}
+
 /// Takes only the first argument of a 2-arity function.
 ///
 /// # Examples
@@ -66,14 +100,17 @@ where
     move |_, b| f(b)
 }
 
-/// Supplies the first argument to a 2-arity function.
+/// Supplies the first argument to a 2-arity function. For functions of more arguments, see
+/// [apply_first3](crate::function::apply_first3) and the others like it.
 ///
 /// # Examples
 ///
+/// ```rust
 /// # use fp_std::function::apply_first;
 /// # use std::ops::Add;
 /// let add1 = apply_first(1, usize::add);
 /// assert_eq!(add1(2), 3);
+/// ```
 pub fn apply_first<F, A: Clone, B, C>(a: A, f: F) -> impl Fn(B) -> C
 where
     F: Fn(A, B) -> C,
@@ -81,17 +118,2783 @@ where
     move |b| f(a.clone(), b)
 }
 
-/// Supplies the second argument to a 2-arity function.
+/// Supplies the second argument to a 2-arity function. For functions of more arguments, see
+/// [apply_second3](crate::function::apply_second3) and the others like it.
 ///
 /// # Examples
 ///
+/// ```rust
 /// # use fp_std::function::apply_second;
 /// # use std::ops::Sub;
 /// let sub1 = apply_second(1, usize::sub);
 /// assert_eq!(sub1(2), 1);
+/// ```
 pub fn apply_second<F, A, B: Clone, C>(b: B, f: F) -> impl Fn(A) -> C
 where
     F: Fn(A, B) -> C,
 {
     move |a| f(a, b.clone())
 }
+
+/// Turns a 2-arity function into a function of one argument, which is passed as both of its
+/// arguments.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::join_args;
+/// # use std::ops::Mul;
+/// let square = join_args(u32::mul);
+/// assert_eq!(square(7), 49);
+/// ```
+pub fn join_args<A: Clone, B, F>(f: F) -> impl Fn(A) -> B
+where
+    F: Fn(A, A) -> B,
+{
+    move |a| f(a.clone(), a)
+}
+
+/// Creates a 2-arity function which transforms both of its arguments with `g`, and then
+/// combines the results with `f`. For comparisons of borrowed values, see
+/// [ord::on](crate::ord::on).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::on;
+/// let same_length = on(|a: usize, b: usize| a == b, str::len);
+/// assert!(same_length("abc", "xyz"));
+/// assert!(!same_length("abc", "xy"));
+/// ```
+pub fn on<A, B, C, F, G>(f: F, g: G) -> impl Fn(A, A) -> C
+where
+    F: Fn(B, B) -> C,
+    G: Fn(A) -> B,
+{
+    move |a, b| f(g(a), g(b))
+}
+
+/// Creates a function which passes its argument to `g`, and then passes the result to the
+/// function that `f` returns for the same argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::ap;
+/// let with_len = ap(|s: &str| move |n: usize| format!("{} ({})", s, n), str::len);
+/// assert_eq!(with_len("abc"), "abc (3)");
+/// ```
+pub fn ap<A: Clone, B, C, F, G, H>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(A) -> H,
+    G: Fn(A) -> B,
+    H: FnOnce(B) -> C,
+{
+    move |a| f(a.clone())(g(a))
+}
+
+/* PARTIAL APPLICATION ********************************************************/
+
+macro_rules! apply_nth {
+    (
+        $(#[$meta:meta])*
+        $name:ident($($before:ident $bv:ident),*; $t:ident $tv:ident; $($after:ident $av:ident),*)
+    ) => {
+        $(#[$meta])*
+        pub fn $name<Function, $($before,)* $t: Clone, $($after,)* Output>(
+            $tv: $t,
+            f: Function,
+        ) -> impl Fn($($before,)* $($after),*) -> Output
+        where
+            Function: Fn($($before,)* $t, $($after),*) -> Output,
+        {
+            move |$($bv,)* $($av),*| f($($bv,)* $tv.clone(), $($av),*)
+        }
+    };
+}
+
+apply_nth!(
+    /// Supplies the first argument to a 3-arity function. For 2-arity functions, see
+    /// [apply_first](crate::function::apply_first).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_first3;
+    /// let digits = apply_first3(1, |a, b, c| [a, b, c]);
+    /// assert_eq!(digits(2, 3), [1, 2, 3]);
+    /// ```
+    apply_first3(; A a; B b, C c)
+);
+
+apply_nth!(
+    /// Supplies the second argument to a 3-arity function. For 2-arity functions, see
+    /// [apply_second](crate::function::apply_second).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_second3;
+    /// let digits = apply_second3(2, |a, b, c| [a, b, c]);
+    /// assert_eq!(digits(1, 3), [1, 2, 3]);
+    /// ```
+    apply_second3(A a; B b; C c)
+);
+
+apply_nth!(
+    /// Supplies the third argument to a 3-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_third3;
+    /// let digits = apply_third3(3, |a, b, c| [a, b, c]);
+    /// assert_eq!(digits(1, 2), [1, 2, 3]);
+    /// ```
+    apply_third3(A a, B b; C c; )
+);
+
+apply_nth!(
+    /// Supplies the first argument to a 4-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_first4;
+    /// let digits = apply_first4(1, |a, b, c, d| [a, b, c, d]);
+    /// assert_eq!(digits(2, 3, 4), [1, 2, 3, 4]);
+    /// ```
+    apply_first4(; A a; B b, C c, D d)
+);
+
+apply_nth!(
+    /// Supplies the second argument to a 4-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_second4;
+    /// let digits = apply_second4(2, |a, b, c, d| [a, b, c, d]);
+    /// assert_eq!(digits(1, 3, 4), [1, 2, 3, 4]);
+    /// ```
+    apply_second4(A a; B b; C c, D d)
+);
+
+apply_nth!(
+    /// Supplies the third argument to a 4-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_third4;
+    /// let digits = apply_third4(3, |a, b, c, d| [a, b, c, d]);
+    /// assert_eq!(digits(1, 2, 4), [1, 2, 3, 4]);
+    /// ```
+    apply_third4(A a, B b; C c; D d)
+);
+
+apply_nth!(
+    /// Supplies the fourth argument to a 4-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fourth4;
+    /// let digits = apply_fourth4(4, |a, b, c, d| [a, b, c, d]);
+    /// assert_eq!(digits(1, 2, 3), [1, 2, 3, 4]);
+    /// ```
+    apply_fourth4(A a, B b, C c; D d; )
+);
+
+apply_nth!(
+    /// Supplies the first argument to a 5-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_first5;
+    /// let digits = apply_first5(1, |a, b, c, d, e| [a, b, c, d, e]);
+    /// assert_eq!(digits(2, 3, 4, 5), [1, 2, 3, 4, 5]);
+    /// ```
+    apply_first5(; A a; B b, C c, D d, E e)
+);
+
+apply_nth!(
+    /// Supplies the second argument to a 5-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_second5;
+    /// let digits = apply_second5(2, |a, b, c, d, e| [a, b, c, d, e]);
+    /// assert_eq!(digits(1, 3, 4, 5), [1, 2, 3, 4, 5]);
+    /// ```
+    apply_second5(A a; B b; C c, D d, E e)
+);
+
+apply_nth!(
+    /// Supplies the third argument to a 5-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_third5;
+    /// let digits = apply_third5(3, |a, b, c, d, e| [a, b, c, d, e]);
+    /// assert_eq!(digits(1, 2, 4, 5), [1, 2, 3, 4, 5]);
+    /// ```
+    apply_third5(A a, B b; C c; D d, E e)
+);
+
+apply_nth!(
+    /// Supplies the fourth argument to a 5-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fourth5;
+    /// let digits = apply_fourth5(4, |a, b, c, d, e| [a, b, c, d, e]);
+    /// assert_eq!(digits(1, 2, 3, 5), [1, 2, 3, 4, 5]);
+    /// ```
+    apply_fourth5(A a, B b, C c; D d; E e)
+);
+
+apply_nth!(
+    /// Supplies the fifth argument to a 5-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fifth5;
+    /// let digits = apply_fifth5(5, |a, b, c, d, e| [a, b, c, d, e]);
+    /// assert_eq!(digits(1, 2, 3, 4), [1, 2, 3, 4, 5]);
+    /// ```
+    apply_fifth5(A a, B b, C c, D d; E e; )
+);
+
+apply_nth!(
+    /// Supplies the first argument to a 6-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_first6;
+    /// let digits = apply_first6(1, |a, b, c, d, e, f| [a, b, c, d, e, f]);
+    /// assert_eq!(digits(2, 3, 4, 5, 6), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    apply_first6(; A a; B b, C c, D d, E e, F f)
+);
+
+apply_nth!(
+    /// Supplies the second argument to a 6-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_second6;
+    /// let digits = apply_second6(2, |a, b, c, d, e, f| [a, b, c, d, e, f]);
+    /// assert_eq!(digits(1, 3, 4, 5, 6), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    apply_second6(A a; B b; C c, D d, E e, F f)
+);
+
+apply_nth!(
+    /// Supplies the third argument to a 6-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_third6;
+    /// let digits = apply_third6(3, |a, b, c, d, e, f| [a, b, c, d, e, f]);
+    /// assert_eq!(digits(1, 2, 4, 5, 6), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    apply_third6(A a, B b; C c; D d, E e, F f)
+);
+
+apply_nth!(
+    /// Supplies the fourth argument to a 6-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fourth6;
+    /// let digits = apply_fourth6(4, |a, b, c, d, e, f| [a, b, c, d, e, f]);
+    /// assert_eq!(digits(1, 2, 3, 5, 6), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    apply_fourth6(A a, B b, C c; D d; E e, F f)
+);
+
+apply_nth!(
+    /// Supplies the fifth argument to a 6-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fifth6;
+    /// let digits = apply_fifth6(5, |a, b, c, d, e, f| [a, b, c, d, e, f]);
+    /// assert_eq!(digits(1, 2, 3, 4, 6), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    apply_fifth6(A a, B b, C c, D d; E e; F f)
+);
+
+apply_nth!(
+    /// Supplies the sixth argument to a 6-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_sixth6;
+    /// let digits = apply_sixth6(6, |a, b, c, d, e, f| [a, b, c, d, e, f]);
+    /// assert_eq!(digits(1, 2, 3, 4, 5), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    apply_sixth6(A a, B b, C c, D d, E e; F f; )
+);
+
+apply_nth!(
+    /// Supplies the first argument to a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_first7;
+    /// let digits = apply_first7(1, |a, b, c, d, e, f, g| [a, b, c, d, e, f, g]);
+    /// assert_eq!(digits(2, 3, 4, 5, 6, 7), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    apply_first7(; A a; B b, C c, D d, E e, F f, G g)
+);
+
+apply_nth!(
+    /// Supplies the second argument to a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_second7;
+    /// let digits = apply_second7(2, |a, b, c, d, e, f, g| [a, b, c, d, e, f, g]);
+    /// assert_eq!(digits(1, 3, 4, 5, 6, 7), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    apply_second7(A a; B b; C c, D d, E e, F f, G g)
+);
+
+apply_nth!(
+    /// Supplies the third argument to a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_third7;
+    /// let digits = apply_third7(3, |a, b, c, d, e, f, g| [a, b, c, d, e, f, g]);
+    /// assert_eq!(digits(1, 2, 4, 5, 6, 7), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    apply_third7(A a, B b; C c; D d, E e, F f, G g)
+);
+
+apply_nth!(
+    /// Supplies the fourth argument to a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fourth7;
+    /// let digits = apply_fourth7(4, |a, b, c, d, e, f, g| [a, b, c, d, e, f, g]);
+    /// assert_eq!(digits(1, 2, 3, 5, 6, 7), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    apply_fourth7(A a, B b, C c; D d; E e, F f, G g)
+);
+
+apply_nth!(
+    /// Supplies the fifth argument to a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fifth7;
+    /// let digits = apply_fifth7(5, |a, b, c, d, e, f, g| [a, b, c, d, e, f, g]);
+    /// assert_eq!(digits(1, 2, 3, 4, 6, 7), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    apply_fifth7(A a, B b, C c, D d; E e; F f, G g)
+);
+
+apply_nth!(
+    /// Supplies the sixth argument to a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_sixth7;
+    /// let digits = apply_sixth7(6, |a, b, c, d, e, f, g| [a, b, c, d, e, f, g]);
+    /// assert_eq!(digits(1, 2, 3, 4, 5, 7), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    apply_sixth7(A a, B b, C c, D d, E e; F f; G g)
+);
+
+apply_nth!(
+    /// Supplies the seventh argument to a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_seventh7;
+    /// let digits = apply_seventh7(7, |a, b, c, d, e, f, g| [a, b, c, d, e, f, g]);
+    /// assert_eq!(digits(1, 2, 3, 4, 5, 6), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    apply_seventh7(A a, B b, C c, D d, E e, F f; G g; )
+);
+
+apply_nth!(
+    /// Supplies the first argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_first8;
+    /// let digits = apply_first8(1, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(2, 3, 4, 5, 6, 7, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_first8(; A a; B b, C c, D d, E e, F f, G g, H h)
+);
+
+apply_nth!(
+    /// Supplies the second argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_second8;
+    /// let digits = apply_second8(2, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(1, 3, 4, 5, 6, 7, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_second8(A a; B b; C c, D d, E e, F f, G g, H h)
+);
+
+apply_nth!(
+    /// Supplies the third argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_third8;
+    /// let digits = apply_third8(3, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(1, 2, 4, 5, 6, 7, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_third8(A a, B b; C c; D d, E e, F f, G g, H h)
+);
+
+apply_nth!(
+    /// Supplies the fourth argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fourth8;
+    /// let digits = apply_fourth8(4, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(1, 2, 3, 5, 6, 7, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_fourth8(A a, B b, C c; D d; E e, F f, G g, H h)
+);
+
+apply_nth!(
+    /// Supplies the fifth argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_fifth8;
+    /// let digits = apply_fifth8(5, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(1, 2, 3, 4, 6, 7, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_fifth8(A a, B b, C c, D d; E e; F f, G g, H h)
+);
+
+apply_nth!(
+    /// Supplies the sixth argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_sixth8;
+    /// let digits = apply_sixth8(6, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(1, 2, 3, 4, 5, 7, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_sixth8(A a, B b, C c, D d, E e; F f; G g, H h)
+);
+
+apply_nth!(
+    /// Supplies the seventh argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_seventh8;
+    /// let digits = apply_seventh8(7, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(1, 2, 3, 4, 5, 6, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_seventh8(A a, B b, C c, D d, E e, F f; G g; H h)
+);
+
+apply_nth!(
+    /// Supplies the eighth argument to an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::apply_eighth8;
+    /// let digits = apply_eighth8(8, |a, b, c, d, e, f, g, h| [a, b, c, d, e, f, g, h]);
+    /// assert_eq!(digits(1, 2, 3, 4, 5, 6, 7), [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    apply_eighth8(A a, B b, C c, D d, E e, F f, G g; H h; )
+);
+
+/// Rotates the arguments of a 3-arity function, so that its first argument is taken last and
+/// the others move forward. Rotating three times gives back the original order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::rotate_args;
+/// let describe = |name: &str, age: u32, city: &str| format!("{}, {}, of {}", name, age, city);
+/// let rotated = rotate_args(describe);
+/// assert_eq!(rotated(30, "Oslo", "ann"), "ann, 30, of Oslo");
+/// ```
+pub fn rotate_args<A, B, C, D, F>(f: F) -> impl Fn(B, C, A) -> D
+where
+    F: Fn(A, B, C) -> D,
+{
+    move |b, c, a| f(a, b, c)
+}
+
+/* COMPOSITION ****************************************************************/
+
+/// Composes two functions, right to left: the result applies `g`, and then `f` to the result
+/// of that.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose2;
+/// let shout = compose2(|s: String| s + "!", |s: &str| s.to_uppercase());
+/// assert_eq!(shout("hey"), "HEY!");
+/// ```
+pub fn compose2<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(B) -> C,
+    G: Fn(A) -> B,
+{
+    move |a| f(g(a))
+}
+
+/// Composes three functions, right to left: the result applies `h`, then `g`, and then `f`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose3;
+/// let label = compose3(|n: usize| format!("{} chars", n), str::len, str::trim);
+/// assert_eq!(label("  hello "), "5 chars");
+/// ```
+pub fn compose3<A, B, C, D, F, G, H>(f: F, g: G, h: H) -> impl Fn(A) -> D
+where
+    F: Fn(C) -> D,
+    G: Fn(B) -> C,
+    H: Fn(A) -> B,
+{
+    move |a| f(g(h(a)))
+}
+
+/// Composes any number of functions, right to left, so that `compose!(f, g, h)(x)` is
+/// `f(g(h(x)))`. See also [pipe!](crate::pipe), which composes left to right.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::compose;
+/// let normalize = compose!(
+///     |s: String| s.replace(' ', "-"),
+///     |s: &str| s.to_lowercase(),
+///     str::trim,
+/// );
+/// assert_eq!(normalize("  Hello World "), "hello-world");
+/// ```
+#[macro_export]
+macro_rules! compose {
+    ($f:expr $(,)?) => {
+        $f
+    };
+    ($f:expr, $($rest:expr),+ $(,)?) => {
+        $crate::function::compose2($f, $crate::compose!($($rest),+))
+    };
+}
+
+/// Composes any number of functions, left to right, so that `pipe!(f, g, h)(x)` is
+/// `h(g(f(x)))`. See also [compose!](crate::compose), which composes right to left.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::pipe;
+/// let parse_and_double = pipe!(
+///     str::trim,
+///     |s: &str| s.parse::<u32>().unwrap_or(0),
+///     |x: u32| x * 2,
+/// );
+/// assert_eq!(parse_and_double(" 21 "), 42);
+/// ```
+#[macro_export]
+macro_rules! pipe {
+    ($f:expr $(,)?) => {
+        $f
+    };
+    ($f:expr, $($rest:expr),+ $(,)?) => {
+        $crate::function::compose2($crate::pipe!($($rest),+), $f)
+    };
+}
+
+/* BRANCHING ******************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Creates a function which applies `on_true` to its argument if it satisfies the predicate,
+/// or `on_false` if it does not.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::if_else;
+/// let describe = if_else(
+///     |n: &i32| *n < 0,
+///     |n| format!("{} below", -n),
+///     |n| format!("{} above", n),
+/// );
+/// assert_eq!(describe(-3), "3 below");
+/// assert_eq!(describe(2), "2 above");
+/// ```
+pub fn if_else<A, B, P, F, G>(pred: P, on_true: F, on_false: G) -> impl Fn(A) -> B
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> B,
+    G: Fn(A) -> B,
+{
+    move |a| if pred(&a) { on_true(a) } else { on_false(a) }
+}
+
+/// Creates a function which applies `f` to its argument if it satisfies the predicate, and
+/// otherwise returns it unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::when;
+/// let clamp_negative = when(|n: &i32| *n < 0, |_| 0);
+/// let clamped: Vec<_> = vec![-2, 3, -1].into_iter().map(clamp_negative).collect();
+/// assert_eq!(clamped, vec![0, 3, 0]);
+/// ```
+pub fn when<A, P, F>(pred: P, f: F) -> impl Fn(A) -> A
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> A,
+{
+    move |a| if pred(&a) { f(a) } else { a }
+}
+
+/// Creates a function which applies `f` to its argument unless it satisfies the predicate, in
+/// which case it is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::unless;
+/// let ensure_slash = unless(|s: &String| s.ends_with('/'), |s| s + "/");
+/// assert_eq!(ensure_slash("a/b".to_owned()), "a/b/");
+/// assert_eq!(ensure_slash("a/b/".to_owned()), "a/b/");
+/// ```
+pub fn unless<A, P, F>(pred: P, f: F) -> impl Fn(A) -> A
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> A,
+{
+    move |a| if pred(&a) { a } else { f(a) }
+}
+
+/// Creates a function which applies the transform of the first branch whose predicate its
+/// argument satisfies, or returns `None` if it satisfies none of them. The predicates are
+/// tested in order, and those after the first which is satisfied are not tested.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::cond;
+/// let branches: Vec<(fn(&u32) -> bool, fn(u32) -> String)> = vec![
+///     (|n| n % 15 == 0, |_| "FizzBuzz".to_owned()),
+///     (|n| n % 3 == 0, |_| "Fizz".to_owned()),
+///     (|n| n % 5 == 0, |_| "Buzz".to_owned()),
+///     (|_| true, |n| n.to_string()),
+/// ];
+/// let fizzbuzz = cond(branches);
+/// let said: Vec<_> = (9..=15).filter_map(fizzbuzz).collect();
+/// assert_eq!(said, vec!["Fizz", "Buzz", "11", "Fizz", "13", "14", "FizzBuzz"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn cond<A, B, I, P, F>(branches: I) -> impl Fn(A) -> Option<B>
+where
+    I: IntoIterator<Item = (P, F)>,
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> B,
+{
+    let branches: Vec<(P, F)> = branches.into_iter().collect();
+    move |a| {
+        let (_, f) = branches.iter().find(|(pred, _)| pred(&a))?;
+        Some(f(a))
+    }
+}
+
+/* FALLIBLE FUNCTIONS *********************************************************/
+
+/// Creates a function which calls a fallible function, and transforms its result with `g` if
+/// it succeeds.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::map_ok;
+/// let double = map_ok(str::parse::<u32>, |n| n * 2);
+/// assert_eq!(double("21"), Ok(42));
+/// assert!(double("x").is_err());
+/// ```
+pub fn map_ok<A, T, U, E, F, G>(f: F, g: G) -> impl Fn(A) -> Result<U, E>
+where
+    F: Fn(A) -> Result<T, E>,
+    G: Fn(T) -> U,
+{
+    move |a| f(a).map(&g)
+}
+
+/// Creates a function which calls a fallible function, and transforms its error with `g` if it
+/// fails.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::map_err_of;
+/// let parse = map_err_of(str::parse::<u32>, |e| e.to_string());
+/// assert_eq!(parse("7"), Ok(7));
+/// assert_eq!(parse("x"), Err("invalid digit found in string".to_owned()));
+/// ```
+pub fn map_err_of<A, T, E, D, F, G>(f: F, g: G) -> impl Fn(A) -> Result<T, D>
+where
+    F: Fn(A) -> Result<T, E>,
+    G: Fn(E) -> D,
+{
+    move |a| f(a).map_err(&g)
+}
+
+/// Creates a function which calls a fallible function, and exchanges the success and error of
+/// its result, so that a failure can be handled as the expected outcome.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::swap_channels;
+/// let lookup = |name: &str| match name {
+///     "admin" => Ok(0u32),
+///     _ => Err(format!("unknown user {}", name)),
+/// };
+/// let reason = swap_channels(lookup);
+/// assert_eq!(reason("guest"), Ok("unknown user guest".to_owned()));
+/// assert_eq!(reason("admin"), Err(0));
+/// ```
+pub fn swap_channels<A, T, E, F>(f: F) -> impl Fn(A) -> Result<E, T>
+where
+    F: Fn(A) -> Result<T, E>,
+{
+    move |a| match f(a) {
+        Ok(t) => Err(t),
+        Err(e) => Ok(e),
+    }
+}
+
+/// Composes two optional functions, right to left: the result applies `g`, and then `f` to its
+/// value, if it has one. See [compose2](crate::function::compose2).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose_option;
+/// let first_digit = compose_option(|c: char| c.to_digit(10), |s: &str| s.chars().next());
+/// assert_eq!(first_digit("7up"), Some(7));
+/// assert_eq!(first_digit("up"), None);
+/// assert_eq!(first_digit(""), None);
+/// ```
+pub fn compose_option<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> Option<C>
+where
+    F: Fn(B) -> Option<C>,
+    G: Fn(A) -> Option<B>,
+{
+    move |a| g(a).and_then(&f)
+}
+
+/// Composes two optional functions, left to right: the result applies `f`, and then `g` to its
+/// value, if it has one. See [compose_option](crate::function::compose_option).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::pipe_option;
+/// let port = pipe_option(|s: &str| s.split(':').nth(1), |p: &str| p.parse::<u16>().ok());
+/// assert_eq!(port("localhost:8080"), Some(8080));
+/// assert_eq!(port("localhost"), None);
+/// ```
+pub fn pipe_option<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> Option<C>
+where
+    F: Fn(A) -> Option<B>,
+    G: Fn(B) -> Option<C>,
+{
+    compose_option(g, f)
+}
+
+/// Composes two fallible functions, right to left: the result applies `g`, and then `f` to its
+/// value if it succeeds. The first error is returned. See
+/// [compose2](crate::function::compose2).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose_result;
+/// let half = |n: u32| if n % 2 == 0 { Ok(n / 2) } else { Err(format!("{} is odd", n)) };
+/// let parse = |s: &str| s.parse::<u32>().map_err(|e| e.to_string());
+/// let parse_half = compose_result(half, parse);
+/// assert_eq!(parse_half("10"), Ok(5));
+/// assert_eq!(parse_half("7"), Err("7 is odd".to_owned()));
+/// assert_eq!(parse_half("x"), Err("invalid digit found in string".to_owned()));
+/// ```
+pub fn compose_result<A, B, C, E, F, G>(f: F, g: G) -> impl Fn(A) -> Result<C, E>
+where
+    F: Fn(B) -> Result<C, E>,
+    G: Fn(A) -> Result<B, E>,
+{
+    move |a| g(a).and_then(&f)
+}
+
+/// Composes two fallible functions, left to right: the result applies `f`, and then `g` to its
+/// value if it succeeds. See [compose_result](crate::function::compose_result).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::pipe_result;
+/// let parse = |s: &str| s.parse::<u32>().map_err(|e| e.to_string());
+/// let milli = pipe_result(parse, |n| n.checked_mul(1000).ok_or_else(|| "overflow".to_owned()));
+/// assert_eq!(milli("12"), Ok(12000));
+/// assert_eq!(milli("9999999"), Err("overflow".to_owned()));
+/// ```
+pub fn pipe_result<A, B, C, E, F, G>(f: F, g: G) -> impl Fn(A) -> Result<C, E>
+where
+    F: Fn(A) -> Result<B, E>,
+    G: Fn(B) -> Result<C, E>,
+{
+    compose_result(g, f)
+}
+
+/* RECURSION ******************************************************************/
+use core::fmt::{self, Display, Formatter};
+use core::ops::ControlFlow;
+
+/// Creates a recursive function from a non-recursive one, which takes the function to recurse
+/// into as its first argument. This allows recursive closures to be written without naming
+/// them.
+///
+/// Each recursive call uses the stack. For recursion which may run deep, see the
+/// [trampoline](crate::trampoline) module.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::fix;
+/// let factorial = fix(|rec: &dyn Fn(u64) -> u64, n| if n == 0 { 1 } else { n * rec(n - 1) });
+/// assert_eq!(factorial(5), 120);
+///
+/// let fibonacci = fix(|rec: &dyn Fn(u32) -> u32, n| match n {
+///     0 | 1 => n,
+///     _ => rec(n - 1) + rec(n - 2),
+/// });
+/// assert_eq!(fibonacci(10), 55);
+/// ```
+pub fn fix<A, B, F>(f: F) -> impl Fn(A) -> B
+where
+    F: Fn(&dyn Fn(A) -> B, A) -> B,
+{
+    fn go<A, B, F>(f: &F, a: A) -> B
+    where
+        F: Fn(&dyn Fn(A) -> B, A) -> B,
+    {
+        f(&|a| go(f, a), a)
+    }
+    move |a| go(&f, a)
+}
+
+/// The error produced by [loop_with](crate::function::loop_with) when its step function has
+/// not finished within the iteration limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LoopLimitExceeded;
+
+impl Display for LoopLimitExceeded {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "loop did not finish within its iteration limit")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LoopLimitExceeded {}
+
+/// Repeatedly applies a step function to an accumulator, starting from `init`, until it breaks
+/// with a result. The step function is applied at most `max_iters` times, after which the
+/// loop fails instead of running forever.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::{loop_with, LoopLimitExceeded};
+/// # use std::ops::ControlFlow;
+/// // Newton's method for the square root of 2.
+/// let sqrt2 = loop_with(50, 1.0f64, |x| {
+///     let next = (x + 2.0 / x) / 2.0;
+///     if (next - x).abs() < 1e-12 {
+///         ControlFlow::Break(next)
+///     } else {
+///         ControlFlow::Continue(next)
+///     }
+/// });
+/// assert!((sqrt2.unwrap() - 2f64.sqrt()).abs() < 1e-12);
+///
+/// let never = loop_with(10, 0u32, |n| ControlFlow::<(), _>::Continue(n + 1));
+/// assert_eq!(never, Err(LoopLimitExceeded));
+/// ```
+pub fn loop_with<Acc, Done, F>(
+    max_iters: usize,
+    init: Acc,
+    step: F,
+) -> Result<Done, LoopLimitExceeded>
+where
+    F: Fn(Acc) -> ControlFlow<Done, Acc>,
+{
+    let mut acc = init;
+    for _ in 0..max_iters {
+        match step(acc) {
+            ControlFlow::Continue(next) => acc = next,
+            ControlFlow::Break(done) => return Ok(done),
+        }
+    }
+    Err(LoopLimitExceeded)
+}
+
+/* STATEFUL AND ONE-SHOT VARIANTS *********************************************/
+
+/// Flips the arguments of a 2-arity function which may mutate its state. See
+/// [flip](crate::function::flip).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::flip_mut;
+/// let mut log = Vec::new();
+/// let mut record = flip_mut(|name: &str, count: u32| log.push(format!("{}={}", name, count)));
+/// record(1, "a");
+/// record(2, "b");
+/// drop(record);
+/// assert_eq!(log, vec!["a=1", "b=2"]);
+/// ```
+pub fn flip_mut<A, B, C, F>(mut f: F) -> impl FnMut(B, A) -> C
+where
+    F: FnMut(A, B) -> C,
+{
+    move |a, b| f(b, a)
+}
+
+/// Flips the arguments of a 2-arity function which can only be called once. See
+/// [flip](crate::function::flip).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::flip_once;
+/// let name = String::from("ann");
+/// let greet = flip_once(move |greeting: &str, punctuation: char| {
+///     format!("{}, {}{}", greeting, name, punctuation)
+/// });
+/// assert_eq!(greet('!', "Hi"), "Hi, ann!");
+/// ```
+pub fn flip_once<A, B, C, F>(f: F) -> impl FnOnce(B, A) -> C
+where
+    F: FnOnce(A, B) -> C,
+{
+    move |a, b| f(b, a)
+}
+
+/// Flips the elements of the pair taken by a function which may mutate its state. See
+/// [flip_tupled](crate::function::flip_tupled).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::flip_tupled_mut;
+/// let mut total = 0;
+/// let pairs = vec![(2, "b"), (1, "a")];
+/// pairs.into_iter().for_each(flip_tupled_mut(|(_, n): (&str, u32)| total += n));
+/// assert_eq!(total, 3);
+/// ```
+pub fn flip_tupled_mut<A, B, C, F>(mut f: F) -> impl FnMut((B, A)) -> C
+where
+    F: FnMut((A, B)) -> C,
+{
+    move |(b, a)| f((a, b))
+}
+
+/// Flips the elements of the pair taken by a function which can only be called once. See
+/// [flip_tupled](crate::function::flip_tupled).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::flip_tupled_once;
+/// let mut names = vec!["ann"];
+/// let push = flip_tupled_once(move |(name, _): (&str, u32)| {
+///     names.push(name);
+///     names
+/// });
+/// assert_eq!(push((1, "bo")), vec!["ann", "bo"]);
+/// ```
+pub fn flip_tupled_once<A, B, C, F>(f: F) -> impl FnOnce((B, A)) -> C
+where
+    F: FnOnce((A, B)) -> C,
+{
+    move |(b, a)| f((a, b))
+}
+
+/// Takes only the first argument of a 2-arity function which may mutate its state. See
+/// [first_arg](crate::function::first_arg).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::first_arg_mut;
+/// let mut seen = Vec::new();
+/// let mut visit = first_arg_mut(|x: u32| seen.push(x));
+/// visit(1, "ignored");
+/// visit(2, "ignored");
+/// drop(visit);
+/// assert_eq!(seen, vec![1, 2]);
+/// ```
+pub fn first_arg_mut<A, B, C, F>(mut f: F) -> impl FnMut(A, B) -> C
+where
+    F: FnMut(A) -> C,
+{
+    move |a, _| f(a)
+}
+
+/// Takes only the first argument of a 2-arity function which can only be called once. See
+/// [first_arg](crate::function::first_arg).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::first_arg_once;
+/// let owned = vec![1, 2];
+/// let extend = first_arg_once(move |x: u32| owned.into_iter().chain(Some(x)).collect::<Vec<_>>());
+/// assert_eq!(extend(3, ()), vec![1, 2, 3]);
+/// ```
+pub fn first_arg_once<A, B, C, F>(f: F) -> impl FnOnce(A, B) -> C
+where
+    F: FnOnce(A) -> C,
+{
+    move |a, _| f(a)
+}
+
+/// Takes only the second argument of a 2-arity function which may mutate its state. See
+/// [second_arg](crate::function::second_arg).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::second_arg_mut;
+/// let mut seen = Vec::new();
+/// let mut visit = second_arg_mut(|x: u32| seen.push(x));
+/// visit("ignored", 1);
+/// drop(visit);
+/// assert_eq!(seen, vec![1]);
+/// ```
+pub fn second_arg_mut<A, B, C, F>(mut f: F) -> impl FnMut(A, B) -> C
+where
+    F: FnMut(B) -> C,
+{
+    move |_, b| f(b)
+}
+
+/// Takes only the second argument of a 2-arity function which can only be called once. See
+/// [second_arg](crate::function::second_arg).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::second_arg_once;
+/// let prefix = String::from("id-");
+/// let label = second_arg_once(move |n: u32| prefix + &n.to_string());
+/// assert_eq!(label((), 7), "id-7");
+/// ```
+pub fn second_arg_once<A, B, C, F>(f: F) -> impl FnOnce(A, B) -> C
+where
+    F: FnOnce(B) -> C,
+{
+    move |_, b| f(b)
+}
+
+/// Supplies the first argument to a 2-arity function which may mutate its state. See
+/// [apply_first](crate::function::apply_first).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::apply_first_mut;
+/// let mut log = Vec::new();
+/// let mut warn = apply_first_mut("warn", |level: &str, message: &str| {
+///     log.push(format!("[{}] {}", level, message))
+/// });
+/// warn("low disk");
+/// drop(warn);
+/// assert_eq!(log, vec!["[warn] low disk"]);
+/// ```
+pub fn apply_first_mut<F, A: Clone, B, C>(a: A, mut f: F) -> impl FnMut(B) -> C
+where
+    F: FnMut(A, B) -> C,
+{
+    move |b| f(a.clone(), b)
+}
+
+/// Supplies the first argument to a 2-arity function which can only be called once. Since
+/// the argument is only supplied once, it need not be `Clone`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::apply_first_once;
+/// let append = apply_first_once(vec![1], |mut v: Vec<u32>, x: u32| {
+///     v.push(x);
+///     v
+/// });
+/// assert_eq!(append(2), vec![1, 2]);
+/// ```
+pub fn apply_first_once<F, A, B, C>(a: A, f: F) -> impl FnOnce(B) -> C
+where
+    F: FnOnce(A, B) -> C,
+{
+    move |b| f(a, b)
+}
+
+/// Supplies the second argument to a 2-arity function which may mutate its state. See
+/// [apply_second](crate::function::apply_second).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::apply_second_mut;
+/// let mut total = 0;
+/// let mut add_scaled = apply_second_mut(10, |x: u32, scale: u32| total += x * scale);
+/// add_scaled(1);
+/// add_scaled(2);
+/// drop(add_scaled);
+/// assert_eq!(total, 30);
+/// ```
+pub fn apply_second_mut<F, A, B: Clone, C>(b: B, mut f: F) -> impl FnMut(A) -> C
+where
+    F: FnMut(A, B) -> C,
+{
+    move |a| f(a, b.clone())
+}
+
+/// Supplies the second argument to a 2-arity function which can only be called once. Since
+/// the argument is only supplied once, it need not be `Clone`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::apply_second_once;
+/// let prepend = apply_second_once(vec![2], |x: u32, mut v: Vec<u32>| {
+///     v.insert(0, x);
+///     v
+/// });
+/// assert_eq!(prepend(1), vec![1, 2]);
+/// ```
+pub fn apply_second_once<F, A, B, C>(b: B, f: F) -> impl FnOnce(A) -> C
+where
+    F: FnOnce(A, B) -> C,
+{
+    move |a| f(a, b)
+}
+
+/// Composes two functions which may mutate their state, right to left. See
+/// [compose2](crate::function::compose2).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose2_mut;
+/// let mut next_id = 0;
+/// let mut label = compose2_mut(|id: u32| format!("#{}", id), |_: &str| {
+///     next_id += 1;
+///     next_id
+/// });
+/// assert_eq!(label("a"), "#1");
+/// assert_eq!(label("b"), "#2");
+/// ```
+pub fn compose2_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> C
+where
+    F: FnMut(B) -> C,
+    G: FnMut(A) -> B,
+{
+    move |a| f(g(a))
+}
+
+/// Composes two functions which can only be called once, right to left. See
+/// [compose2](crate::function::compose2).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose2_once;
+/// let header = String::from("total: ");
+/// let report = compose2_once(move |n: usize| header + &n.to_string(), |v: Vec<u8>| v.len());
+/// assert_eq!(report(vec![1, 2, 3]), "total: 3");
+/// ```
+pub fn compose2_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> C
+where
+    F: FnOnce(B) -> C,
+    G: FnOnce(A) -> B,
+{
+    move |a| f(g(a))
+}
+
+/// Composes three functions which may mutate their state, right to left. See
+/// [compose3](crate::function::compose3).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose3_mut;
+/// let mut seen = 0;
+/// let mut count = compose3_mut(|n: usize| n * 2, str::len, |s: &str| {
+///     seen += 1;
+///     s.trim()
+/// });
+/// assert_eq!(count(" ab "), 4);
+/// drop(count);
+/// assert_eq!(seen, 1);
+/// ```
+pub fn compose3_mut<A, B, C, D, F, G, H>(mut f: F, mut g: G, mut h: H) -> impl FnMut(A) -> D
+where
+    F: FnMut(C) -> D,
+    G: FnMut(B) -> C,
+    H: FnMut(A) -> B,
+{
+    move |a| f(g(h(a)))
+}
+
+/// Composes three functions which can only be called once, right to left. See
+/// [compose3](crate::function::compose3).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose3_once;
+/// let suffix = String::from("!");
+/// let shout = compose3_once(move |s: String| s + &suffix, |s: &str| s.to_uppercase(), str::trim);
+/// assert_eq!(shout(" hey "), "HEY!");
+/// ```
+pub fn compose3_once<A, B, C, D, F, G, H>(f: F, g: G, h: H) -> impl FnOnce(A) -> D
+where
+    F: FnOnce(C) -> D,
+    G: FnOnce(B) -> C,
+    H: FnOnce(A) -> B,
+{
+    move |a| f(g(h(a)))
+}
+
+/* METHODS ********************************************************************/
+
+/// Adapts a method into a function which takes its receiver as the last argument, so that it
+/// may be used in a pipeline.
+///
+/// Given just the path to a method, `method!(Type::method)` is a function of the receiver
+/// alone. Given the method's other arguments as a list of names, `method!(Type::method(a, b))`
+/// is a function which takes those arguments, and returns a function of the receiver. The
+/// arguments are cloned each time the method is called.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::method;
+/// let lengths: Vec<usize> = vec!["a", "bc"].into_iter().map(method!(str::len)).collect();
+/// assert_eq!(lengths, vec![1, 2]);
+///
+/// let starts_with = method!(str::starts_with(prefix));
+/// let words: Vec<&str> = vec!["apple", "banana", "avocado"]
+///     .into_iter()
+///     .filter(|word| starts_with("a")(word))
+///     .collect();
+/// assert_eq!(words, vec!["apple", "avocado"]);
+///
+/// let replace = method!(str::replace(from, to));
+/// let censor = replace("bad", "***");
+/// assert_eq!(censor("a bad word"), "a *** word");
+/// ```
+#[macro_export]
+macro_rules! method {
+    ($($path:ident)::+) => {
+        |receiver| $($path)::+(receiver)
+    };
+    ($($path:ident)::+ ($($arg:ident),* $(,)?)) => {
+        move |$($arg),*| move |receiver| $($path)::+(receiver, $(::core::clone::Clone::clone(&$arg)),*)
+    };
+}
+
+/* PIPING *********************************************************************/
+
+/// Passes values to functions with method syntax, so that a value can flow left to right
+/// through free functions, such as the ones in this crate, alongside ordinary method calls.
+///
+/// Implemented for every sized type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::Pipe;
+/// # use fp_std::list::chunks_of;
+/// let rows: Vec<String> = (1..=5)
+///     .pipe(chunks_of(2))
+///     .map(|row| row.iter().map(u32::to_string).collect::<Vec<_>>().join(" "))
+///     .collect();
+/// assert_eq!(rows, vec!["1 2", "3 4", "5"]);
+/// ```
+pub trait Pipe: Sized {
+    /// Passes the value to a function, returning its result.
+    fn pipe<B, F>(self, f: F) -> B
+    where
+        F: FnOnce(Self) -> B,
+    {
+        f(self)
+    }
+
+    /// Passes a borrow of the value to a function, returning its result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::Pipe;
+    /// let name = String::from("ferris");
+    /// assert_eq!(name.pipe_ref(String::len), 6);
+    /// assert_eq!(name, "ferris");
+    /// ```
+    fn pipe_ref<B, F>(&self, f: F) -> B
+    where
+        F: FnOnce(&Self) -> B,
+    {
+        f(self)
+    }
+}
+
+impl<T> Pipe for T {}
+
+/* OPERATORS ******************************************************************/
+use core::ops::{BitOr, Shr};
+
+/// A function of one argument, or a composition of such functions. Implemented for every
+/// `Fn(A) -> B`, and for [Chain](crate::function::Chain).
+///
+/// Since the [Fn] traits cannot be implemented outside of the standard library, this is what
+/// lets a composition built with [Func](crate::function::Func) operators be called.
+pub trait Callable<A> {
+    /// The result of the function.
+    type Output;
+
+    /// Calls the function.
+    fn call(&self, a: A) -> Self::Output;
+}
+
+impl<A, B, F> Callable<A> for F
+where
+    F: Fn(A) -> B,
+{
+    type Output = B;
+
+    fn call(&self, a: A) -> B {
+        self(a)
+    }
+}
+
+/// The composition of two functions, left to right: the result of `first` is passed to
+/// `second`. Constructed by composing [Func](crate::function::Func)s.
+#[derive(Clone, Copy, Debug)]
+pub struct Chain<F, G> {
+    first: F,
+    second: G,
+}
+
+impl<A, F, G> Callable<A> for Chain<F, G>
+where
+    F: Callable<A>,
+    G: Callable<F::Output>,
+{
+    type Output = G::Output;
+
+    fn call(&self, a: A) -> G::Output {
+        self.second.call(self.first.call(a))
+    }
+}
+
+/// A wrapper around a function, which composes with other wrapped functions using operators,
+/// as an alternative to [compose!](crate::compose) and [pipe!](crate::pipe).
+///
+/// Both `Func(f) >> Func(g)` and `Func(f) | Func(g)` compose left to right, into a function
+/// which applies `f` and then `g`. The composition can be called with
+/// [call](crate::function::Func::call), or turned back into a closure with
+/// [into_fn](crate::function::Func::into_fn).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::Func;
+/// let slug = Func(str::trim) >> Func(str::to_lowercase) | Func(|s: String| s.replace(' ', "-"));
+/// assert_eq!(slug.call("  Hello World "), "hello-world");
+///
+/// let slugs: Vec<String> = vec!["A B", "c"].into_iter().map(slug.into_fn()).collect();
+/// assert_eq!(slugs, vec!["a-b", "c"]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Func<F>(pub F);
+
+impl<F> Func<F> {
+    /// Calls the function.
+    pub fn call<A>(&self, a: A) -> F::Output
+    where
+        F: Callable<A>,
+    {
+        self.0.call(a)
+    }
+
+    /// Converts the wrapped function into a closure, which can be passed anywhere an `Fn` is
+    /// expected.
+    pub fn into_fn<A>(self) -> impl Fn(A) -> F::Output
+    where
+        F: Callable<A>,
+    {
+        move |a| self.0.call(a)
+    }
+}
+
+impl<F, G> Shr<Func<G>> for Func<F> {
+    type Output = Func<Chain<F, G>>;
+
+    fn shr(self, next: Func<G>) -> Self::Output {
+        Func(Chain {
+            first: self.0,
+            second: next.0,
+        })
+    }
+}
+
+impl<F, G> BitOr<Func<G>> for Func<F> {
+    type Output = Func<Chain<F, G>>;
+
+    fn bitor(self, next: Func<G>) -> Self::Output {
+        Func(Chain {
+            first: self.0,
+            second: next.0,
+        })
+    }
+}
+
+/* CONSTRUCTORS ***************************************************************/
+
+/// Wraps a value in `Some`. Useful where a function is expected, in place of `|x| Some(x)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::some;
+/// let options: Vec<Option<u32>> = vec![1, 2].into_iter().map(some).collect();
+/// assert_eq!(options, vec![Some(1), Some(2)]);
+/// ```
+pub const fn some<A>(a: A) -> Option<A> {
+    Some(a)
+}
+
+/// Wraps a value in `Ok`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::ok;
+/// let results: Vec<Result<u32, ()>> = vec![1, 2].into_iter().map(ok).collect();
+/// assert_eq!(results, vec![Ok(1), Ok(2)]);
+/// ```
+pub const fn ok<A, E>(a: A) -> Result<A, E> {
+    Ok(a)
+}
+
+/// Wraps a value in `Err`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::err;
+/// let result: Result<u32, &str> = Some("missing").map_or(Ok(0), err);
+/// assert_eq!(result, Err("missing"));
+/// ```
+pub const fn err<A, E>(e: E) -> Result<A, E> {
+    Err(e)
+}
+
+/// Creates a function which discards its argument and fails with the given error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::err_with;
+/// let positive = |x: i32| if x > 0 { Ok(x) } else { err_with("not positive")(x) };
+/// assert_eq!(positive(3), Ok(3));
+/// assert_eq!(positive(-3), Err("not positive"));
+/// ```
+pub fn err_with<A, B, E: Clone>(e: E) -> impl Fn(A) -> Result<B, E> {
+    move |_| Err(e.clone())
+}
+
+/// Creates a function which pairs its argument with the given value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::pair_with;
+/// let pairs: Vec<(u32, bool)> = vec![1, 2].into_iter().map(pair_with(true)).collect();
+/// assert_eq!(pairs, vec![(1, true), (2, true)]);
+/// ```
+pub fn pair_with<A, B: Clone>(b: B) -> impl Fn(A) -> (A, B) {
+    move |a| (a, b.clone())
+}
+
+/// Creates a function which builds a `Vec` of `n` copies of its argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::vec_of;
+/// assert_eq!(Some('a').map(vec_of(3)), Some(vec!['a', 'a', 'a']));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn vec_of<A: Clone>(n: usize) -> impl Fn(A) -> alloc::vec::Vec<A> {
+    move |a| alloc::vec![a; n]
+}
+
+/* THUNKS *********************************************************************/
+
+/// Converts a value into a thunk: a function of no arguments which returns a copy of it. Such
+/// functions can be passed to `Option::unwrap_or_else` and the like, or to
+/// [defer](crate::function::defer).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::supply;
+/// let fallback = supply(vec![0]);
+/// assert_eq!(None.unwrap_or_else(&fallback), vec![0]);
+/// assert_eq!(Some(vec![1]).unwrap_or_else(&fallback), vec![1]);
+/// ```
+pub fn supply<A: Clone>(a: A) -> impl Fn() -> A {
+    move || a.clone()
+}
+
+/// Delays calling a function until its result is needed, by creating a thunk which calls it
+/// with the value produced by another thunk. Each time the result is called, so are both
+/// functions.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::{defer, supply};
+/// # use std::cell::Cell;
+/// let calls = Cell::new(0);
+/// let expensive = |x: u32| {
+///     calls.set(calls.get() + 1);
+///     x * 2
+/// };
+/// let deferred = defer(expensive, supply(21));
+/// assert_eq!(calls.get(), 0);
+/// assert_eq!(Some(1).unwrap_or_else(&deferred), 1);
+/// assert_eq!(calls.get(), 0);
+/// assert_eq!(None.unwrap_or_else(&deferred), 42);
+/// assert_eq!(calls.get(), 1);
+///
+/// let nested = defer(|x: u32| x + 1, defer(|x: u32| x * 2, supply(5)));
+/// assert_eq!(nested(), 11);
+/// ```
+pub fn defer<A, B, F, T>(f: F, arg: T) -> impl Fn() -> B
+where
+    F: Fn(A) -> B,
+    T: Fn() -> A,
+{
+    move || f(arg())
+}
+
+/* STRUCTS ********************************************************************/
+
+/// Constructs a struct from fields which are each wrapped in an `Option`, or each in a
+/// `Result`, producing the struct wrapped in the same way.
+///
+/// Every field expression is evaluated, in order, before any of them is unwrapped. If any of
+/// them is `None` or `Err`, the result is the first such value. For `Result`, each error must be
+/// convertible into the error type of the first field, as with `?`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::struct_ap;
+/// #[derive(Debug, PartialEq)]
+/// struct User { name: String, age: u8 }
+///
+/// let user = struct_ap!(User { name: Some("Cameron".to_owned()), age: "29".parse().ok() });
+/// assert_eq!(user, Some(User { name: "Cameron".to_owned(), age: 29 }));
+///
+/// let user = struct_ap!(User { name: Some("Cameron".to_owned()), age: "old".parse().ok() });
+/// assert_eq!(user, None);
+///
+/// let user = struct_ap!(User {
+///     name: Ok::<_, String>("Cameron".to_owned()),
+///     age: "300".parse::<u8>().map_err(|e| e.to_string()),
+/// });
+/// assert!(user.is_err());
+/// ```
+#[macro_export]
+macro_rules! struct_ap {
+    (
+        $($name:ident)::+ {
+            $first:ident : $first_value:expr
+            $(, $field:ident : $value:expr)* $(,)?
+        }
+    ) => {{
+        let ($first, $($field,)*) = ($first_value, $($value,)*);
+        let wrapper = $crate::function::__wrapper_of(&$first);
+        (move || {
+            let value = $($name)::+ { $first: $first?, $($field: $field?),* };
+            $crate::function::__pure(wrapper, value)
+        })()
+    }};
+}
+
+#[doc(hidden)]
+pub trait __Wrapper {
+    type Wrapped<U>;
+
+    fn pure<U>(u: U) -> Self::Wrapped<U>;
+}
+
+impl<T> __Wrapper for Option<T> {
+    type Wrapped<U> = Option<U>;
+
+    fn pure<U>(u: U) -> Option<U> {
+        Some(u)
+    }
+}
+
+impl<T, E> __Wrapper for Result<T, E> {
+    type Wrapped<U> = Result<U, E>;
+
+    fn pure<U>(u: U) -> Result<U, E> {
+        Ok(u)
+    }
+}
+
+#[doc(hidden)]
+pub fn __wrapper_of<W: __Wrapper>(_: &W) -> core::marker::PhantomData<W> {
+    core::marker::PhantomData
+}
+
+#[doc(hidden)]
+pub fn __pure<W: __Wrapper, U>(_: core::marker::PhantomData<W>, u: U) -> W::Wrapped<U> {
+    W::pure(u)
+}
+
+/* PIPELINES ******************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::iter::FromIterator;
+
+/// A function from `A` to `B` built from a sequence of boxed stages. Unlike a composition of
+/// closures, a pipeline has a nameable type, so it can be assembled at runtime, such as from
+/// configuration, and stored or passed around.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::Pipeline;
+/// let config = vec!["trim", "lowercase"];
+/// let mut pipeline = Pipeline::<String, String>::new();
+/// for step in config {
+///     pipeline = match step {
+///         "trim" => pipeline.then(|s: String| s.trim().to_owned()),
+///         "lowercase" => pipeline.then(|s: String| s.to_lowercase()),
+///         _ => pipeline,
+///     };
+/// }
+/// assert_eq!(pipeline.run("  Hello ".to_owned()), "hello");
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Pipeline<A, B> {
+    run: Box<dyn Fn(A) -> B>,
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Pipeline<A, A> {
+    /// Creates a pipeline with no stages, which returns its input unchanged.
+    pub fn new() -> Self {
+        Pipeline::from_fn(identity)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Default for Pipeline<A, A> {
+    fn default() -> Self {
+        Pipeline::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, B: 'static> Pipeline<A, B> {
+    /// Creates a pipeline with a single stage.
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(A) -> B + 'static,
+    {
+        Pipeline { run: Box::new(f) }
+    }
+
+    /// Adds a stage to the end of this pipeline.
+    pub fn then<C: 'static, F>(self, f: F) -> Pipeline<A, C>
+    where
+        F: Fn(B) -> C + 'static,
+    {
+        Pipeline::from_fn(move |a| f((self.run)(a)))
+    }
+
+    /// Runs the pipeline.
+    pub fn run(&self, a: A) -> B {
+        (self.run)(a)
+    }
+
+    /// Converts this pipeline into a plain function.
+    pub fn into_fn(self) -> impl Fn(A) -> B {
+        move |a| self.run(a)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, B: 'static, E: 'static> Pipeline<A, Result<B, E>> {
+    /// Adds a fallible stage to the end of this fallible pipeline. The stage is skipped if an
+    /// earlier stage has already failed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::function::Pipeline;
+    /// let parse = |s: &'static str| s.parse::<u32>().map_err(|_| "not a number");
+    /// let pipeline = Pipeline::from_fn(parse)
+    ///     .then_try(|n| n.checked_sub(10).ok_or("too small"))
+    ///     .then(|result| result.map(|n| n * 2));
+    /// assert_eq!(pipeline.run("15"), Ok(10));
+    /// assert_eq!(pipeline.run("5"), Err("too small"));
+    /// assert_eq!(pipeline.run("x"), Err("not a number"));
+    /// ```
+    pub fn then_try<C: 'static, F>(self, f: F) -> Pipeline<A, Result<C, E>>
+    where
+        F: Fn(B) -> Result<C, E> + 'static,
+    {
+        self.then(move |result| result.and_then(&f))
+    }
+}
+
+/// Builds a pipeline which runs each of the stages in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::Pipeline;
+/// let stages: Vec<Box<dyn Fn(i32) -> i32>> = vec![Box::new(|x| x + 1), Box::new(|x| x * 10)];
+/// let pipeline: Pipeline<i32, i32> = stages.into_iter().collect();
+/// assert_eq!(pipeline.run(2), 30);
+/// ```
+#[cfg(feature = "alloc")]
+impl<A: 'static, F> FromIterator<F> for Pipeline<A, A>
+where
+    F: Fn(A) -> A + 'static,
+{
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        iter.into_iter().fold(Pipeline::new(), Pipeline::then)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A, B> fmt::Debug for Pipeline<A, B> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Pipeline(..)")
+    }
+}
+
+/// Threads a value through a sequence of fallible stages, so that
+/// `pipe_try!(x => f => g)` is `g(f(x)?)`, with each error converted by `From` as `?` does.
+/// The first stage to fail stops the pipeline, and its error is returned.
+///
+/// Since every error is converted, the error type of the result must be known from its
+/// context, such as by annotating the variable it is assigned to.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::pipe_try;
+/// # use std::num::ParseIntError;
+/// #[derive(Debug, PartialEq)]
+/// enum AppError {
+///     Parse,
+///     Range(u32),
+/// }
+///
+/// impl From<ParseIntError> for AppError {
+///     fn from(_: ParseIntError) -> Self {
+///         AppError::Parse
+///     }
+/// }
+///
+/// let in_range = |n: u32| if n <= 100 { Ok(n) } else { Err(AppError::Range(n)) };
+/// let percent = |s: &str| -> Result<u32, AppError> {
+///     pipe_try!(s => str::parse::<u32> => in_range => |n: u32| in_range(n * 2))
+/// };
+/// assert_eq!(percent("21"), Ok(42));
+/// assert_eq!(percent("210"), Err(AppError::Range(210)));
+/// assert_eq!(percent("x"), Err(AppError::Parse));
+/// ```
+#[macro_export]
+macro_rules! pipe_try {
+    ($value:expr $(=> $stage:expr)* $(,)?) => {
+        'pipe_try: {
+            let value = $value;
+            $(
+                let value = match $stage(value) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(error) => {
+                        break 'pipe_try ::core::result::Result::Err(
+                            ::core::convert::From::from(error),
+                        );
+                    }
+                };
+            )*
+            ::core::result::Result::Ok(value)
+        }
+    };
+}
+
+/* MEMOIZATION ****************************************************************/
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+#[cfg(feature = "std")]
+use std::ops::Deref;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "time")]
+use std::time::{Duration, Instant};
+
+/// Creates a function which caches the results of another, so that it is only called once for
+/// each distinct argument.
+///
+/// The cache is not thread safe. To share the function between threads, see
+/// [memoize_sync](crate::function::memoize_sync).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::memoize;
+/// # use std::cell::Cell;
+/// let calls = Cell::new(0);
+/// let slow_square = memoize(|x: u64| {
+///     calls.set(calls.get() + 1);
+///     x * x
+/// });
+/// let squares: Vec<u64> = vec![3, 4, 3, 3].into_iter().map(&slow_square).collect();
+/// assert_eq!(squares, vec![9, 16, 9, 9]);
+/// assert_eq!(calls.get(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn memoize<A, B, F>(f: F) -> impl Fn(A) -> B
+where
+    A: Eq + Hash + Clone,
+    B: Clone,
+    F: Fn(A) -> B,
+{
+    memoize_by(A::clone, f)
+}
+
+/// Creates a function which caches the results of another, as with
+/// [memoize](crate::function::memoize), but which can be shared between threads.
+///
+/// The cache is not locked while `f` is running, so threads which call the function with the
+/// same argument at the same time may each call `f`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::memoize_sync;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use std::sync::Arc;
+/// # use std::thread;
+/// let calls = Arc::new(AtomicUsize::new(0));
+/// let counter = calls.clone();
+/// let slow_square = Arc::new(memoize_sync(move |x: u64| {
+///     counter.fetch_add(1, Ordering::SeqCst);
+///     x * x
+/// }));
+/// assert_eq!(slow_square(3), 9);
+/// let shared = slow_square.clone();
+/// assert_eq!(thread::spawn(move || shared(3)).join().unwrap(), 9);
+/// assert_eq!(calls.load(Ordering::SeqCst), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn memoize_sync<A, B, F>(f: F) -> impl Fn(A) -> B + Send + Sync
+where
+    A: Eq + Hash + Clone + Send,
+    B: Clone + Send,
+    F: Fn(A) -> B + Send + Sync,
+{
+    let cache = Mutex::new(HashMap::new());
+    move |a: A| {
+        if let Some(b) = cache.lock().unwrap().get(&a) {
+            return B::clone(b);
+        }
+        let b = f(a.clone());
+        cache.lock().unwrap().insert(a, b.clone());
+        b
+    }
+}
+
+/// Creates a function which caches the results of another, keyed by a value extracted from
+/// each argument. Arguments with the same key are assumed to produce the same result, so only
+/// the first of them is actually passed to `f`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::memoize_by;
+/// # use std::cell::Cell;
+/// struct Request { id: u32, payload: Vec<u8> }
+///
+/// let calls = Cell::new(0);
+/// let size = memoize_by(|req: &Request| req.id, |req: Request| {
+///     calls.set(calls.get() + 1);
+///     req.payload.len()
+/// });
+/// assert_eq!(size(Request { id: 1, payload: vec![1, 2, 3] }), 3);
+/// assert_eq!(size(Request { id: 1, payload: vec![] }), 3);
+/// assert_eq!(size(Request { id: 2, payload: vec![] }), 0);
+/// assert_eq!(calls.get(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn memoize_by<A, B, K, KF, F>(key_fn: KF, f: F) -> impl Fn(A) -> B
+where
+    K: Eq + Hash,
+    B: Clone,
+    KF: Fn(&A) -> K,
+    F: Fn(A) -> B,
+{
+    let cache = RefCell::new(HashMap::new());
+    move |a| {
+        let key = key_fn(&a);
+        if let Some(b) = cache.borrow().get(&key) {
+            return B::clone(b);
+        }
+        let b = f(a);
+        cache.borrow_mut().insert(key, b.clone());
+        b
+    }
+}
+
+/// Creates a function which caches the results of another, keyed by the address of the value
+/// behind each argument, such as an `Rc` or `Arc`. Arguments which point to the same value are
+/// assumed to produce the same result, however deep that value is, and without requiring it to
+/// implement `Hash` or `Eq`.
+///
+/// The cache holds a clone of each argument, which keeps the value it points to alive so that
+/// its address cannot be reused by a different value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::cache_by_ptr;
+/// # use std::cell::Cell;
+/// # use std::rc::Rc;
+/// let calls = Cell::new(0);
+/// let total = cache_by_ptr(|values: Rc<Vec<u32>>| {
+///     calls.set(calls.get() + 1);
+///     values.iter().sum::<u32>()
+/// });
+/// let shared = Rc::new(vec![1, 2, 3]);
+/// assert_eq!(total(shared.clone()), 6);
+/// assert_eq!(total(shared), 6);
+/// assert_eq!(total(Rc::new(vec![1, 2, 3])), 6);
+/// assert_eq!(calls.get(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn cache_by_ptr<P, B, F>(f: F) -> impl Fn(P) -> B
+where
+    P: Deref + Clone,
+    B: Clone,
+    F: Fn(P) -> B,
+{
+    let cache = RefCell::new(HashMap::<*const (), (P, B)>::new());
+    move |p| {
+        let key = &*p as *const P::Target as *const ();
+        if let Some((_, b)) = cache.borrow().get(&key) {
+            return B::clone(b);
+        }
+        let b = f(p.clone());
+        cache.borrow_mut().insert(key, (p, b.clone()));
+        b
+    }
+}
+
+/// Creates a function which caches the results of another for a limited time. Once an entry is
+/// older than `ttl`, the next call with that argument computes the result again.
+///
+/// Each call which misses the cache first removes every expired entry, so the cache holds only
+/// the results computed within the last `ttl`, however many distinct arguments there have been.
+///
+/// Requires the `time` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::bound_memo;
+/// # use std::cell::Cell;
+/// # use std::time::Duration;
+/// let calls = Cell::new(0);
+/// let rate = bound_memo(Duration::from_secs(60), |currency: &str| {
+///     calls.set(calls.get() + 1);
+///     currency.len()
+/// });
+/// assert_eq!(rate("CAD"), 3);
+/// assert_eq!(rate("CAD"), 3);
+/// assert_eq!(calls.get(), 1);
+///
+/// let stale = bound_memo(Duration::from_secs(0), |x: u32| {
+///     calls.set(calls.get() + 1);
+///     x
+/// });
+/// stale(1);
+/// stale(1);
+/// assert_eq!(calls.get(), 3);
+/// ```
+#[cfg(feature = "time")]
+pub fn bound_memo<A, B, F>(ttl: Duration, f: F) -> impl Fn(A) -> B
+where
+    A: Eq + Hash + Clone,
+    B: Clone,
+    F: Fn(A) -> B,
+{
+    let cache = RefCell::new(HashMap::<A, (Instant, B)>::new());
+    move |a| {
+        if let Some((created, b)) = cache.borrow().get(&a) {
+            if created.elapsed() < ttl {
+                return B::clone(b);
+            }
+        }
+        let b = f(a.clone());
+        let mut cache = cache.borrow_mut();
+        cache.retain(|_, (created, _)| created.elapsed() < ttl);
+        cache.insert(a, (Instant::now(), b.clone()));
+        b
+    }
+}
+
+/* TIMING *********************************************************************/
+
+/// Creates a function which measures how long another takes to run, returning the elapsed time
+/// alongside the result.
+///
+/// Requires the `time` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::measure;
+/// let timed_sum = measure(|n: u64| (0..n).sum::<u64>());
+/// let (elapsed, sum) = timed_sum(1000);
+/// assert_eq!(sum, 499500);
+/// assert!(elapsed.as_secs() < 60);
+/// ```
+#[cfg(feature = "time")]
+pub fn measure<A, B, F>(f: F) -> impl Fn(A) -> (Duration, B)
+where
+    F: Fn(A) -> B,
+{
+    move |a| {
+        let start = Instant::now();
+        let b = f(a);
+        (start.elapsed(), b)
+    }
+}
+
+/// Creates a function which measures how long another takes to run, passing the elapsed time
+/// to a callback and returning the result unchanged. This allows instrumenting a stage of a
+/// pipeline without changing its type.
+///
+/// Requires the `time` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::measure_with;
+/// # use std::cell::Cell;
+/// let calls = Cell::new(0);
+/// let parse = measure_with(|_elapsed| calls.set(calls.get() + 1), |s: &str| s.parse::<u32>());
+/// let parsed: Vec<_> = vec!["1", "2"].into_iter().map(parse).collect();
+/// assert_eq!(parsed, vec![Ok(1), Ok(2)]);
+/// assert_eq!(calls.get(), 2);
+/// ```
+#[cfg(feature = "time")]
+pub fn measure_with<A, B, C, F>(callback: C, f: F) -> impl Fn(A) -> B
+where
+    C: Fn(Duration),
+    F: Fn(A) -> B,
+{
+    move |a| {
+        let start = Instant::now();
+        let b = f(a);
+        callback(start.elapsed());
+        b
+    }
+}
+
+/* RATE LIMITING **************************************************************/
+#[cfg(feature = "time")]
+use crate::error::Error;
+#[cfg(feature = "time")]
+use std::cell::Cell;
+
+#[cfg(feature = "time")]
+struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: Cell<f64>,
+    refilled: Cell<Instant>,
+}
+
+#[cfg(feature = "time")]
+impl TokenBucket {
+    fn new(permits_per_sec: f64) -> Self {
+        assert!(
+            permits_per_sec > 0.0,
+            "rate limit must allow a positive number of permits per second"
+        );
+        let capacity = permits_per_sec.max(1.0);
+        TokenBucket {
+            rate: permits_per_sec,
+            capacity,
+            tokens: Cell::new(capacity),
+            refilled: Cell::new(Instant::now()),
+        }
+    }
+
+    /// Takes a token if one is available, or else returns how long it will be until one is.
+    fn acquire(&self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled.get()).as_secs_f64();
+        let tokens = (self.tokens.get() + elapsed * self.rate).min(self.capacity);
+        self.refilled.set(now);
+        if tokens >= 1.0 {
+            self.tokens.set(tokens - 1.0);
+            Ok(())
+        } else {
+            self.tokens.set(tokens);
+            Err(Duration::from_secs_f64((1.0 - tokens) / self.rate))
+        }
+    }
+}
+
+/// Creates a function which calls another at most `permits_per_sec` times per second, blocking
+/// the current thread until it is allowed to call it.
+///
+/// Calls are limited by a token bucket which holds up to one second's worth of permits, and
+/// starts out full, so short bursts are allowed as long as the average rate stays within the
+/// limit. Unlike throttling or debouncing, every call is eventually made. To fail instead of
+/// blocking, see [try_rate_limit](crate::function::try_rate_limit).
+///
+/// Requires the `time` feature.
+///
+/// # Panics
+///
+/// Panics if `permits_per_sec` is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::rate_limit;
+/// # use std::time::{Duration, Instant};
+/// let fetch = rate_limit(20.0, |id: u32| format!("/users/{}", id));
+/// let start = Instant::now();
+/// let paths: Vec<_> = (0..22).map(fetch).collect();
+/// assert_eq!(paths[21], "/users/21");
+/// assert!(start.elapsed() >= Duration::from_millis(50));
+/// ```
+#[cfg(feature = "time")]
+pub fn rate_limit<A, B, F>(permits_per_sec: f64, f: F) -> impl Fn(A) -> B
+where
+    F: Fn(A) -> B,
+{
+    let bucket = TokenBucket::new(permits_per_sec);
+    move |a| {
+        while let Err(wait) = bucket.acquire() {
+            std::thread::sleep(wait);
+        }
+        f(a)
+    }
+}
+
+/// Creates a function which calls another at most `permits_per_sec` times per second, as with
+/// [rate_limit](crate::function::rate_limit), but fails with
+/// [RateLimited](crate::error::Error::RateLimited) instead of blocking when a call would exceed
+/// the limit. A call which fails does not use up a permit.
+///
+/// Requires the `time` feature.
+///
+/// # Panics
+///
+/// Panics if `permits_per_sec` is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::error::Error;
+/// # use fp_std::function::try_rate_limit;
+/// let send = try_rate_limit(2.0, |message: &str| message.len());
+/// assert_eq!(send("hello"), Ok(5));
+/// assert_eq!(send("world"), Ok(5));
+/// assert_eq!(send("again"), Err(Error::RateLimited));
+/// ```
+#[cfg(feature = "time")]
+pub fn try_rate_limit<A, B, F>(permits_per_sec: f64, f: F) -> impl Fn(A) -> Result<B, Error>
+where
+    F: Fn(A) -> B,
+{
+    let bucket = TokenBucket::new(permits_per_sec);
+    move |a| match bucket.acquire() {
+        Ok(()) => Ok(f(a)),
+        Err(_) => Err(Error::RateLimited),
+    }
+}
+
+/* RETRIES ********************************************************************/
+#[cfg(feature = "time")]
+use crate::result::RetryPolicy;
+
+/// Creates a function which calls a fallible function, and calls it again with a copy of the
+/// same argument each time it fails, according to a [RetryPolicy](crate::result::RetryPolicy),
+/// until it succeeds, fails with an error which should not be retried, or runs out of
+/// attempts. The last error is returned if it never succeeds.
+///
+/// The thread sleeps between attempts, for as long as the policy says. For asynchronous
+/// functions, see [retry_async](crate::result::retry_async).
+///
+/// Requires the `time` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::retry;
+/// # use fp_std::result::RetryPolicy;
+/// # use std::cell::Cell;
+/// # use std::time::Duration;
+/// let attempts = Cell::new(0);
+/// let fetch = retry(
+///     RetryPolicy::new(3)
+///         .with_delay(Duration::from_millis(1))
+///         .with_backoff(2.0)
+///         .retry_if(|error: &String| error.starts_with("timeout")),
+///     |path: &str| {
+///         attempts.set(attempts.get() + 1);
+///         match (path, attempts.get()) {
+///             ("/missing", _) => Err(format!("not found: {}", path)),
+///             (_, 1) => Err(format!("timeout: {}", path)),
+///             (_, attempt) => Ok(attempt),
+///         }
+///     },
+/// );
+/// assert_eq!(fetch("/index"), Ok(2));
+/// assert_eq!(fetch("/missing"), Err("not found: /missing".to_owned()));
+/// assert_eq!(attempts.get(), 3);
+/// ```
+#[cfg(feature = "time")]
+pub fn retry<A, B, E, F>(policy: RetryPolicy<E>, f: F) -> impl Fn(A) -> Result<B, E>
+where
+    A: Clone,
+    F: Fn(A) -> Result<B, E>,
+{
+    move |a| {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match f(a.clone()) {
+                Ok(value) => return Ok(value),
+                Err(error) if policy.should_retry(attempts, &error) => {
+                    let delay = policy.jittered_delay_before(attempts);
+                    if delay > Duration::from_secs(0) {
+                        std::thread::sleep(delay);
+                    }
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+/* FAN-OUT ********************************************************************/
+
+/// A tuple of up to eight functions, each of which can be applied to a reference to the same
+/// argument. See [juxt](crate::function::juxt) and [converge](crate::function::converge).
+pub trait Juxt<A> {
+    /// The tuple of the results of the functions.
+    type Output;
+
+    /// Applies each function to the argument, collecting the results in order.
+    fn call_each(&self, a: &A) -> Self::Output;
+}
+
+macro_rules! juxt_impl {
+    ($($f:ident $b:ident),+) => {
+        impl<A, $($f, $b),+> Juxt<A> for ($($f,)+)
+        where
+            $($f: Fn(&A) -> $b),+
+        {
+            type Output = ($($b,)+);
+
+            #[allow(non_snake_case)]
+            fn call_each(&self, a: &A) -> Self::Output {
+                let ($($f,)+) = self;
+                ($($f(a),)+)
+            }
+        }
+    };
+}
+
+juxt_impl!(F1 B1);
+juxt_impl!(F1 B1, F2 B2);
+juxt_impl!(F1 B1, F2 B2, F3 B3);
+juxt_impl!(F1 B1, F2 B2, F3 B3, F4 B4);
+juxt_impl!(F1 B1, F2 B2, F3 B3, F4 B4, F5 B5);
+juxt_impl!(F1 B1, F2 B2, F3 B3, F4 B4, F5 B5, F6 B6);
+juxt_impl!(F1 B1, F2 B2, F3 B3, F4 B4, F5 B5, F6 B6, F7 B7);
+juxt_impl!(F1 B1, F2 B2, F3 B3, F4 B4, F5 B5, F6 B6, F7 B7, F8 B8);
+
+/// Creates a function which passes its argument to each of a tuple of functions, and returns a
+/// tuple of their results.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::juxt;
+/// let summary = juxt((
+///     |xs: &Vec<i32>| xs.iter().min().copied(),
+///     |xs: &Vec<i32>| xs.iter().max().copied(),
+///     Vec::len,
+/// ));
+/// assert_eq!(summary(vec![3, -1, 4]), (Some(-1), Some(4), 3));
+/// ```
+pub fn juxt<A, J>(fns: J) -> impl Fn(A) -> J::Output
+where
+    J: Juxt<A>,
+{
+    move |a| fns.call_each(&a)
+}
+
+/// Creates a function which passes its argument to each of a tuple of functions, and then
+/// combines their results with `f`, which takes them as a tuple.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::converge;
+/// let mean = converge(
+///     |(sum, count): (u32, usize)| sum as f64 / count as f64,
+///     (|xs: &Vec<u32>| xs.iter().sum::<u32>(), Vec::len),
+/// );
+/// assert_eq!(mean(vec![1, 2, 6]), 3.0);
+/// ```
+pub fn converge<A, B, F, J>(f: F, fns: J) -> impl Fn(A) -> B
+where
+    J: Juxt<A>,
+    F: Fn(J::Output) -> B,
+{
+    move |a| f(fns.call_each(&a))
+}
+
+/* ARROWS *********************************************************************/
+
+/// Creates a function which passes a copy of its argument to each of two functions, and
+/// returns both results. Unlike [fork_join](crate::function::fork_join), the functions take
+/// the argument by value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::fanout;
+/// let bounds = fanout(|n: i32| n - 1, |n: i32| n + 1);
+/// assert_eq!(bounds(5), (4, 6));
+/// ```
+pub fn fanout<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> (B, C)
+where
+    A: Clone,
+    F: Fn(A) -> B,
+    G: Fn(A) -> C,
+{
+    move |a| (f(a.clone()), g(a))
+}
+
+/// Creates a function which transforms the first element of a 2-tuple with `f`, and the
+/// second with `g`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::split;
+/// let parse_entry = split(str::to_uppercase, |v: &str| v.parse::<u32>());
+/// assert_eq!(parse_entry(("port", "80")), ("PORT".to_owned(), Ok(80)));
+/// ```
+pub fn split<A, B, C, D, F, G>(f: F, g: G) -> impl Fn((A, B)) -> (C, D)
+where
+    F: Fn(A) -> C,
+    G: Fn(B) -> D,
+{
+    move |(a, b)| (f(a), g(b))
+}
+
+/// Lifts a function into one which transforms the first element of a 2-tuple, leaving the
+/// second unchanged. This is the same as [tuple::map_first](crate::tuple::map_first).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::{compose2, first_fn, second_fn};
+/// let scale = compose2(first_fn(|w: u32| w * 2), second_fn(|h: u32| h * 3));
+/// assert_eq!(scale((4, 5)), (8, 15));
+/// ```
+pub fn first_fn<A, B, C, F>(f: F) -> impl Fn((A, C)) -> (B, C)
+where
+    F: Fn(A) -> B,
+{
+    crate::tuple::map_first(f)
+}
+
+/// Lifts a function into one which transforms the second element of a 2-tuple, leaving the
+/// first unchanged. This is the same as [tuple::map_second](crate::tuple::map_second).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::second_fn;
+/// let with_len = second_fn(str::len);
+/// assert_eq!(with_len(("name", "ferris")), ("name", 6));
+/// ```
+pub fn second_fn<A, B, C, F>(f: F) -> impl Fn((C, A)) -> (C, B)
+where
+    F: Fn(A) -> B,
+{
+    crate::tuple::map_second(f)
+}
+
+/* PARALLELISM ****************************************************************/
+
+/// Creates a function which passes its argument to two functions and returns both results,
+/// evaluating one after the other. To evaluate them in parallel, see
+/// [par_fork_join](crate::function::par_fork_join).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::fork_join;
+/// let stats = fork_join(
+///     |xs: &Vec<u64>| xs.iter().sum::<u64>(),
+///     |xs: &Vec<u64>| xs.iter().max().copied(),
+/// );
+/// assert_eq!(stats(vec![3, 1, 4]), (8, Some(4)));
+/// ```
+pub fn fork_join<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> (B, C)
+where
+    F: Fn(&A) -> B,
+    G: Fn(&A) -> C,
+{
+    move |a| (f(&a), g(&a))
+}
+
+/// Creates a function which passes its argument to two functions and returns both results,
+/// evaluating them in parallel, which is worthwhile when both are expensive. See
+/// [fork_join](crate::function::fork_join).
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::par_fork_join;
+/// let stats = par_fork_join(
+///     |xs: &Vec<u64>| xs.iter().sum::<u64>(),
+///     |xs: &Vec<u64>| xs.iter().max().copied(),
+/// );
+/// assert_eq!(stats(vec![3, 1, 4]), (8, Some(4)));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_fork_join<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> (B, C)
+where
+    A: Sync,
+    B: Send,
+    C: Send,
+    F: Fn(&A) -> B + Sync,
+    G: Fn(&A) -> C + Sync,
+{
+    move |a| rayon::join(|| f(&a), || g(&a))
+}
+
+/* ASYNC FUNCTIONS ************************************************************/
+#[cfg(feature = "async")]
+use futures::future::{AndThen, FutureExt, Shared, Then, TryFuture, TryFutureExt};
+#[cfg(feature = "async")]
+use std::future::Future;
+
+/// Composes two asynchronous functions, right to left: the result awaits `g`, and then `f`
+/// with its output. See [compose2](crate::function::compose2).
+///
+/// Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose_async;
+/// # use futures::executor::block_on;
+/// async fn fetch_len(url: &str) -> usize { url.len() }
+/// async fn double(n: usize) -> usize { n * 2 }
+/// let fetch_doubled = compose_async(double, fetch_len);
+/// assert_eq!(block_on(fetch_doubled("a.io")), 8);
+/// ```
+#[cfg(feature = "async")]
+pub fn compose_async<A, B, Fut, Fut2, F, G>(f: F, g: G) -> impl Fn(A) -> Then<Fut, Fut2, F>
+where
+    G: Fn(A) -> Fut,
+    Fut: Future<Output = B>,
+    F: Fn(B) -> Fut2 + Clone,
+    Fut2: Future,
+{
+    move |a| g(a).then(f.clone())
+}
+
+/// Chains two fallible asynchronous functions, left to right: the result awaits `f`, and if it
+/// succeeds, `g` with its value. The first error is returned without running `g`.
+///
+/// Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::and_then_async;
+/// # use futures::executor::block_on;
+/// async fn parse(s: &str) -> Result<u32, String> {
+///     s.parse().map_err(|_| format!("invalid: {}", s))
+/// }
+/// async fn reciprocal(n: u32) -> Result<f64, String> {
+///     if n == 0 { Err("division by zero".to_owned()) } else { Ok(1.0 / n as f64) }
+/// }
+/// let parse_reciprocal = and_then_async(parse, reciprocal);
+/// assert_eq!(block_on(parse_reciprocal("4")), Ok(0.25));
+/// assert_eq!(block_on(parse_reciprocal("0")), Err("division by zero".to_owned()));
+/// assert_eq!(block_on(parse_reciprocal("x")), Err("invalid: x".to_owned()));
+/// ```
+#[cfg(feature = "async")]
+pub fn and_then_async<A, Fut, Fut2, F, G>(f: F, g: G) -> impl Fn(A) -> AndThen<Fut, Fut2, G>
+where
+    F: Fn(A) -> Fut,
+    Fut: TryFuture,
+    G: Fn(Fut::Ok) -> Fut2 + Clone,
+    Fut2: TryFuture<Error = Fut::Error>,
+{
+    move |a| f(a).and_then(g.clone())
+}
+
+/// Creates an asynchronous function which caches the results of another, so that it is only
+/// called once for each distinct argument, as with [memoize](crate::function::memoize).
+///
+/// The cache holds the futures themselves, so a call made while an earlier call with the same
+/// argument is still in progress awaits that same future rather than starting another.
+///
+/// Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::memoize_async;
+/// # use futures::executor::block_on;
+/// # use futures::future::join;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let calls = AtomicUsize::new(0);
+/// let lookup = memoize_async(|id: u32| {
+///     calls.fetch_add(1, Ordering::SeqCst);
+///     async move { format!("user {}", id) }
+/// });
+/// let (a, b) = block_on(join(lookup(7), lookup(7)));
+/// assert_eq!((a.as_str(), b.as_str()), ("user 7", "user 7"));
+/// assert_eq!(block_on(lookup(7)), "user 7");
+/// assert_eq!(calls.load(Ordering::SeqCst), 1);
+/// ```
+#[cfg(feature = "async")]
+pub fn memoize_async<A, Fut, F>(f: F) -> impl Fn(A) -> Shared<Fut>
+where
+    A: Eq + Hash + Clone,
+    F: Fn(A) -> Fut,
+    Fut: Future,
+    Fut::Output: Clone,
+{
+    let cache = Mutex::new(HashMap::new());
+    move |a: A| {
+        let mut cache = cache.lock().unwrap();
+        if let Some(shared) = cache.get(&a) {
+            return Shared::clone(shared);
+        }
+        let shared = f(a.clone()).shared();
+        cache.insert(a, shared.clone());
+        shared
+    }
+}
+
+/// Spreads a 2-tuple into the arguments of a 2-arity asynchronous function. See
+/// [tuple::spread](crate::tuple::spread).
+///
+/// Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::spread_async;
+/// # use futures::executor::block_on;
+/// async fn add(a: u32, b: u32) -> u32 { a + b }
+/// let sum = spread_async(add);
+/// assert_eq!(block_on(sum((1, 2))), 3);
+/// ```
+#[cfg(feature = "async")]
+pub fn spread_async<A, B, Fut, F>(f: F) -> impl Fn((A, B)) -> Fut
+where
+    F: Fn(A, B) -> Fut,
+    Fut: Future,
+{
+    move |(a, b)| f(a, b)
+}
+
+/* SIDE EFFECTS ***************************************************************/
+#[cfg(any(feature = "std", feature = "log", feature = "tracing"))]
+use core::fmt::Debug;
+
+/// Creates a function which passes a reference to its argument to `f`, then returns the
+/// argument unchanged. This allows side effects, such as logging, to be performed in the middle
+/// of a pipeline without breaking it apart.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::tap;
+/// # use std::cell::RefCell;
+/// let seen = RefCell::new(Vec::new());
+/// let doubled: Vec<_> = vec![1, 2, 3]
+///     .into_iter()
+///     .map(tap(|x: &i32| seen.borrow_mut().push(*x)))
+///     .map(|x| x * 2)
+///     .collect();
+/// assert_eq!(doubled, vec![2, 4, 6]);
+/// assert_eq!(seen.into_inner(), vec![1, 2, 3]);
+/// ```
+pub fn tap<A, F>(f: F) -> impl Fn(A) -> A
+where
+    F: Fn(&A),
+{
+    move |a| {
+        f(&a);
+        a
+    }
+}
+
+/// Creates a function which prints its argument's `Debug` representation to standard error
+/// under the given label, then returns it unchanged. To log the value instead, see
+/// [trace](crate::function::trace).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::tap_with;
+/// let total: u32 = vec![1, 2, 3]
+///     .into_iter()
+///     .map(tap_with("item"))
+///     .map(|x| x * 10)
+///     .sum();
+/// assert_eq!(total, 60);
+/// ```
+#[cfg(feature = "std")]
+pub fn tap_with<'a, A: Debug>(label: &'a str) -> impl Fn(A) -> A + 'a {
+    move |a| {
+        std::eprintln!("{}: {:?}", label, a);
+        a
+    }
+}
+
+/* TRACING ********************************************************************/
+
+/// Creates a function which logs its argument under the given label, then returns it
+/// unchanged. The event is emitted at the debug level through [log](https://crates.io/crates/log)
+/// and [tracing](https://crates.io/crates/tracing), according to which features are enabled.
+///
+/// Requires the `log` or `tracing` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::trace;
+/// let doubled: Vec<_> = vec![1, 2, 3]
+///     .into_iter()
+///     .map(trace("before"))
+///     .map(|x| x * 2)
+///     .map(trace("after"))
+///     .collect();
+/// assert_eq!(doubled, vec![2, 4, 6]);
+/// ```
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub fn trace<'a, A: Debug>(label: &'a str) -> impl Fn(A) -> A + 'a {
+    move |a| {
+        debug_event(label, &a);
+        a
+    }
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub(crate) fn debug_event<A: Debug>(label: &str, value: &A) {
+    #[cfg(feature = "log")]
+    log::debug!("{}: {:?}", label, value);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(label, value = ?value);
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub(crate) fn warn_event<A: Debug>(label: &str, value: &A) {
+    #[cfg(feature = "log")]
+    log::warn!("{}: {:?}", label, value);
+    #[cfg(feature = "tracing")]
+    tracing::warn!(label, value = ?value);
+}
@@ -0,0 +1,479 @@
+//! Functions for composing futures, mirroring the functions available for plain values.
+//!
+//! These functions do not depend on any particular runtime, so the futures they produce may be
+//! run on whichever executor is available.
+//!
+//! Requires the `async` feature.
+
+/* BASIC FUNCTIONS ************************************************************/
+use futures::future::{self, Either, FutureExt, Join, Join3, Then};
+use std::future::Future;
+
+/// Lifts a function to transform the output of a future.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::map;
+/// # use futures::executor::block_on;
+/// fn double(x: usize) -> usize { x * 2 }
+/// let double_later = map(double);
+/// assert_eq!(block_on(double_later(async { 3 })), 6);
+/// ```
+pub fn map<Fut, B, F>(f: F) -> impl Fn(Fut) -> future::Map<Fut, F>
+where
+    Fut: Future,
+    F: Fn(Fut::Output) -> B + Clone,
+{
+    move |fut| fut.map(f.clone())
+}
+
+/// Lifts a function producing a future to chain onto the output of another future.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::and_then;
+/// # use futures::executor::block_on;
+/// async fn fetch_double(x: usize) -> usize { x * 2 }
+/// let fetch_doubled = and_then(fetch_double);
+/// assert_eq!(block_on(fetch_doubled(async { 3 })), 6);
+/// ```
+pub fn and_then<Fut, Fut2, F>(f: F) -> impl Fn(Fut) -> Then<Fut, Fut2, F>
+where
+    Fut: Future,
+    Fut2: Future,
+    F: Fn(Fut::Output) -> Fut2 + Clone,
+{
+    move |fut| fut.then(f.clone())
+}
+
+/// Runs two futures concurrently, producing both of their outputs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::join2;
+/// # use futures::executor::block_on;
+/// assert_eq!(block_on(join2(async { 1 }, async { "a" })), (1, "a"));
+/// ```
+pub fn join2<A, B>(a: A, b: B) -> Join<A, B>
+where
+    A: Future,
+    B: Future,
+{
+    future::join(a, b)
+}
+
+/// Runs three futures concurrently, producing all of their outputs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::join3;
+/// # use futures::executor::block_on;
+/// assert_eq!(
+///     block_on(join3(async { 1 }, async { "a" }, async { 'b' })),
+///     (1, "a", 'b'),
+/// );
+/// ```
+pub fn join3<A, B, C>(a: A, b: B, c: C) -> Join3<A, B, C>
+where
+    A: Future,
+    B: Future,
+    C: Future,
+{
+    future::join3(a, b, c)
+}
+
+/// Runs two futures concurrently, producing the output of whichever completes first. The other
+/// future is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::race;
+/// # use futures::executor::block_on;
+/// # use futures::future::pending;
+/// assert_eq!(block_on(race(pending(), async { 2 })), 2);
+/// ```
+pub async fn race<A, B>(a: A, b: B) -> A::Output
+where
+    A: Future,
+    B: Future<Output = A::Output>,
+{
+    futures::pin_mut!(a, b);
+    match future::select(a, b).await {
+        Either::Left((output, ..)) => output,
+        Either::Right((output, ..)) => output,
+    }
+}
+
+/* TIMEOUTS *******************************************************************/
+use futures_timer::Delay;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// The error produced by a future which did not complete before its timeout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elapsed;
+
+impl std::fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "future timed out")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+pin_project_lite::pin_project! {
+    /// A future which fails with [Elapsed](crate::future::Elapsed) if the inner future does not
+    /// complete before the timeout. Constructed by [with_timeout](crate::future::with_timeout).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Timeout<Fut> {
+        #[pin]
+        future: Fut,
+        delay: Delay,
+    }
+}
+
+impl<Fut: Future> Future for Timeout<Fut> {
+    type Output = Result<Fut::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        if let Poll::Ready(output) = this.future.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        Pin::new(this.delay).poll(cx).map(|()| Err(Elapsed))
+    }
+}
+
+/// Limits the time a future is given to complete. The timer starts when the future is passed to
+/// the returned function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::{with_timeout, Elapsed};
+/// # use futures::executor::block_on;
+/// # use futures::future::pending;
+/// # use std::time::Duration;
+/// let within_10ms = with_timeout(Duration::from_millis(10));
+/// assert_eq!(block_on(within_10ms(pending::<()>())), Err(Elapsed));
+/// assert_eq!(block_on(within_10ms(pending::<()>())), Err(Elapsed));
+///
+/// let within_1s = with_timeout(Duration::from_secs(1));
+/// assert_eq!(block_on(within_1s(async { 3 })), Ok(3));
+/// ```
+pub fn with_timeout<Fut>(duration: Duration) -> impl Fn(Fut) -> Timeout<Fut>
+where
+    Fut: Future,
+{
+    move |future| Timeout {
+        future,
+        delay: Delay::new(duration),
+    }
+}
+
+/* TRAVERSALS *****************************************************************/
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+/// Applies a fallible asynchronous function to every item, running at most `n` of the resulting
+/// futures at a time, and collecting their outputs in the original order. Stops at the first
+/// error, dropping any futures still in progress.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::traverse_limit;
+/// # use futures::executor::block_on;
+/// async fn parse(s: &str) -> Result<u32, String> {
+///     s.parse().map_err(|_| format!("invalid: {}", s))
+/// }
+/// assert_eq!(block_on(traverse_limit(2, parse, vec!["1", "2", "3"])), Ok(vec![1, 2, 3]));
+/// assert_eq!(
+///     block_on(traverse_limit(2, parse, vec!["1", "x", "y"])),
+///     Err("invalid: x".to_owned()),
+/// );
+/// ```
+pub async fn traverse_limit<A, B, E, Fut, F, I>(n: usize, f: F, iter: I) -> Result<Vec<B>, E>
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> Fut,
+    Fut: Future<Output = Result<B, E>>,
+{
+    sequence_limit(n, iter.into_iter().map(f)).await
+}
+
+/// Applies a fallible asynchronous function to every item, running at most `n` of the resulting
+/// futures at a time. Every future is run to completion; if any of them fail, all of the errors
+/// are collected in the original order instead.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::traverse_limit_accumulate;
+/// # use futures::executor::block_on;
+/// async fn parse(s: &str) -> Result<u32, String> {
+///     s.parse().map_err(|_| format!("invalid: {}", s))
+/// }
+/// assert_eq!(
+///     block_on(traverse_limit_accumulate(2, parse, vec!["1", "2"])),
+///     Ok(vec![1, 2]),
+/// );
+/// assert_eq!(
+///     block_on(traverse_limit_accumulate(2, parse, vec!["1", "x", "y"])),
+///     Err(vec!["invalid: x".to_owned(), "invalid: y".to_owned()]),
+/// );
+/// ```
+pub async fn traverse_limit_accumulate<A, B, E, Fut, F, I>(
+    n: usize,
+    f: F,
+    iter: I,
+) -> Result<Vec<B>, Vec<E>>
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> Fut,
+    Fut: Future<Output = Result<B, E>>,
+{
+    sequence_limit_accumulate(n, iter.into_iter().map(f)).await
+}
+
+/// Runs fallible futures, at most `n` at a time, collecting their outputs in the original order.
+/// Stops at the first error, dropping any futures still in progress.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::sequence_limit;
+/// # use futures::executor::block_on;
+/// # use futures::future::ready;
+/// let futures = vec![ready(Ok(1)), ready(Err("oops")), ready(Ok(3))];
+/// assert_eq!(block_on(sequence_limit(2, futures)), Err("oops"));
+/// ```
+pub async fn sequence_limit<B, E, Fut, I>(n: usize, futures: I) -> Result<Vec<B>, E>
+where
+    I: IntoIterator<Item = Fut>,
+    Fut: Future<Output = Result<B, E>>,
+{
+    assert!(n > 0, "concurrency limit must be greater than zero");
+    stream::iter(futures).buffered(n).try_collect().await
+}
+
+/// Runs fallible futures, at most `n` at a time. Every future is run to completion; if any of
+/// them fail, all of the errors are collected in the original order instead.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::sequence_limit_accumulate;
+/// # use futures::executor::block_on;
+/// # use futures::future::ready;
+/// let futures = vec![ready(Ok(1)), ready(Err("oops")), ready(Err("again"))];
+/// assert_eq!(
+///     block_on(sequence_limit_accumulate(2, futures)),
+///     Err(vec!["oops", "again"]),
+/// );
+/// ```
+pub async fn sequence_limit_accumulate<B, E, Fut, I>(n: usize, futures: I) -> Result<Vec<B>, Vec<E>>
+where
+    I: IntoIterator<Item = Fut>,
+    Fut: Future<Output = Result<B, E>>,
+{
+    assert!(n > 0, "concurrency limit must be greater than zero");
+    stream::iter(futures)
+        .buffered(n)
+        .fold(Ok(vec![]), |acc, result| async move {
+            match (acc, result) {
+                (Ok(mut values), Ok(value)) => {
+                    values.push(value);
+                    Ok(values)
+                }
+                (Ok(..), Err(error)) => Err(vec![error]),
+                (Err(errors), Ok(..)) => Err(errors),
+                (Err(mut errors), Err(error)) => {
+                    errors.push(error);
+                    Err(errors)
+                }
+            }
+        })
+        .await
+}
+
+/* LAZY VALUES ****************************************************************/
+use futures::future::{BoxFuture, Shared};
+use std::fmt::{self, Debug, Formatter};
+
+/// A value which is computed by an asynchronous initializer the first time it is forced. The
+/// asynchronous counterpart of [Lazy](crate::lazy::Lazy).
+///
+/// Clones of an `AsyncLazy` share the same value, so however many of them are forced, even
+/// concurrently, the initializer runs at most once, and every one of them gets a copy of its
+/// result.
+pub struct AsyncLazy<T> {
+    future: Shared<BoxFuture<'static, T>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> AsyncLazy<T> {
+    /// Creates a value which is computed by awaiting the future that `init` returns when it is
+    /// first forced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::future::AsyncLazy;
+    /// # use futures::executor::block_on;
+    /// # use futures::future::join;
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let calls = Arc::new(AtomicU32::new(0));
+    /// let counter = calls.clone();
+    /// let config = AsyncLazy::new(move || async move {
+    ///     counter.fetch_add(1, Ordering::SeqCst);
+    ///     "port=8080".to_owned()
+    /// });
+    /// let other = config.clone();
+    /// let (a, b) = block_on(join(config.force(), other.force()));
+    /// assert_eq!((a.as_str(), b.as_str()), ("port=8080", "port=8080"));
+    /// assert_eq!(calls.load(Ordering::SeqCst), 1);
+    /// ```
+    pub fn new<F, Fut>(init: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        AsyncLazy {
+            future: async move { init().await }.boxed().shared(),
+        }
+    }
+
+    /// Creates a value which has already been computed.
+    pub fn evaluated(value: T) -> Self {
+        AsyncLazy::new(|| future::ready(value))
+    }
+
+    /// Computes the value if it has not been already, and produces a copy of it.
+    pub async fn force(&self) -> T {
+        self.future.clone().await
+    }
+
+    /// Produces a copy of the value, if it has been computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::future::AsyncLazy;
+    /// # use futures::executor::block_on;
+    /// let answer = AsyncLazy::new(|| async { 42 });
+    /// assert_eq!(answer.get(), None);
+    /// assert_eq!(block_on(answer.force()), 42);
+    /// assert_eq!(answer.get(), Some(42));
+    /// ```
+    pub fn get(&self) -> Option<T> {
+        self.future.peek().cloned()
+    }
+
+    /// Whether the value has been computed.
+    pub fn is_forced(&self) -> bool {
+        self.future.peek().is_some()
+    }
+
+    /// Creates a value which is computed by transforming this one, once it is forced. This
+    /// value is not forced until then, and other clones of it are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::future::AsyncLazy;
+    /// # use futures::executor::block_on;
+    /// let words = AsyncLazy::new(|| async { "the quick brown fox".to_owned() });
+    /// let count = words.clone().map(|s| s.split(' ').count());
+    /// assert_eq!(block_on(count.force()), 4);
+    /// assert!(words.is_forced());
+    /// ```
+    pub fn map<U, F>(self, f: F) -> AsyncLazy<U>
+    where
+        U: Clone + Send + Sync + 'static,
+        F: FnOnce(T) -> U + Send + 'static,
+    {
+        AsyncLazy::new(move || async move { f(self.force().await) })
+    }
+
+    /// Creates a value which is computed by forcing the asynchronous lazy value that a
+    /// function of this one returns, once it is forced. This value is not forced until then.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::future::AsyncLazy;
+    /// # use futures::executor::block_on;
+    /// let base = AsyncLazy::new(|| async { 2u32 });
+    /// let power = base.and_then(|b| AsyncLazy::new(move || async move { b.pow(10) }));
+    /// assert_eq!(block_on(power.force()), 1024);
+    /// ```
+    pub fn and_then<U, F>(self, f: F) -> AsyncLazy<U>
+    where
+        U: Clone + Send + Sync + 'static,
+        F: FnOnce(T) -> AsyncLazy<U> + Send + 'static,
+    {
+        AsyncLazy::new(move || async move { f(self.force().await).force().await })
+    }
+}
+
+impl<T> Clone for AsyncLazy<T> {
+    fn clone(&self) -> Self {
+        AsyncLazy {
+            future: self.future.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Debug> Debug for AsyncLazy<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.future.peek() {
+            Some(value) => f.debug_tuple("AsyncLazy").field(value).finish(),
+            None => f.write_str("AsyncLazy(..)"),
+        }
+    }
+}
+
+/// Wraps a future so that it runs at most once, however many times it is awaited, and
+/// produces a copy of its output each time. The future does not start until it is first
+/// awaited.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::future::memoize_shared;
+/// # use futures::executor::block_on;
+/// let token = memoize_shared(async { vec!["token"] });
+/// let retry = token.clone();
+/// assert_eq!(block_on(token.force()), vec!["token"]);
+/// assert_eq!(retry.get(), Some(vec!["token"]));
+/// ```
+pub fn memoize_shared<Fut>(future: Fut) -> AsyncLazy<Fut::Output>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Clone + Send + Sync,
+{
+    AsyncLazy::new(move || future)
+}
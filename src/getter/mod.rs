@@ -0,0 +1,142 @@
+//! Getters: read-only optics which compute a value from a structure, so that derived values,
+//! such as a length or a total, can be read alongside the parts that
+//! [lenses](crate::lens) and [traversals](crate::traversal) focus on.
+//!
+//! Unlike a lens, a getter cannot be used to set anything, since what it produces need not be
+//! stored in the structure at all.
+//!
+//! ```rust
+//! # use fp_std::getter::{compose_traversal_getter, getter};
+//! # use fp_std::traversal::each;
+//! let line_total = getter(|&(price, qty): &(u32, u32)| price * qty);
+//! let total = compose_traversal_getter(each(), line_total).then(getter(|totals: &Vec<u32>| {
+//!     totals.iter().sum::<u32>()
+//! }));
+//! assert_eq!(total.get(&vec![(250, 2), (100, 3)]), 800);
+//! ```
+
+/* GETTERS ********************************************************************/
+use core::fmt::{self, Debug, Formatter};
+
+/// An optic which computes a value from a structure. See [getter](crate::getter::getter).
+#[derive(Clone, Copy)]
+pub struct Getter<F> {
+    f: F,
+}
+
+impl<F> Getter<F> {
+    /// Computes the value from a structure.
+    pub fn get<S, A>(&self, s: &S) -> A
+    where
+        F: Fn(&S) -> A,
+    {
+        (self.f)(s)
+    }
+
+    /// Composes this getter with another, which computes a value from the value this one
+    /// computes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::getter::getter;
+    /// let word_count = getter(|s: &String| s.split_whitespace().count());
+    /// let is_long = word_count.then(getter(|n: &usize| *n > 3));
+    /// assert!(is_long.get(&"one two three four".to_owned()));
+    /// ```
+    pub fn then<S, A, B, G>(self, inner: Getter<G>) -> Getter<impl Fn(&S) -> B>
+    where
+        F: Fn(&S) -> A,
+        G: Fn(&A) -> B,
+    {
+        getter(move |s: &S| inner.get(&self.get(s)))
+    }
+
+    /// Converts the getter back into the function it applies.
+    pub fn into_fn<S, A>(self) -> impl Fn(&S) -> A
+    where
+        F: Fn(&S) -> A,
+    {
+        self.f
+    }
+}
+
+impl<F> Debug for Getter<F> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Getter(..)")
+    }
+}
+
+/// Creates a getter which computes a value from a structure with a function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::getter::getter;
+/// let len = getter(Vec::<u8>::len);
+/// assert_eq!(len.get(&vec![1, 2, 3]), 3);
+/// ```
+pub const fn getter<S, A, F>(f: F) -> Getter<F>
+where
+    F: Fn(&S) -> A,
+{
+    Getter { f }
+}
+
+/* COMPOSITION ****************************************************************/
+#[cfg(feature = "alloc")]
+use crate::traversal::Traversal;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+
+/// Composes a lens with a getter, to compute a value from the part of a structure that the
+/// lens focuses on. The result is `None` if the lens focuses on nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::getter::{compose_lens_getter, getter};
+/// # use fp_std::tuple::LensFirst;
+/// let tag_count = compose_lens_getter(LensFirst, getter(Vec::<&str>::len));
+/// assert_eq!(tag_count.get(&(vec!["rust", "fp"], "post")), Some(2));
+/// ```
+#[cfg(feature = "std")]
+pub fn compose_lens_getter<L, S, A, B, F>(
+    _lens: L,
+    inner: Getter<F>,
+) -> Getter<impl Fn(&S) -> Option<A>>
+where
+    L: Lens<S, B>,
+    F: Fn(&B) -> A,
+{
+    getter(move |s: &S| L::get(s).map(|b| inner.get(b)))
+}
+
+/// Composes a traversal with a getter, to compute a value from each of the values that the
+/// traversal focuses on, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::getter::{compose_traversal_getter, getter};
+/// # use fp_std::traversal::each;
+/// let lengths = compose_traversal_getter(each(), getter(String::len));
+/// assert_eq!(lengths.get(&vec!["ab".to_owned(), "c".to_owned()]), vec![2, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_traversal_getter<T, S, A, B, F>(
+    traversal: T,
+    inner: Getter<F>,
+) -> Getter<impl Fn(&S) -> Vec<A>>
+where
+    T: Traversal<S, B>,
+    F: Fn(&B) -> A,
+{
+    getter(move |s: &S| {
+        let mut values = Vec::new();
+        traversal.for_each(s, |b| values.push(inner.get(b)));
+        values
+    })
+}
@@ -0,0 +1,92 @@
+//! Assertions for checking pure functions against sets of inputs, such as that a function still
+//! behaves the same after being refactored into a composition of smaller ones.
+//!
+//! Requires the `test-utils` feature.
+//!
+//! ```rust
+//! # use fp_std::function::compose2;
+//! # use fp_std::harness::assert_pointwise_eq;
+//! let original = |x: u32| (x + 1) * 2;
+//! let refactored = compose2(|x| x * 2, |x| x + 1);
+//! assert_pointwise_eq(original, refactored, 0..100);
+//! ```
+
+/* ASSERTIONS *****************************************************************/
+use core::fmt::Debug;
+
+/// Asserts that two functions return equal outputs for every one of the given inputs.
+///
+/// # Panics
+///
+/// Panics at the first input for which the outputs differ, reporting the input and both
+/// outputs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::harness::assert_pointwise_eq;
+/// assert_pointwise_eq(|s: &str| s.len(), |s: &str| s.chars().count(), vec!["", "abc"]);
+/// ```
+///
+/// ```rust,should_panic
+/// # use fp_std::harness::assert_pointwise_eq;
+/// assert_pointwise_eq(|s: &str| s.len(), |s: &str| s.chars().count(), vec!["é"]);
+/// ```
+#[track_caller]
+pub fn assert_pointwise_eq<I, B, F, G>(f: F, g: G, inputs: I)
+where
+    I: IntoIterator,
+    I::Item: Clone + Debug,
+    B: PartialEq + Debug,
+    F: Fn(I::Item) -> B,
+    G: Fn(I::Item) -> B,
+{
+    for input in inputs {
+        let left = f(input.clone());
+        let right = g(input.clone());
+        if left != right {
+            panic!(
+                "functions differ at input {:?}\n  left: {:?}\n right: {:?}",
+                input, left, right,
+            );
+        }
+    }
+}
+
+/// Asserts that a function returns the expected output for every one of the given cases, each
+/// of which is a pair of an input and its expected output.
+///
+/// # Panics
+///
+/// Panics at the first case for which the output is not the expected one, reporting the index
+/// of the case, its input, and both outputs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::harness::table_test;
+/// table_test(|x: i32| x.signum(), vec![(-5, -1), (0, 0), (7, 1)]);
+/// ```
+///
+/// ```rust,should_panic
+/// # use fp_std::harness::table_test;
+/// table_test(|x: i32| x.abs(), vec![(-1, 1), (2, -2)]);
+/// ```
+#[track_caller]
+pub fn table_test<I, A, B, F>(f: F, cases: I)
+where
+    I: IntoIterator<Item = (A, B)>,
+    A: Clone + Debug,
+    B: PartialEq + Debug,
+    F: Fn(A) -> B,
+{
+    for (index, (input, expected)) in cases.into_iter().enumerate() {
+        let actual = f(input.clone());
+        if actual != expected {
+            panic!(
+                "case {} failed for input {:?}\n  expected: {:?}\n    actual: {:?}",
+                index, input, expected, actual,
+            );
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! The identity functor, which wraps a value without adding any effect to it.
+//!
+//! Generic code written against the traits of fp-core can be run with an
+//! [Identity](crate::id::Identity) in place of a real effect, which makes that code easy to
+//! test.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! # use fp_std::id::Identity;
+//! use fp_core::functor::Functor;
+//!
+//! fn increment<F: Functor<u32, Current = u32>>(f: F) -> F::Target {
+//!     f.fmap(|n| n + 1)
+//! }
+//!
+//! assert_eq!(increment(Some(1)), Some(2));
+//! assert_eq!(increment(Identity(1)), Identity(2));
+//! # }
+//! ```
+
+/* IDENTITY *******************************************************************/
+use core::ops::{Deref, DerefMut};
+
+/// A value with no effect around it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::id::Identity;
+/// let answer = Identity(20).map(|n| n + 1).and_then(|n| Identity(n * 2));
+/// assert_eq!(answer.into_inner(), 42);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity<T>(pub T);
+
+impl<T> Identity<T> {
+    /// Takes the value out of the wrapper.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Transforms the value.
+    pub fn map<U, F>(self, f: F) -> Identity<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Identity(f(self.0))
+    }
+
+    /// Transforms the value into another wrapped value.
+    pub fn and_then<U, F>(self, f: F) -> Identity<U>
+    where
+        F: FnOnce(T) -> Identity<U>,
+    {
+        f(self.0)
+    }
+}
+
+impl<T> From<T> for Identity<T> {
+    fn from(value: T) -> Self {
+        Identity(value)
+    }
+}
+
+impl<T> Deref for Identity<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Identity<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/* INSTANCES ******************************************************************/
+#[cfg(feature = "std")]
+use fp_core::applicative::Applicative;
+#[cfg(feature = "std")]
+use fp_core::apply::Apply;
+#[cfg(feature = "std")]
+use fp_core::chain::Chain;
+#[cfg(feature = "std")]
+use fp_core::comonad::Comonad;
+#[cfg(feature = "std")]
+use fp_core::extend::Extend;
+#[cfg(feature = "std")]
+use fp_core::extract::Extract;
+#[cfg(feature = "std")]
+use fp_core::foldable::Foldable;
+#[cfg(feature = "std")]
+use fp_core::functor::Functor;
+#[cfg(feature = "std")]
+use fp_core::hkt::HKT;
+#[cfg(feature = "std")]
+use fp_core::monad::Monad;
+#[cfg(feature = "std")]
+use fp_core::pure::Pure;
+
+#[cfg(feature = "std")]
+impl<T, U> HKT<U> for Identity<T> {
+    type Current = T;
+    type Target = Identity<U>;
+}
+
+#[cfg(feature = "std")]
+impl<A, B> Functor<B> for Identity<A> {
+    fn fmap<F>(self, f: F) -> Identity<B>
+    where
+        F: FnOnce(A) -> B,
+    {
+        self.map(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A> Pure<A> for Identity<A> {
+    fn of(a: A) -> Identity<A> {
+        Identity(a)
+    }
+}
+
+/// Applies the wrapped function to the wrapped value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::id::Identity;
+/// use fp_core::apply::Apply;
+/// let double: Box<dyn Fn(u32) -> u32> = Box::new(|n| n * 2);
+/// assert_eq!(Identity(4).ap(Identity(double)), Identity(8));
+/// ```
+#[cfg(feature = "std")]
+impl<A, B> Apply<B> for Identity<A> {
+    fn ap(self, f: Identity<Box<dyn Fn(A) -> B>>) -> Identity<B> {
+        Identity((f.0)(self.0))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> Applicative<A, B> for Identity<A> {}
+
+#[cfg(feature = "std")]
+impl<A, B> Chain<B> for Identity<A> {
+    fn chain<F>(self, f: F) -> Identity<B>
+    where
+        F: FnOnce(A) -> Identity<B>,
+    {
+        self.and_then(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> Monad<A, B> for Identity<A> {}
+
+#[cfg(feature = "std")]
+impl<A, B> Extend<B> for Identity<A> {
+    fn extend<W>(self, f: W) -> Identity<B>
+    where
+        W: FnOnce(Self) -> B,
+    {
+        Identity(f(self))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A> Extract<A> for Identity<A> {
+    fn extract(self) -> A {
+        self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> Comonad<A, B> for Identity<A> {}
+
+/// Folds over the value, as if it were a list of one item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::id::Identity;
+/// use fp_core::foldable::fold_map;
+/// let total: i64 = fold_map(Identity(3), |x: &i64| *x);
+/// assert_eq!(total, 3);
+/// ```
+#[cfg(feature = "std")]
+impl<A, B> Foldable<B> for Identity<A> {
+    fn reduce<F>(self, b: B, f: F) -> B
+    where
+        F: Fn(B, &A) -> B,
+    {
+        f(b, &self.0)
+    }
+
+    fn reduce_right<F>(self, b: B, f: F) -> B
+    where
+        F: Fn(&A, B) -> B,
+    {
+        f(&self.0, b)
+    }
+}
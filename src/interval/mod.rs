@@ -0,0 +1,406 @@
+//! Half-open intervals over ordered values, and normalized sets of them.
+
+/* INTERVALS ******************************************************************/
+use crate::error::Error;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// A non-empty, half-open interval `[start, end)` over some ordered type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::interval::Interval;
+/// let morning = Interval::new(9, 12).unwrap();
+/// assert!(morning.contains(&9));
+/// assert!(!morning.contains(&12));
+/// assert_eq!(Interval::new(3, 3), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T: Ord> {
+    start: T,
+    end: T,
+}
+
+impl<T: Ord> Interval<T> {
+    /// Constructs the interval `[start, end)`, or `None` if it would be empty.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        if start < end {
+            Some(Interval { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Constructs the interval `[start, end)`, or fails with
+    /// [Error::EmptyInterval](crate::error::Error::EmptyInterval) if it would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::error::Error;
+    /// # use fp_std::interval::Interval;
+    /// assert!(Interval::try_new(1, 3).is_ok());
+    /// assert_eq!(Interval::try_new(3, 3), Err(Error::EmptyInterval));
+    /// ```
+    pub fn try_new(start: T, end: T) -> Result<Self, Error> {
+        Interval::new(start, end).ok_or(Error::EmptyInterval)
+    }
+
+    /// The inclusive lower bound of this interval.
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// The exclusive upper bound of this interval.
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    /// Deconstructs this interval into its `(start, end)` bounds.
+    pub fn into_bounds(self) -> (T, T) {
+        (self.start, self.end)
+    }
+
+    /// Checks whether a value lies within this interval.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::Interval;
+    /// let interval = Interval::new(1, 5).unwrap();
+    /// assert!(interval.contains(&1));
+    /// assert!(interval.contains(&4));
+    /// assert!(!interval.contains(&5));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        &self.start <= value && value < &self.end
+    }
+
+    /// Checks whether two intervals share at least one value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::Interval;
+    /// let a = Interval::new(1, 5).unwrap();
+    /// assert!(a.overlaps(&Interval::new(4, 8).unwrap()));
+    /// assert!(!a.overlaps(&Interval::new(5, 8).unwrap()));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Checks whether two intervals overlap or share a boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::Interval;
+    /// let a = Interval::new(1, 5).unwrap();
+    /// assert!(a.touches(&Interval::new(5, 8).unwrap()));
+    /// assert!(!a.touches(&Interval::new(6, 8).unwrap()));
+    /// ```
+    pub fn touches(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl<T: Ord + Clone> Interval<T> {
+    /// Computes the values shared by two intervals, if there are any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::Interval;
+    /// let a = Interval::new(1, 5).unwrap();
+    /// let b = Interval::new(3, 8).unwrap();
+    /// assert_eq!(a.intersect(&b), Interval::new(3, 5));
+    /// assert_eq!(a.intersect(&Interval::new(6, 8).unwrap()), None);
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let start = core::cmp::max(&self.start, &other.start).clone();
+        let end = core::cmp::min(&self.end, &other.end).clone();
+        Interval::new(start, end)
+    }
+
+    /// Computes the union of two intervals, as a sorted list of disjoint intervals. Intervals
+    /// which overlap or touch are merged into one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::Interval;
+    /// let a = Interval::new(1, 5).unwrap();
+    /// assert_eq!(a.union(&Interval::new(5, 8).unwrap()), vec![Interval::new(1, 8).unwrap()]);
+    /// assert_eq!(
+    ///     a.union(&Interval::new(0, 1).unwrap()),
+    ///     vec![Interval::new(0, 5).unwrap()],
+    /// );
+    /// assert_eq!(
+    ///     a.union(&Interval::new(7, 8).unwrap()),
+    ///     vec![Interval::new(1, 5).unwrap(), Interval::new(7, 8).unwrap()],
+    /// );
+    /// ```
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        if self.touches(other) {
+            let start = core::cmp::min(&self.start, &other.start).clone();
+            let end = core::cmp::max(&self.end, &other.end).clone();
+            vec![Interval { start, end }]
+        } else if self.start < other.start {
+            vec![self.clone(), other.clone()]
+        } else {
+            vec![other.clone(), self.clone()]
+        }
+    }
+
+    /// Computes the interval lying strictly between two disjoint intervals. Intervals which
+    /// overlap or touch have no gap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::Interval;
+    /// let a = Interval::new(1, 3).unwrap();
+    /// let b = Interval::new(6, 8).unwrap();
+    /// assert_eq!(a.gap(&b), Interval::new(3, 6));
+    /// assert_eq!(b.gap(&a), Interval::new(3, 6));
+    /// assert_eq!(a.gap(&Interval::new(3, 4).unwrap()), None);
+    /// ```
+    pub fn gap(&self, other: &Self) -> Option<Self> {
+        let start = core::cmp::min(&self.end, &other.end).clone();
+        let end = core::cmp::max(&self.start, &other.start).clone();
+        Interval::new(start, end)
+    }
+}
+
+/// Parses an interval of the form `start..end`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::error::Error;
+/// # use fp_std::interval::Interval;
+/// assert_eq!("1..5".parse(), Ok(Interval::new(1, 5).unwrap()));
+/// assert_eq!("5..1".parse::<Interval<u32>>(), Err(Error::EmptyInterval));
+/// assert_eq!("1-5".parse::<Interval<u32>>(), Err(Error::MalformedInterval));
+/// assert_eq!("a..5".parse::<Interval<u32>>(), Err(Error::InvalidBound));
+/// ```
+impl<T: Ord + FromStr> FromStr for Interval<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (start, end) = s.split_once("..").ok_or(Error::MalformedInterval)?;
+        let start = start.trim().parse().map_err(|_| Error::InvalidBound)?;
+        let end = end.trim().parse().map_err(|_| Error::InvalidBound)?;
+        Interval::try_new(start, end)
+    }
+}
+
+/* INTERVAL SETS **************************************************************/
+use core::iter::FromIterator;
+
+/// A set of values described by a list of intervals, which is kept normalized: the intervals
+/// are sorted, and no two of them overlap or touch.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::interval::{Interval, IntervalSet};
+/// let set: IntervalSet<_> = vec![(5, 8), (1, 3), (2, 5), (10, 12)]
+///     .into_iter()
+///     .filter_map(|(start, end)| Interval::new(start, end))
+///     .collect();
+/// assert_eq!(
+///     set.intervals(),
+///     &[Interval::new(1, 8).unwrap(), Interval::new(10, 12).unwrap()],
+/// );
+/// assert!(set.contains(&7));
+/// assert!(!set.contains(&9));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T: Ord> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+}
+
+impl<T: Ord> IntervalSet<T> {
+    /// Constructs an empty interval set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The normalized intervals which make up this set.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Checks whether this set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Checks whether a value lies within any interval of this set.
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end() <= value);
+        self.intervals
+            .get(index)
+            .map(|interval| interval.contains(value))
+            .unwrap_or(false)
+    }
+
+    /// Adds an interval to this set, merging it with any intervals it overlaps or touches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::{Interval, IntervalSet};
+    /// let mut set = IntervalSet::new();
+    /// set.insert(Interval::new(1, 3).unwrap());
+    /// set.insert(Interval::new(5, 7).unwrap());
+    /// set.insert(Interval::new(3, 5).unwrap());
+    /// assert_eq!(set.intervals(), &[Interval::new(1, 7).unwrap()]);
+    /// ```
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let (mut start, mut end) = interval.into_bounds();
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end() < &start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start() <= &end);
+        for existing in self.intervals.drain(first..last) {
+            let (existing_start, existing_end) = existing.into_bounds();
+            start = core::cmp::min(start, existing_start);
+            end = core::cmp::max(end, existing_end);
+        }
+        self.intervals.insert(first, Interval { start, end });
+    }
+
+    /// Iterates over the normalized intervals of this set.
+    pub fn iter(&self) -> core::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Computes the set of values contained in either set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::{Interval, IntervalSet};
+    /// let a: IntervalSet<_> = vec![Interval::new(1, 3).unwrap()].into_iter().collect();
+    /// let b: IntervalSet<_> = vec![Interval::new(2, 6).unwrap()].into_iter().collect();
+    /// assert_eq!(a.union(&b).intervals(), &[Interval::new(1, 6).unwrap()]);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).cloned().collect()
+    }
+
+    /// Computes the set of values contained in both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::{Interval, IntervalSet};
+    /// let a: IntervalSet<_> = vec![(1, 4), (6, 9)]
+    ///     .into_iter()
+    ///     .filter_map(|(start, end)| Interval::new(start, end))
+    ///     .collect();
+    /// let b: IntervalSet<_> = vec![Interval::new(3, 7).unwrap()].into_iter().collect();
+    /// assert_eq!(
+    ///     a.intersect(&b).intervals(),
+    ///     &[Interval::new(3, 4).unwrap(), Interval::new(6, 7).unwrap()],
+    /// );
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+            intervals.extend(a.intersect(b));
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Computes the gaps between the intervals of this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::interval::{Interval, IntervalSet};
+    /// let set: IntervalSet<_> = vec![(1, 2), (4, 5), (7, 9)]
+    ///     .into_iter()
+    ///     .filter_map(|(start, end)| Interval::new(start, end))
+    ///     .collect();
+    /// assert_eq!(
+    ///     set.gaps().intervals(),
+    ///     &[Interval::new(2, 4).unwrap(), Interval::new(5, 7).unwrap()],
+    /// );
+    /// ```
+    pub fn gaps(&self) -> Self {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .windows(2)
+                .filter_map(|pair| pair[0].gap(&pair[1]))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ord> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Ord> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = alloc::vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = core::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
@@ -0,0 +1,514 @@
+//! Isomorphisms: pairs of functions which convert between two equivalent representations of
+//! the same information.
+//!
+//! For an iso to be lawful, converting forward and then backward (or backward and then
+//! forward) must return the original value. The isos which fall short of this, such as
+//! [Utf8Lossy](crate::iso::Utf8Lossy), say so.
+//!
+//! Isos compose with each other, and can be used to transform values focused on by lenses and
+//! prisms. Since an iso produces its converted values rather than borrowing them, only the
+//! owned side of those optics, such as `over`, can be used through one.
+
+/* ISOMORPHISMS ***************************************************************/
+
+/// A conversion between `S` and `A` which loses no information in either direction.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::Iso;
+/// struct Chars;
+///
+/// impl Iso<String, Vec<char>> for Chars {
+///     fn forward(&self, s: String) -> Vec<char> {
+///         s.chars().collect()
+///     }
+///
+///     fn backward(&self, a: Vec<char>) -> String {
+///         a.into_iter().collect()
+///     }
+/// }
+///
+/// assert_eq!(Chars.forward("ab".to_owned()), vec!['a', 'b']);
+/// assert_eq!(Chars.backward(vec!['a', 'b']), "ab");
+/// ```
+pub trait Iso<S, A> {
+    /// Converts from the source representation to the target representation.
+    fn forward(&self, s: S) -> A;
+
+    /// Converts from the target representation back to the source representation.
+    fn backward(&self, a: A) -> S;
+
+    /// Transforms a value in the target representation, converting to and from the source
+    /// representation around it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::iso::{Iso, Utf8Lossy};
+    /// let upper = Utf8Lossy.over(b"hi".to_vec(), |s| s.to_uppercase());
+    /// assert_eq!(upper, b"HI".to_vec());
+    /// ```
+    fn over<F>(&self, s: S, f: F) -> S
+    where
+        F: FnOnce(A) -> A,
+    {
+        self.backward(f(self.forward(s)))
+    }
+}
+
+/// An iso built from a pair of functions. See [iso](crate::iso::iso).
+#[derive(Clone, Copy, Debug)]
+pub struct FnIso<F, G> {
+    forward: F,
+    backward: G,
+}
+
+impl<S, A, F, G> Iso<S, A> for FnIso<F, G>
+where
+    F: Fn(S) -> A,
+    G: Fn(A) -> S,
+{
+    fn forward(&self, s: S) -> A {
+        (self.forward)(s)
+    }
+
+    fn backward(&self, a: A) -> S {
+        (self.backward)(a)
+    }
+}
+
+/// Builds an iso from a pair of functions, which must be inverses of each other.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{iso, Iso};
+/// let negated = iso(|x: i32| -x, |x: i32| -x);
+/// assert_eq!(negated.forward(3), -3);
+/// assert_eq!(negated.backward(-3), 3);
+/// ```
+pub fn iso<S, A, F, G>(forward: F, backward: G) -> FnIso<F, G>
+where
+    F: Fn(S) -> A,
+    G: Fn(A) -> S,
+{
+    FnIso { forward, backward }
+}
+
+/* STRINGS ********************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::string::{FromUtf8Error, String};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// An iso between bytes and the result of decoding them as UTF-8. Invalid bytes are kept in the
+/// error, so nothing is lost in either direction.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{Iso, Utf8};
+/// assert_eq!(Utf8.forward(b"hi".to_vec()), Ok("hi".to_owned()));
+///
+/// let invalid = Utf8.forward(vec![0xff]);
+/// assert!(invalid.is_err());
+/// assert_eq!(Utf8.backward(invalid), vec![0xff]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Utf8;
+
+#[cfg(feature = "alloc")]
+impl Iso<Vec<u8>, Result<String, FromUtf8Error>> for Utf8 {
+    fn forward(&self, s: Vec<u8>) -> Result<String, FromUtf8Error> {
+        String::from_utf8(s)
+    }
+
+    fn backward(&self, a: Result<String, FromUtf8Error>) -> Vec<u8> {
+        match a {
+            Ok(string) => string.into_bytes(),
+            Err(error) => error.into_bytes(),
+        }
+    }
+}
+
+/// A conversion between bytes and strings which replaces invalid UTF-8 with `U+FFFD`.
+///
+/// This is only lawful for bytes which are valid UTF-8: invalid bytes do not survive the round
+/// trip. Prefer [Utf8](crate::iso::Utf8) where that matters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{Iso, Utf8Lossy};
+/// assert_eq!(Utf8Lossy.forward(b"hi".to_vec()), "hi");
+/// assert_eq!(Utf8Lossy.forward(vec![b'a', 0xff]), "a\u{FFFD}");
+/// assert_eq!(Utf8Lossy.backward("hi".to_owned()), b"hi".to_vec());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Utf8Lossy;
+
+#[cfg(feature = "alloc")]
+impl Iso<Vec<u8>, String> for Utf8Lossy {
+    fn forward(&self, s: Vec<u8>) -> String {
+        match String::from_utf8(s) {
+            Ok(string) => string,
+            Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+        }
+    }
+
+    fn backward(&self, a: String) -> Vec<u8> {
+        a.into_bytes()
+    }
+}
+
+/* OPTIONS ********************************************************************/
+
+/// An iso between `Option<T>` and `Result<T, ()>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{Iso, OptionResult};
+/// assert_eq!(OptionResult.forward(Some(1)), Ok(1));
+/// assert_eq!(OptionResult.forward(None::<u8>), Err(()));
+/// assert_eq!(OptionResult.backward(Err::<u8, _>(())), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OptionResult;
+
+impl<T> Iso<Option<T>, Result<T, ()>> for OptionResult {
+    fn forward(&self, s: Option<T>) -> Result<T, ()> {
+        s.ok_or(())
+    }
+
+    fn backward(&self, a: Result<T, ()>) -> Option<T> {
+        a.ok()
+    }
+}
+
+/* TUPLES *********************************************************************/
+
+/// An iso between right- and left-nested tuples, `(A, (B, C))` and `((A, B), C)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{Iso, Reassociate};
+/// assert_eq!(Reassociate.forward((1, ('a', true))), ((1, 'a'), true));
+/// assert_eq!(Reassociate.backward(((1, 'a'), true)), (1, ('a', true)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reassociate;
+
+impl<A, B, C> Iso<(A, (B, C)), ((A, B), C)> for Reassociate {
+    fn forward(&self, (a, (b, c)): (A, (B, C))) -> ((A, B), C) {
+        ((a, b), c)
+    }
+
+    fn backward(&self, ((a, b), c): ((A, B), C)) -> (A, (B, C)) {
+        (a, (b, c))
+    }
+}
+
+/// An iso between a pair and the same pair with its elements swapped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{Iso, Swap};
+/// assert_eq!(Swap.forward((1, 'a')), ('a', 1));
+/// assert_eq!(Swap.backward(('a', 1)), (1, 'a'));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Swap;
+
+impl<A, B> Iso<(A, B), (B, A)> for Swap {
+    fn forward(&self, (a, b): (A, B)) -> (B, A) {
+        (b, a)
+    }
+
+    fn backward(&self, (b, a): (B, A)) -> (A, B) {
+        (a, b)
+    }
+}
+
+/* NEWTYPES *******************************************************************/
+use crate::tagged::Tagged;
+
+/// An iso between any two types which can each be converted into the other with `From`, such as
+/// a newtype and the type it wraps. It is only lawful if the conversions are inverses.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{FromInto, Iso};
+/// #[derive(Debug, PartialEq)]
+/// struct Celsius(f64);
+///
+/// impl From<f64> for Celsius {
+///     fn from(degrees: f64) -> Self { Celsius(degrees) }
+/// }
+///
+/// impl From<Celsius> for f64 {
+///     fn from(Celsius(degrees): Celsius) -> Self { degrees }
+/// }
+///
+/// let warmer = FromInto.over(Celsius(20.0), |degrees: f64| degrees + 1.5);
+/// assert_eq!(warmer, Celsius(21.5));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FromInto;
+
+impl<S, A> Iso<S, A> for FromInto
+where
+    S: From<A>,
+    A: From<S>,
+{
+    fn forward(&self, s: S) -> A {
+        A::from(s)
+    }
+
+    fn backward(&self, a: A) -> S {
+        S::from(a)
+    }
+}
+
+/// An iso between a [Tagged](crate::tagged::Tagged) value and the value without its tag.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{Iso, Untagged};
+/// # use fp_std::tag;
+/// # use fp_std::tagged::Tagged;
+/// tag!(Score);
+/// let score: Tagged<Score, u32> = Tagged::new(3);
+/// assert_eq!(Untagged.over(score, |x| x * 2), Tagged::new(6));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Untagged;
+
+impl<Tag, T> Iso<Tagged<Tag, T>, T> for Untagged {
+    fn forward(&self, s: Tagged<Tag, T>) -> T {
+        s.into_inner()
+    }
+
+    fn backward(&self, a: T) -> Tagged<Tag, T> {
+        Tagged::new(a)
+    }
+}
+
+/* NUMBERS ********************************************************************/
+use crate::error::Error;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// An iso between two linear scales, such as units of temperature, which converts forward by
+/// multiplying by `scale` and then adding `offset`.
+///
+/// For floating point numbers, rounding errors may cause the round trip to differ very
+/// slightly from the original value. For integers, the iso is only lawful on multiples of
+/// `scale`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{Affine, Iso};
+/// let fahrenheit = Affine::new(1.8, 32.0);
+/// assert_eq!(fahrenheit.forward(100.0), 212.0);
+/// assert_eq!(fahrenheit.backward(32.0), 0.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Affine<T> {
+    scale: T,
+    offset: T,
+}
+
+impl<T> Affine<T> {
+    /// Creates an iso which converts forward by multiplying by `scale` and then adding
+    /// `offset`. The `scale` must not be zero, but is not checked, so that this can be used in
+    /// constants. See [try_new](crate::iso::Affine::try_new) for the checked version.
+    ///
+    /// # Panics
+    ///
+    /// If `scale` is zero, converting backward divides by zero, which panics for integers. For
+    /// floating point numbers it produces an infinity or `NaN` instead.
+    ///
+    /// ```rust,should_panic
+    /// # use fp_std::iso::{Affine, Iso};
+    /// Affine::new(0, 1).backward(5);
+    /// ```
+    pub const fn new(scale: T, offset: T) -> Self {
+        Affine { scale, offset }
+    }
+}
+
+impl<T: Default + PartialEq> Affine<T> {
+    /// Creates an iso which converts forward by multiplying by `scale` and then adding
+    /// `offset`, or fails with [Error::ZeroScale](crate::error::Error::ZeroScale) if `scale` is
+    /// zero, as it could not convert backward.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::error::Error;
+    /// # use fp_std::iso::{Affine, Iso};
+    /// assert_eq!(Affine::try_new(2, 1).map(|double| double.backward(7)), Ok(3));
+    /// assert_eq!(Affine::try_new(0.0, 1.0), Err(Error::ZeroScale));
+    /// ```
+    pub fn try_new(scale: T, offset: T) -> Result<Self, Error> {
+        if scale == T::default() {
+            Err(Error::ZeroScale)
+        } else {
+            Ok(Affine { scale, offset })
+        }
+    }
+}
+
+impl<T> Iso<T, T> for Affine<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn forward(&self, s: T) -> T {
+        s * self.scale + self.offset
+    }
+
+    fn backward(&self, a: T) -> T {
+        (a - self.offset) / self.scale
+    }
+}
+
+/* COMPOSITION ****************************************************************/
+use crate::prism::Prism;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+
+/// An iso which converts in the opposite direction of another. See
+/// [reversed](crate::iso::reversed).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reversed<I>(pub I);
+
+impl<S, A, I> Iso<A, S> for Reversed<I>
+where
+    I: Iso<S, A>,
+{
+    fn forward(&self, a: A) -> S {
+        self.0.backward(a)
+    }
+
+    fn backward(&self, s: S) -> A {
+        self.0.forward(s)
+    }
+}
+
+/// Reverses the direction of an iso.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{reversed, Iso, Utf8Lossy};
+/// let bytes = reversed(Utf8Lossy);
+/// assert_eq!(bytes.forward("hi".to_owned()), b"hi".to_vec());
+/// assert_eq!(bytes.backward(b"hi".to_vec()), "hi");
+/// ```
+pub fn reversed<I>(iso: I) -> Reversed<I> {
+    Reversed(iso)
+}
+
+/// An iso which converts through `First` to a `B`, and then through `Second`. See
+/// [compose_isos](crate::iso::compose_isos).
+#[derive(Clone, Copy, Debug)]
+pub struct ComposeIso<First, Second, B> {
+    first: First,
+    second: Second,
+    via: PhantomData<fn() -> B>,
+}
+
+impl<S, A, B, First, Second> Iso<S, A> for ComposeIso<First, Second, B>
+where
+    First: Iso<S, B>,
+    Second: Iso<B, A>,
+{
+    fn forward(&self, s: S) -> A {
+        self.second.forward(self.first.forward(s))
+    }
+
+    fn backward(&self, a: A) -> S {
+        self.first.backward(self.second.backward(a))
+    }
+}
+
+/// Composes two isos, so that the result converts forward through `first` and then `second`,
+/// and backward through `second` and then `first`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{compose_isos, Affine, Iso};
+/// let kelvin_to_fahrenheit = compose_isos(Affine::new(1.0, -273.15), Affine::new(1.8, 32.0));
+/// let boiling: f64 = kelvin_to_fahrenheit.forward(373.15);
+/// assert!((boiling - 212.0).abs() < 1e-9);
+/// assert!((kelvin_to_fahrenheit.backward(32.0) - 273.15).abs() < 1e-9);
+/// ```
+pub fn compose_isos<First, Second, B>(
+    first: First,
+    second: Second,
+) -> ComposeIso<First, Second, B> {
+    ComposeIso {
+        first,
+        second,
+        via: PhantomData,
+    }
+}
+
+/// Creates a function which transforms the part of a structure focused on by a lens, through an
+/// iso. The lens focuses on a `B`, which the iso converts to an `A` for `f` to transform, and
+/// then back. If the lens focuses on nothing, the structure is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{over_lens_iso, Utf8Lossy};
+/// # use fp_std::tuple::LensSecond;
+/// let shout = over_lens_iso(LensSecond, Utf8Lossy, |s: String| s.to_uppercase());
+/// assert_eq!(shout(&(1, b"hey".to_vec())), (1, b"HEY".to_vec()));
+/// ```
+#[cfg(feature = "std")]
+pub fn over_lens_iso<L, I, S, A, B, F>(_lens: L, iso: I, f: F) -> impl Fn(&S) -> S
+where
+    L: Lens<S, B>,
+    I: Iso<B, A>,
+    S: Clone,
+    B: Clone,
+    F: Fn(A) -> A,
+{
+    move |s| match L::get(s) {
+        Some(b) => L::set(iso.over(b.clone(), &f), s),
+        None => s.clone(),
+    }
+}
+
+/// Creates a function which transforms the value focused on by a prism, through an iso. The
+/// prism focuses on a `B`, which the iso converts to an `A` for `f` to transform, and then
+/// back. If the prism focuses on nothing, the structure is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::iso::{over_prism_iso, Swap};
+/// # use fp_std::prism::PrismSome;
+/// let bump = over_prism_iso(PrismSome, Swap, |(n, s): (u32, &str)| (n + 1, s));
+/// assert_eq!(bump(Some(("a", 1))), Some(("a", 2)));
+/// assert_eq!(bump(None), None);
+/// ```
+pub fn over_prism_iso<P, I, S, A, B, F>(prism: P, iso: I, f: F) -> impl Fn(S) -> S
+where
+    P: Prism<S, B>,
+    I: Iso<B, A>,
+    F: Fn(A) -> A,
+{
+    move |s| prism.over(s, |b| iso.over(b, &f))
+}
@@ -0,0 +1,220 @@
+//! Checks that optics obey the laws which make them behave predictably, for use in tests of
+//! hand-written lenses and prisms, including property tests with `proptest` or `quickcheck`.
+//!
+//! Each check returns a [LawViolation](crate::laws::LawViolation) describing the first law
+//! which does not hold for the given values, if there is one. Optics are passed by value, as
+//! they are everywhere else in this crate.
+//!
+//! Requires the `test-utils` and `std` features.
+//!
+//! ```rust
+//! # use fp_std::laws::check_lens_laws;
+//! # use fp_std::tuple::LensFirst;
+//! assert_eq!(check_lens_laws(LensFirst, &(1, "a"), 2, 3), Ok(()));
+//! ```
+//!
+//! ```rust
+//! # #[cfg(feature = "proptest")] {
+//! # use fp_std::laws::check_prism_laws;
+//! # use fp_std::prism::PrismSome;
+//! use proptest::prelude::*;
+//! proptest!(|(s: Option<u8>, a: u8)| {
+//!     prop_assert_eq!(check_prism_laws(PrismSome, &s, a), Ok(()));
+//! });
+//! # }
+//! ```
+
+/* VIOLATIONS *****************************************************************/
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// The law which an optic did not obey, along with the values which show that it did not.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LawViolation {
+    law: &'static str,
+    details: String,
+}
+
+impl LawViolation {
+    fn new(law: &'static str, details: String) -> Self {
+        LawViolation { law, details }
+    }
+
+    /// The name of the law which was broken, such as `"get-set"`.
+    pub fn law(&self) -> &'static str {
+        self.law
+    }
+
+    /// A description of the values for which the law was broken.
+    pub fn details(&self) -> &str {
+        &self.details
+    }
+}
+
+impl Display for LawViolation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} law does not hold: {}", self.law, self.details)
+    }
+}
+
+impl std::error::Error for LawViolation {}
+
+/* LENSES *********************************************************************/
+use fp_core::lens::Lens;
+
+/// Checks that a lens obeys the lens laws for a structure `s`, and values `a` and `b`:
+///
+/// - get-set: setting the focused part to what it already is leaves the structure unchanged.
+/// - set-get: getting the focused part after setting it produces the value that was set.
+/// - set-set: setting the focused part twice is the same as setting it only the second time.
+///
+/// Since a lens may focus on nothing, such as an index past the end of a `Vec`, the first two
+/// laws only apply when the lens focuses on part of `s`. Otherwise, in their place:
+///
+/// - set-no-focus: setting the focused part of a structure which has none leaves it unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_core::lens::Lens;
+/// # use fp_std::laws::check_lens_laws;
+/// // Setting the first element also changes the second, so even setting it to what it
+/// // already is changes the structure.
+/// struct Careless;
+///
+/// impl Lens<(u32, u32), u32> for Careless {
+///     fn get(s: &(u32, u32)) -> Option<&u32> {
+///         Some(&s.0)
+///     }
+///
+///     fn set(a: u32, s: &(u32, u32)) -> (u32, u32) {
+///         (a, s.1.saturating_sub(1))
+///     }
+/// }
+///
+/// let violation = check_lens_laws(Careless, &(1, 5), 1, 2).unwrap_err();
+/// assert_eq!(violation.law(), "get-set");
+/// ```
+pub fn check_lens_laws<L, S, A>(_lens: L, s: &S, a: A, b: A) -> Result<(), LawViolation>
+where
+    L: Lens<S, A>,
+    S: PartialEq + Debug,
+    A: Clone + PartialEq + Debug,
+{
+    match L::get(s) {
+        Some(focus) => {
+            let unchanged = L::set(focus.clone(), s);
+            if unchanged != *s {
+                return Err(LawViolation::new(
+                    "get-set",
+                    format!("setting {:?} to {:?} produced {:?}", s, focus, unchanged),
+                ));
+            }
+            let set = L::set(a.clone(), s);
+            if L::get(&set) != Some(&a) {
+                return Err(LawViolation::new(
+                    "set-get",
+                    format!(
+                        "setting {:?} to {:?} produced {:?}, which focuses on {:?}",
+                        s,
+                        a,
+                        set,
+                        L::get(&set),
+                    ),
+                ));
+            }
+        }
+        None => {
+            let set = L::set(a.clone(), s);
+            if set != *s {
+                return Err(LawViolation::new(
+                    "set-no-focus",
+                    format!(
+                        "{:?} has no focus, but setting it to {:?} produced {:?}",
+                        s, a, set
+                    ),
+                ));
+            }
+        }
+    }
+    let twice = L::set(b.clone(), &L::set(a.clone(), s));
+    let once = L::set(b.clone(), s);
+    if twice != once {
+        return Err(LawViolation::new(
+            "set-set",
+            format!(
+                "setting {:?} to {:?} and then {:?} produced {:?}, but setting it to {:?} \
+                 produced {:?}",
+                s, a, b, twice, b, once,
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/* PRISMS *********************************************************************/
+use crate::prism::Prism;
+
+/// Checks that a prism obeys the prism laws for a structure `s` and a value `a`:
+///
+/// - review-preview: previewing a reviewed value finds that same value.
+/// - preview-review: reviewing a previewed value rebuilds the original structure.
+/// - matching: [matching](crate::prism::Prism::matching) agrees with
+///   [preview](crate::prism::Prism::preview), and returns the structure unchanged when it is
+///   not the right case.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::laws::check_prism_laws;
+/// # use fp_std::prism::{PrismOk, PrismSome};
+/// assert_eq!(check_prism_laws(PrismSome, &None, 3), Ok(()));
+/// assert_eq!(check_prism_laws(PrismOk, &Err::<u32, _>("e"), 3), Ok(()));
+/// ```
+pub fn check_prism_laws<P, S, A>(prism: P, s: &S, a: A) -> Result<(), LawViolation>
+where
+    P: Prism<S, A>,
+    S: Clone + PartialEq + Debug,
+    A: Clone + PartialEq + Debug,
+{
+    let reviewed = prism.review(a.clone());
+    if prism.preview(&reviewed) != Some(&a) {
+        return Err(LawViolation::new(
+            "review-preview",
+            format!(
+                "reviewing {:?} produced {:?}, which previews as {:?}",
+                a,
+                reviewed,
+                prism.preview(&reviewed),
+            ),
+        ));
+    }
+    let previewed = prism.preview(s).cloned();
+    if let Some(focus) = &previewed {
+        let rebuilt = prism.review(focus.clone());
+        if rebuilt != *s {
+            return Err(LawViolation::new(
+                "preview-review",
+                format!(
+                    "{:?} previews as {:?}, which reviews as {:?}",
+                    s, focus, rebuilt
+                ),
+            ));
+        }
+    }
+    let matched = prism.matching(s.clone());
+    let consistent = match (&matched, &previewed) {
+        (Ok(matched), Some(focus)) => matched == focus,
+        (Err(unmatched), None) => unmatched == s,
+        _ => false,
+    };
+    if !consistent {
+        return Err(LawViolation::new(
+            "matching",
+            format!(
+                "{:?} previews as {:?}, but matches as {:?}",
+                s, previewed, matched
+            ),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Values which are computed only when they are first needed, and then remembered.
+//!
+//! ```rust
+//! # use fp_std::lazy::Lazy;
+//! let port = Lazy::new(|| "port=8080".split('=').nth(1).unwrap().parse::<u16>().unwrap());
+//! assert!(!port.is_forced());
+//! assert_eq!(*port.force(), 8080);
+//! assert!(port.is_forced());
+//! assert_eq!(format!("{:?}", port), "Lazy(8080)");
+//! ```
+
+/* LAZY VALUES ****************************************************************/
+use alloc::boxed::Box;
+use core::cell::{Cell, OnceCell};
+use core::fmt::{self, Debug, Formatter};
+
+/// A value which is computed by a closure the first time it is forced. The result is cached,
+/// so the closure is called at most once.
+///
+/// # Panics
+///
+/// Forcing a value from within its own closure panics. If the closure panics, the value is
+/// left uncomputed, and forcing it again panics too.
+pub struct Lazy<T> {
+    value: OnceCell<T>,
+    init: Cell<Option<Box<dyn FnOnce() -> T>>>,
+}
+
+impl<T: 'static> Lazy<T> {
+    /// Creates a value which is computed by `init` when it is first forced.
+    pub fn new<F>(init: F) -> Self
+    where
+        F: FnOnce() -> T + 'static,
+    {
+        Lazy {
+            value: OnceCell::new(),
+            init: Cell::new(Some(Box::new(init))),
+        }
+    }
+
+    /// Creates a value which has already been computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::lazy::Lazy;
+    /// let ready = Lazy::evaluated(3);
+    /// assert!(ready.is_forced());
+    /// assert_eq!(ready.into_inner(), 3);
+    /// ```
+    pub fn evaluated(value: T) -> Self {
+        Lazy {
+            value: OnceCell::from(value),
+            init: Cell::new(None),
+        }
+    }
+
+    /// Computes the value if it has not been already, and borrows it.
+    pub fn force(&self) -> &T {
+        self.value.get_or_init(|| match self.init.take() {
+            Some(init) => init(),
+            None => panic!("Lazy value was forced after its closure panicked"),
+        })
+    }
+
+    /// Borrows the value, if it has been computed.
+    pub fn get(&self) -> Option<&T> {
+        self.value.get()
+    }
+
+    /// Whether the value has been computed.
+    pub fn is_forced(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// Computes the value if it has not been already, and takes it.
+    pub fn into_inner(self) -> T {
+        self.force();
+        self.value.into_inner().unwrap()
+    }
+
+    /// Creates a value which is computed by transforming this one, once it is forced. This
+    /// value is not forced until then.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::lazy::Lazy;
+    /// let words = Lazy::new(|| "the quick brown fox".to_owned());
+    /// let count = words.map(|s| s.split(' ').count());
+    /// assert!(!count.is_forced());
+    /// assert_eq!(*count.force(), 4);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Lazy<U>
+    where
+        U: 'static,
+        F: FnOnce(T) -> U + 'static,
+    {
+        Lazy::new(move || f(self.into_inner()))
+    }
+
+    /// Creates a value which is computed by forcing the lazy value that a function of this one
+    /// returns, once it is forced. This value is not forced until then.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::lazy::Lazy;
+    /// let base = Lazy::new(|| 2u32);
+    /// let power = base.and_then(|b| Lazy::new(move || b.pow(10)));
+    /// assert_eq!(power.into_inner(), 1024);
+    /// ```
+    pub fn and_then<U, F>(self, f: F) -> Lazy<U>
+    where
+        U: 'static,
+        F: FnOnce(T) -> Lazy<U> + 'static,
+    {
+        Lazy::new(move || f(self.into_inner()).into_inner())
+    }
+}
+
+impl<T: Debug> Debug for Lazy<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.value.get() {
+            Some(value) => f.debug_tuple("Lazy").field(value).finish(),
+            None => f.write_str("Lazy(..)"),
+        }
+    }
+}
@@ -0,0 +1,612 @@
+//! Functions for working with [lenses](fp_core::lens::Lens), such as those for the elements of
+//! [tuples](crate::tuple::LensFirst), and lenses for the elements of
+//! [collections](crate::lens::LensIndex).
+//!
+//! A [Lens](fp_core::lens::Lens) is implemented by a type, and its methods take no `self`, so
+//! a lens never needs to be a value. Even so, the functions of lenses throughout this crate,
+//! such as [view](crate::lens::view) and [group_by_lens](crate::list::group_by_lens), take the
+//! lens as an argument, which is ignored: passing the lens by value lets the compiler infer the
+//! types it focuses between, which naming the lens as a type parameter would not.
+//!
+//! Requires the `std` feature.
+
+/* COMPOSITION ****************************************************************/
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use fp_core::lens::Lens;
+
+/// A lens which focuses through `Outer` on a `B`, and then through `Inner` on some part of that
+/// `B`. See [compose_lens](crate::lens::compose_lens).
+///
+/// Where `Outer` focuses on nothing, setting through the composed lens leaves the structure
+/// unchanged. Since the signatures of [Lens](fp_core::lens::Lens) offer no way to relate the
+/// lifetime of the intermediate type `B` to anything else, `B` must be `'static`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::ComposeLens;
+/// # use fp_std::tuple::{LensFirst, LensSecond};
+/// use fp_core::lens::Lens;
+/// type FirstOfSecond = ComposeLens<LensSecond, LensFirst, (char, bool)>;
+/// let nested = (1, ('a', true));
+/// assert_eq!(FirstOfSecond::get(&nested), Some(&'a'));
+/// assert_eq!(FirstOfSecond::set('b', &nested), (1, ('b', true)));
+/// ```
+pub struct ComposeLens<Outer, Inner, B> {
+    outer: PhantomData<fn() -> Outer>,
+    inner: PhantomData<fn() -> Inner>,
+    focus: PhantomData<fn() -> B>,
+}
+
+impl<S, A, B, Outer, Inner> Lens<S, A> for ComposeLens<Outer, Inner, B>
+where
+    S: Clone,
+    B: 'static,
+    Outer: Lens<S, B>,
+    Inner: Lens<B, A>,
+{
+    fn get(s: &S) -> Option<&A> {
+        Outer::get(s).and_then(Inner::get)
+    }
+
+    fn set(a: A, s: &S) -> S {
+        match Outer::get(s) {
+            Some(b) => Outer::set(Inner::set(a, b), s),
+            None => s.clone(),
+        }
+    }
+}
+
+impl<Outer, Inner, B> Clone for ComposeLens<Outer, Inner, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Outer, Inner, B> Copy for ComposeLens<Outer, Inner, B> {}
+
+impl<Outer, Inner, B> Default for ComposeLens<Outer, Inner, B> {
+    fn default() -> Self {
+        ComposeLens {
+            outer: PhantomData,
+            inner: PhantomData,
+            focus: PhantomData,
+        }
+    }
+}
+
+impl<Outer, Inner, B> Debug for ComposeLens<Outer, Inner, B> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("ComposeLens")
+    }
+}
+
+/// Composes two lenses, so that the result focuses through `outer`, and then through `inner`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::compose_lens;
+/// # use fp_std::ord::ascend_lens;
+/// # use fp_std::tuple::{LensFirst, LensSecond};
+/// let mut rows = vec![(("b", 2), 'x'), (("a", 3), 'y'), (("c", 1), 'z')];
+/// rows.sort_by(ascend_lens(compose_lens(LensFirst, LensSecond)));
+/// assert_eq!(rows, vec![(("c", 1), 'z'), (("b", 2), 'x'), (("a", 3), 'y')]);
+/// ```
+pub fn compose_lens<Outer, Inner, B>(_outer: Outer, _inner: Inner) -> ComposeLens<Outer, Inner, B> {
+    ComposeLens::default()
+}
+
+/// Composes any number of lenses written as a path, so that `lens_path!(a . b . c)` is
+/// `compose_lens(a, compose_lens(b, c))`, which focuses through `a`, then `b`, then `c`. Each
+/// segment of the path is an expression for a lens.
+///
+/// As with [compose_lens](crate::lens::compose_lens), the types of the intermediate structures
+/// are inferred from where the composed lens is used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::{increment, LensIndex};
+/// # use fp_std::lens_path;
+/// # use fp_std::tuple::{LensFirst, LensSecond};
+/// let order = ("order-1", (vec![("apple", 2), ("pear", 1)], true));
+/// assert_eq!(
+///     increment(lens_path!(LensSecond . LensFirst . LensIndex::<1> . LensSecond), 4, &order),
+///     ("order-1", (vec![("apple", 2), ("pear", 5)], true)),
+/// );
+/// ```
+#[macro_export]
+macro_rules! lens_path {
+    (@segment [$($done:tt)*] [$($lens:tt)+]) => {
+        $crate::lens_path!(@compose $($done)* ($($lens)+))
+    };
+    (@segment [$($done:tt)*] [$($lens:tt)+] . $($rest:tt)+) => {
+        $crate::lens_path!(@segment [$($done)* ($($lens)+)] [] $($rest)+)
+    };
+    (@segment [$($done:tt)*] [$($lens:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::lens_path!(@segment [$($done)*] [$($lens)* $next] $($rest)*)
+    };
+    (@compose ($($lens:tt)+)) => {
+        $($lens)+
+    };
+    (@compose ($($lens:tt)+) $($rest:tt)+) => {
+        $crate::lens::compose_lens($($lens)+, $crate::lens_path!(@compose $($rest)+))
+    };
+    ($($path:tt)+) => {
+        $crate::lens_path!(@segment [] [] $($path)+)
+    };
+}
+
+/* FIELDS *********************************************************************/
+
+/// A structure which has a lens for each of its fields, collected into a value of type
+/// [Lenses](crate::lens::Lenses::Lenses) with a field of the same name for each lens.
+///
+/// With the `derive` feature enabled, `#[derive(Lenses)]` implements this trait for structs
+/// with named fields, which must also implement `Clone`. For each field, it generates a lens
+/// type named after the struct and the field, so that the `x` field of `Point` has the lens
+/// `LensPointX`, which implements both [Lens](fp_core::lens::Lens) and
+/// [LensMut](crate::lens::LensMut). It also generates the struct `PointLenses` to hold them.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// # use fp_std::lens::{compose_lens, increment, Lenses};
+/// use fp_core::lens::Lens;
+/// #[derive(Lenses, Clone, PartialEq, Debug)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Lenses, Clone, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     position: Point,
+/// }
+///
+/// let player = Player { name: "Ash".to_owned(), position: Point { x: 1, y: 2 } };
+/// assert_eq!(LensPlayerName::get(&player), Some(&"Ash".to_owned()));
+///
+/// let up = increment(compose_lens(Player::lenses().position, Point::lenses().y), 1, &player);
+/// assert_eq!(up.position, Point { x: 1, y: 3 });
+/// # }
+/// ```
+///
+/// Each lens has the visibility of its field, so a public struct may keep some of its fields,
+/// and their lenses, private:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// mod account {
+///     use fp_std::lens::Lenses;
+///
+///     #[derive(Lenses, Clone)]
+///     pub struct Account {
+///         pub owner: String,
+///         balance: u64,
+///     }
+///
+///     impl Account {
+///         pub fn open(owner: &str) -> Self {
+///             Account { owner: owner.to_owned(), balance: 0 }
+///         }
+///     }
+/// }
+///
+/// # use fp_std::lens::set_via;
+/// let account = account::Account::open("ash");
+/// let renamed = set_via(account::LensAccountOwner, "brock".to_owned())(&account);
+/// assert_eq!(renamed.owner, "brock");
+/// # }
+/// ```
+pub trait Lenses {
+    /// The structure which holds a lens for each field.
+    type Lenses;
+
+    /// The lens for each field.
+    fn lenses() -> Self::Lenses;
+}
+
+#[cfg(feature = "derive")]
+pub use fp_std_derive::Lenses;
+
+#[doc(hidden)]
+pub struct __Char<const C: char>;
+
+#[doc(hidden)]
+pub trait __Field<Name> {
+    type Lens: Default;
+    type Focus;
+}
+
+/* ACCESSORS ******************************************************************/
+
+/// Borrows the part of a structure focused on by a lens, if there is one. This is the same as
+/// [Lens::get](fp_core::lens::Lens::get), but with the lens passed by value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::view;
+/// # use fp_std::lens_path;
+/// # use fp_std::tuple::{LensFirst, LensSecond};
+/// let entry = ("config", ("port", 8080));
+/// assert_eq!(view(LensSecond, &entry), Some(&("port", 8080)));
+/// assert_eq!(view(lens_path!(LensSecond . LensFirst), &entry), Some(&"port"));
+/// ```
+pub fn view<L, S, A>(_lens: L, s: &S) -> Option<&A>
+where
+    L: Lens<S, A>,
+{
+    L::get(s)
+}
+
+/// Replaces the part of a structure focused on by a lens with the result of a function of it.
+/// If the lens focuses on nothing, the structure is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::over;
+/// # use fp_std::tuple::LensFirst;
+/// let shouted = over(LensFirst, |name: &String| name.to_uppercase(), &("ash".to_owned(), 3));
+/// assert_eq!(shouted, ("ASH".to_owned(), 3));
+/// ```
+pub fn over<L, S, A, F>(_lens: L, f: F, s: &S) -> S
+where
+    L: Lens<S, A>,
+    S: Clone,
+    F: FnOnce(&A) -> A,
+{
+    match L::get(s) {
+        Some(a) => L::set(f(a), s),
+        None => s.clone(),
+    }
+}
+
+/// Creates a function which sets the part of a structure focused on by a lens to `a`, so that
+/// the update can be used as a stage of a pipeline.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::set_via;
+/// # use fp_std::pipe;
+/// # use fp_std::tuple::{LensFirst, LensSecond};
+/// let reset = pipe!(set_via(LensFirst, 0), |s: (u32, bool)| set_via(LensSecond, false)(&s));
+/// assert_eq!(reset(&(5, true)), (0, false));
+/// ```
+pub fn set_via<L, S, A>(_lens: L, a: A) -> impl Fn(&S) -> S
+where
+    L: Lens<S, A>,
+    A: Clone,
+{
+    move |s| L::set(a.clone(), s)
+}
+
+/* IN-PLACE UPDATES ***********************************************************/
+
+/// A lens which sets the part of a structure it focuses on without cloning the rest of the
+/// structure, when the structure is owned or mutably borrowed, and which can borrow that part
+/// mutably to update it in place.
+///
+/// [Lens::set](fp_core::lens::Lens::set) only borrows the structure, and so must clone every
+/// other part of it to build the result, which is costly when those parts are large. Since
+/// these methods never clone, this is not a subtrait of [Lens](fp_core::lens::Lens), and is
+/// implemented even for structures which cannot be cloned, such as a tuple containing a
+/// `Mutex`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::LensMut;
+/// # use fp_std::tuple::{LensFirst, LensSecond};
+/// # use std::sync::Mutex;
+/// let mut page = ("draft", vec![0u8; 1 << 20]);
+/// LensFirst::set_mut(&mut page, "published");
+/// assert_eq!(page.0, "published");
+///
+/// let page = LensSecond::set_owned(vec![], page);
+/// assert_eq!(page, ("published", vec![]));
+///
+/// let mut guarded = ("hits", Mutex::new(0));
+/// LensFirst::set_mut(&mut guarded, "misses");
+/// assert_eq!(guarded.0, "misses");
+/// ```
+pub trait LensMut<S, A> {
+    /// Mutably borrows the focused part of a structure, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::lens::{LensIndex, LensMut};
+    /// let mut queue = vec![3, 5];
+    /// if let Some(front) = LensIndex::<0>::get_mut(&mut queue) {
+    ///     *front -= 1;
+    /// }
+    /// assert_eq!(queue, vec![2, 5]);
+    /// assert_eq!(LensIndex::<2>::get_mut(&mut queue), None);
+    /// ```
+    fn get_mut(s: &mut S) -> Option<&mut A>;
+
+    /// Updates the focused part of a mutably borrowed structure in place with a function, so
+    /// that neither the structure nor the focused part is rebuilt. If the lens focuses on
+    /// nothing, the function is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::lens::LensMut;
+    /// # use fp_std::tuple::LensSecond;
+    /// let mut history = ("log", vec![1, 2]);
+    /// LensSecond::over_mut(|entries: &mut Vec<u32>| entries.push(3), &mut history);
+    /// assert_eq!(history, ("log", vec![1, 2, 3]));
+    /// ```
+    fn over_mut<F>(f: F, s: &mut S)
+    where
+        F: FnOnce(&mut A),
+    {
+        if let Some(a) = Self::get_mut(s) {
+            f(a);
+        }
+    }
+
+    /// Sets the focused part of a mutably borrowed structure. If the lens focuses on nothing,
+    /// the structure is left unchanged.
+    fn set_mut(s: &mut S, a: A);
+
+    /// Sets the focused part of an owned structure, returning the updated structure.
+    fn set_owned(a: A, mut s: S) -> S {
+        Self::set_mut(&mut s, a);
+        s
+    }
+}
+
+/// Setting through a composed lens sets the focused part of the intermediate `B` in place, so
+/// neither the structure nor the `B` is cloned.
+impl<S, A, B, Outer, Inner> LensMut<S, A> for ComposeLens<Outer, Inner, B>
+where
+    B: 'static,
+    Outer: LensMut<S, B>,
+    Inner: LensMut<B, A>,
+{
+    fn get_mut(s: &mut S) -> Option<&mut A> {
+        Outer::get_mut(s).and_then(Inner::get_mut)
+    }
+
+    fn set_mut(s: &mut S, a: A) {
+        if let Some(b) = Outer::get_mut(s) {
+            Inner::set_mut(b, a);
+        }
+    }
+}
+
+/* UPDATES ********************************************************************/
+use core::ops::Add;
+
+/// Adds `n` to the part of a structure focused on by a lens. If the lens focuses on nothing,
+/// the structure is returned unchanged. To keep the result within bounds, see
+/// [over_clamped](crate::ord::over_clamped).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::increment;
+/// # use fp_std::tuple::LensSecond;
+/// let visits = ("/home", 41);
+/// assert_eq!(increment(LensSecond, 1, &visits), ("/home", 42));
+/// ```
+pub fn increment<L, S, A>(_lens: L, n: A, s: &S) -> S
+where
+    L: Lens<S, A>,
+    S: Clone,
+    A: Add<Output = A> + Clone,
+{
+    match L::get(s) {
+        Some(a) => L::set(a.clone() + n, s),
+        None => s.clone(),
+    }
+}
+
+/* COLLECTIONS ****************************************************************/
+use core::hash::Hash;
+use std::collections::{BTreeMap, HashMap};
+
+/// A [Lens](fp_core::lens::Lens) for the element at index `N` of a `Vec` or an array.
+///
+/// Getting an index which is out of bounds finds nothing, and setting it leaves the structure
+/// unchanged. Appending to a `Vec` is not a lens operation, since the lens laws require that
+/// setting gets back only what was already there to be got.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::LensIndex;
+/// use fp_core::lens::Lens;
+/// let scores = vec![3, 5];
+/// assert_eq!(LensIndex::<1>::get(&scores), Some(&5));
+/// assert_eq!(LensIndex::<2>::get(&scores), None);
+/// assert_eq!(LensIndex::<0>::set(4, &scores), vec![4, 5]);
+/// assert_eq!(LensIndex::<2>::set(9, &scores), vec![3, 5]);
+/// assert_eq!(LensIndex::<1>::set('b', &['a', 'a', 'a']), ['a', 'b', 'a']);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LensIndex<const N: usize>;
+
+impl<A: Clone, const N: usize> Lens<Vec<A>, A> for LensIndex<N> {
+    fn get(s: &Vec<A>) -> Option<&A> {
+        s.get(N)
+    }
+
+    fn set(a: A, s: &Vec<A>) -> Vec<A> {
+        let mut s = s.clone();
+        if let Some(element) = s.get_mut(N) {
+            *element = a;
+        }
+        s
+    }
+}
+
+impl<A, const N: usize> LensMut<Vec<A>, A> for LensIndex<N> {
+    fn get_mut(s: &mut Vec<A>) -> Option<&mut A> {
+        s.get_mut(N)
+    }
+
+    fn set_mut(s: &mut Vec<A>, a: A) {
+        if let Some(element) = s.get_mut(N) {
+            *element = a;
+        }
+    }
+}
+
+impl<A: Clone, const N: usize, const M: usize> Lens<[A; M], A> for LensIndex<N> {
+    fn get(s: &[A; M]) -> Option<&A> {
+        s.get(N)
+    }
+
+    fn set(a: A, s: &[A; M]) -> [A; M] {
+        let mut s = s.clone();
+        if let Some(element) = s.get_mut(N) {
+            *element = a;
+        }
+        s
+    }
+}
+
+impl<A, const N: usize, const M: usize> LensMut<[A; M], A> for LensIndex<N> {
+    fn get_mut(s: &mut [A; M]) -> Option<&mut A> {
+        s.get_mut(N)
+    }
+
+    fn set_mut(s: &mut [A; M], a: A) {
+        if let Some(element) = s.get_mut(N) {
+            *element = a;
+        }
+    }
+}
+
+/// A type which stands for a particular key of type `K`, so that a [LensKey](crate::lens::LensKey)
+/// can focus on the entry of a map with that key.
+///
+/// Since a [Lens](fp_core::lens::Lens) is implemented by a type rather than a value, the key it
+/// focuses on must be known from its type alone.
+pub trait Key<K> {
+    /// The key which this type stands for.
+    fn key() -> K;
+}
+
+/// A [Lens](fp_core::lens::Lens) for the value of the entry of a `HashMap` or a `BTreeMap` with
+/// the key that `K` stands for.
+///
+/// Getting a key which is missing from the map finds nothing, while setting it inserts a new
+/// entry. Setting a key which is present replaces its value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::{Key, LensKey};
+/// # use std::collections::HashMap;
+/// use fp_core::lens::Lens;
+/// struct Port;
+/// impl Key<&'static str> for Port {
+///     fn key() -> &'static str {
+///         "port"
+///     }
+/// }
+///
+/// type PortLens = LensKey<Port>;
+/// let config: HashMap<&str, u16> = HashMap::new();
+/// assert_eq!(PortLens::get(&config), None);
+/// let config = PortLens::set(8080, &config);
+/// assert_eq!(PortLens::get(&config), Some(&8080));
+/// assert_eq!(PortLens::set(443, &config)["port"], 443);
+/// ```
+pub struct LensKey<K> {
+    key: PhantomData<fn() -> K>,
+}
+
+impl<K, Q, V> Lens<HashMap<Q, V>, V> for LensKey<K>
+where
+    K: Key<Q>,
+    Q: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn get(s: &HashMap<Q, V>) -> Option<&V> {
+        s.get(&K::key())
+    }
+
+    fn set(v: V, s: &HashMap<Q, V>) -> HashMap<Q, V> {
+        let mut s = s.clone();
+        s.insert(K::key(), v);
+        s
+    }
+}
+
+impl<K, Q, V> LensMut<HashMap<Q, V>, V> for LensKey<K>
+where
+    K: Key<Q>,
+    Q: Eq + Hash,
+{
+    fn get_mut(s: &mut HashMap<Q, V>) -> Option<&mut V> {
+        s.get_mut(&K::key())
+    }
+
+    fn set_mut(s: &mut HashMap<Q, V>, v: V) {
+        s.insert(K::key(), v);
+    }
+}
+
+impl<K, Q, V> Lens<BTreeMap<Q, V>, V> for LensKey<K>
+where
+    K: Key<Q>,
+    Q: Ord + Clone,
+    V: Clone,
+{
+    fn get(s: &BTreeMap<Q, V>) -> Option<&V> {
+        s.get(&K::key())
+    }
+
+    fn set(v: V, s: &BTreeMap<Q, V>) -> BTreeMap<Q, V> {
+        let mut s = s.clone();
+        s.insert(K::key(), v);
+        s
+    }
+}
+
+impl<K, Q, V> LensMut<BTreeMap<Q, V>, V> for LensKey<K>
+where
+    K: Key<Q>,
+    Q: Ord,
+{
+    fn get_mut(s: &mut BTreeMap<Q, V>) -> Option<&mut V> {
+        s.get_mut(&K::key())
+    }
+
+    fn set_mut(s: &mut BTreeMap<Q, V>, v: V) {
+        s.insert(K::key(), v);
+    }
+}
+
+impl<K> Clone for LensKey<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for LensKey<K> {}
+
+impl<K> Default for LensKey<K> {
+    fn default() -> Self {
+        LensKey { key: PhantomData }
+    }
+}
+
+impl<K> Debug for LensKey<K> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("LensKey")
+    }
+}
@@ -25,6 +25,178 @@
 //! Despite this all working, it's really not all that pleasant an experience overall. This
 //! project exists mostly as an experiment. If it provides value to you, feel free to use it,
 //! but do not consider anything you see here to be best practice.
+//!
+//! # Features
+//!
+//! Without the default `std` feature, this crate is `#![no_std]`. The `alloc` feature restores
+//! those functions which only require an allocator, such as those producing a `Vec`. Since
+//! [fp-core][] requires `std`, the functions which make use of its traits require `std` too.
+//!
+//! With neither feature, the combinators of the `function`, `curry`, `tuple`, `option`, and
+//! `result` modules are still available, so they can be used in embedded projects, for example
+//! with `default-features = false` in `Cargo.toml`.
+//!
+//! The `proptest` and `quickcheck` features implement the respective `Arbitrary` traits for the
+//! types in this crate, so that property tests can generate them without custom strategies.
+//!
+//! ```rust
+//! # #[cfg(feature = "proptest")] {
+//! # use fp_std::interval::Interval;
+//! use proptest::prelude::*;
+//! proptest!(|(a: Interval<u8>, b: Interval<u8>)| {
+//!     prop_assert_eq!(a.intersect(&b), b.intersect(&a));
+//! });
+//! # }
+//! ```
+//!
+//! ```rust
+//! # #[cfg(feature = "quickcheck")] {
+//! # use fp_std::interval::IntervalSet;
+//! fn union_commutes(a: IntervalSet<u8>, b: IntervalSet<u8>) -> bool {
+//!     a.union(&b) == b.union(&a)
+//! }
+//! quickcheck::quickcheck(union_commutes as fn(_, _) -> bool);
+//! # }
+//! ```
+//!
+//! The `time` feature enables those functions which depend on the system clock, such as
+//! [bound_memo](crate::function::bound_memo).
+//!
+//! The `rand` feature enables those functions which make random choices, such as
+//! [shuffle](crate::list::shuffle). They take the random number generator as an argument. It
+//! also enables the [random](crate::random) module, for composing random generators.
+//!
+//! The `test-utils` feature enables the [harness](crate::harness) module, of assertions for
+//! checking that pure functions behave as expected over sets of inputs, and the
+//! [laws](crate::laws) module, of checks that hand-written optics obey their laws.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(feature = "derive", feature = "std"))]
+#[doc(hidden)]
+pub use fp_core as __fp_core;
+
+/// Composes the lenses for a path of struct fields and tuple elements, so that
+/// `lens!(Order.customer.address.0)` focuses on the first element of the `address` of the
+/// `customer` of an `Order`. It is written as the type of the structure, and then the fields,
+/// as they would be accessed on a value of that type.
+///
+/// The lenses for the fields of structs are those generated by
+/// [derive(Lenses)](crate::lens::Lenses), and the lenses for the elements of tuples are
+/// [LensNth](crate::tuple::LensNth). For paths of other lenses, see
+/// [lens_path!](crate::lens_path).
+///
+/// Requires the `derive` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// # use fp_std::lens;
+/// # use fp_std::lens::{increment, Lenses};
+/// use fp_core::lens::Lens;
+/// #[derive(Lenses, Clone, Debug, PartialEq)]
+/// struct Customer {
+///     name: String,
+///     address: (u32, String),
+/// }
+///
+/// #[derive(Lenses, Clone, Debug, PartialEq)]
+/// struct Order {
+///     customer: Customer,
+///     lines: Vec<(String, (u32, u32))>,
+/// }
+///
+/// let order = Order {
+///     customer: Customer { name: "Ash".to_owned(), address: (12, "Main St".to_owned()) },
+///     lines: vec![],
+/// };
+/// let moved = increment(lens!(Order.customer.address.0), 2, &order);
+/// assert_eq!(moved.customer.address, (14, "Main St".to_owned()));
+///
+/// let totals = (("", 3u8), (2u8, 'x'));
+/// assert_eq!(increment(lens!(((&str, u8), (u8, char)).0.1), 1, &totals), (("", 4), (2, 'x')));
+/// # }
+/// ```
+#[cfg(all(feature = "derive", feature = "std"))]
+pub use fp_std_derive::lens;
 
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod arbitrary;
+pub mod array;
+pub mod bounded;
+pub mod constants;
+pub mod curry;
+#[cfg(feature = "alloc")]
+pub mod debug;
+pub mod effects;
+pub mod either;
+pub mod enumerable;
+pub mod error;
 pub mod function;
+#[cfg(feature = "async")]
+pub mod future;
+pub mod getter;
+#[cfg(feature = "test-utils")]
+pub mod harness;
+pub mod id;
+#[cfg(feature = "alloc")]
+pub mod interval;
+pub mod iso;
+#[cfg(all(feature = "test-utils", feature = "std"))]
+pub mod laws;
+#[cfg(feature = "alloc")]
+pub mod lazy;
+#[cfg(feature = "std")]
+pub mod lens;
+#[cfg(feature = "alloc")]
+pub mod list;
+pub mod logic;
+#[cfg(feature = "alloc")]
+pub mod machine;
+pub mod map;
+pub mod math;
+#[cfg(feature = "std")]
+pub mod memo;
+#[cfg(feature = "std")]
+pub mod monoid;
+#[cfg(feature = "alloc")]
+pub mod nonempty;
+pub mod option;
+pub mod ord;
+#[cfg(feature = "alloc")]
+pub mod parse;
+#[cfg(feature = "alloc")]
+pub mod predicate;
+pub mod prelude;
+pub mod prism;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "alloc")]
+pub mod reader;
+pub mod reducer;
+pub mod refined;
+pub mod result;
+pub mod seq;
+#[cfg(feature = "alloc")]
+pub mod state;
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "alloc")]
+pub mod string;
+pub mod tagged;
+#[cfg(feature = "alloc")]
+pub mod trampoline;
+pub mod transducer;
+pub mod traversal;
+#[cfg(feature = "alloc")]
+pub mod tree;
 pub mod tuple;
+pub mod units;
+#[cfg(feature = "std")]
+pub mod validation;
+#[cfg(feature = "std")]
+pub mod writer;
@@ -0,0 +1,2404 @@
+//! Functions for manipulating lists, and anything else that can be iterated.
+
+/* GROUPING *******************************************************************/
+use crate::either::Either;
+use crate::nonempty::NonEmpty;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+#[cfg(feature = "std")]
+use std::collections::hash_map::Entry;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Groups items by a computed key. Within each group, the items remain in their original order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::group_by;
+/// let by_length = group_by(|s: &&str| s.len());
+/// let groups = by_length(vec!["a", "bc", "d", "ef", "ghi"]);
+/// assert_eq!(groups[&1], vec!["a", "d"]);
+/// assert_eq!(groups[&2], vec!["bc", "ef"]);
+/// assert_eq!(groups[&3], vec!["ghi"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn group_by<I, K, F>(key_fn: F) -> impl Fn(I) -> HashMap<K, Vec<I::Item>>
+where
+    I: IntoIterator,
+    K: Eq + Hash,
+    F: Fn(&I::Item) -> K,
+{
+    move |iter| {
+        let mut groups: HashMap<K, Vec<I::Item>> = HashMap::new();
+        for item in iter {
+            groups.entry(key_fn(&item)).or_default().push(item);
+        }
+        groups
+    }
+}
+
+/// Groups items by the part of them focused on by a lens. Items which the lens does not focus
+/// on anything in are grouped under `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::group_by_lens;
+/// # use fp_std::tuple::LensSecond;
+/// let by_team = group_by_lens(LensSecond);
+/// let groups = by_team(vec![("ann", "red"), ("bo", "blue"), ("cy", "red")]);
+/// assert_eq!(groups[&Some("red")], vec![("ann", "red"), ("cy", "red")]);
+/// assert_eq!(groups[&Some("blue")], vec![("bo", "blue")]);
+/// ```
+#[cfg(feature = "std")]
+pub fn group_by_lens<I, L, A>(_lens: L) -> impl Fn(I) -> HashMap<Option<A>, Vec<I::Item>>
+where
+    I: IntoIterator,
+    L: Lens<I::Item, A>,
+    A: Eq + Hash + Clone,
+{
+    group_by(|item| L::get(item).cloned())
+}
+
+/// Indexes items by a computed key. If multiple items have the same key, only the last of them
+/// is kept. To fail instead, see [try_index_by](crate::list::try_index_by).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::index_by;
+/// let by_id = index_by(|user: &(u32, &str)| user.0);
+/// let users = by_id(vec![(1, "ann"), (2, "bo"), (1, "ann b.")]);
+/// assert_eq!(users[&1], (1, "ann b."));
+/// assert_eq!(users[&2], (2, "bo"));
+/// ```
+#[cfg(feature = "std")]
+pub fn index_by<I, K, F>(key_fn: F) -> impl Fn(I) -> HashMap<K, I::Item>
+where
+    I: IntoIterator,
+    K: Eq + Hash,
+    F: Fn(&I::Item) -> K,
+{
+    move |iter| iter.into_iter().map(|item| (key_fn(&item), item)).collect()
+}
+
+/// Indexes items by a computed key, failing with
+/// [Error::DuplicateKey](crate::error::Error::DuplicateKey) if multiple items have the same
+/// key.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::error::Error;
+/// # use fp_std::list::try_index_by;
+/// let by_id = try_index_by(|user: &(u32, &str)| user.0);
+/// assert_eq!(by_id(vec![(1, "ann"), (2, "bo")]).unwrap()[&2], (2, "bo"));
+/// assert_eq!(by_id(vec![(1, "ann"), (1, "ann b.")]), Err(Error::DuplicateKey));
+/// ```
+#[cfg(feature = "std")]
+pub fn try_index_by<I, K, F>(key_fn: F) -> impl Fn(I) -> Result<HashMap<K, I::Item>, Error>
+where
+    I: IntoIterator,
+    K: Eq + Hash,
+    F: Fn(&I::Item) -> K,
+{
+    move |iter| {
+        let mut index = HashMap::new();
+        for item in iter {
+            match index.entry(key_fn(&item)) {
+                Entry::Occupied(_) => return Err(Error::DuplicateKey),
+                Entry::Vacant(entry) => {
+                    entry.insert(item);
+                }
+            }
+        }
+        Ok(index)
+    }
+}
+
+/// Counts items by a computed key. Only keys which some item has are in the result, so every
+/// count is positive. For counts which can be combined, see [Counter](crate::map::Counter).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::count_by;
+/// let by_status = count_by(|response: &(u16, &str)| response.0);
+/// let counts = by_status(vec![(200, "/"), (404, "/a"), (200, "/b")]);
+/// assert_eq!(counts[&200], 2);
+/// assert_eq!(counts[&404], 1);
+/// assert!(!counts.contains_key(&500));
+/// ```
+#[cfg(feature = "std")]
+pub fn count_by<I, K, F>(key_fn: F) -> impl Fn(I) -> HashMap<K, usize>
+where
+    I: IntoIterator,
+    K: Eq + Hash,
+    F: Fn(&I::Item) -> K,
+{
+    move |iter| {
+        let mut counts: HashMap<K, usize> = HashMap::new();
+        for item in iter {
+            *counts.entry(key_fn(&item)).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// Groups adjacent items which have equal keys, preserving the order of both the groups and the
+/// items within them. Unlike [group_by](crate::list::group_by), items with the same key which
+/// are separated by others end up in separate groups.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::group_runs;
+/// let by_parity = group_runs(|x: &u32| x % 2);
+/// assert_eq!(
+///     by_parity(vec![1, 3, 2, 4, 5]),
+///     vec![(1, vec![1, 3]), (0, vec![2, 4]), (1, vec![5])],
+/// );
+/// ```
+pub fn group_runs<I, K, F>(key_fn: F) -> impl Fn(I) -> Vec<(K, Vec<I::Item>)>
+where
+    I: IntoIterator,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K,
+{
+    move |iter| {
+        let mut runs: Vec<(K, Vec<I::Item>)> = Vec::new();
+        for item in iter {
+            let key = key_fn(&item);
+            match runs.last_mut() {
+                Some((last, run)) if *last == key => run.push(item),
+                _ => runs.push((key, alloc::vec![item])),
+            }
+        }
+        runs
+    }
+}
+
+/// Groups items by a computed key, as [group_by](crate::list::group_by) does, but with the
+/// groups typed as [NonEmpty](crate::nonempty::NonEmpty) lists, since no group can be empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::group_by_nonempty;
+/// let by_length = group_by_nonempty(|s: &&str| s.len());
+/// let groups = by_length(vec!["a", "bc", "d"]);
+/// assert_eq!(groups[&1].last(), &"d");
+/// assert_eq!(groups[&2].first(), &"bc");
+/// ```
+#[cfg(feature = "std")]
+pub fn group_by_nonempty<I, K, F>(key_fn: F) -> impl Fn(I) -> HashMap<K, NonEmpty<I::Item>>
+where
+    I: IntoIterator,
+    K: Eq + Hash,
+    F: Fn(&I::Item) -> K,
+{
+    move |iter| {
+        let mut groups: HashMap<K, NonEmpty<I::Item>> = HashMap::new();
+        for item in iter {
+            match groups.entry(key_fn(&item)) {
+                Entry::Occupied(mut group) => group.get_mut().push(item),
+                Entry::Vacant(group) => {
+                    group.insert(NonEmpty::singleton(item));
+                }
+            }
+        }
+        groups
+    }
+}
+
+/// Groups adjacent items which have equal keys, as [group_runs](crate::list::group_runs) does,
+/// but with the runs typed as [NonEmpty](crate::nonempty::NonEmpty) lists, since no run can be
+/// empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::group_runs_nonempty;
+/// # use fp_std::nonempty::NonEmpty;
+/// let by_parity = group_runs_nonempty(|x: &u32| x % 2);
+/// assert_eq!(
+///     by_parity(vec![1, 3, 2]),
+///     vec![(1, NonEmpty::new(1, vec![3])), (0, NonEmpty::singleton(2))],
+/// );
+/// ```
+pub fn group_runs_nonempty<I, K, F>(key_fn: F) -> impl Fn(I) -> Vec<(K, NonEmpty<I::Item>)>
+where
+    I: IntoIterator,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K,
+{
+    move |iter| {
+        let mut runs: Vec<(K, NonEmpty<I::Item>)> = Vec::new();
+        for item in iter {
+            let key = key_fn(&item);
+            match runs.last_mut() {
+                Some((last, run)) if *last == key => run.push(item),
+                _ => runs.push((key, NonEmpty::singleton(item))),
+            }
+        }
+        runs
+    }
+}
+
+/// Compresses runs of equal adjacent items into pairs of the item and the length of its run.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::run_length_encode;
+/// assert_eq!(
+///     run_length_encode("aaabccdd".chars()),
+///     vec![('a', 3), ('b', 1), ('c', 2), ('d', 2)],
+/// );
+/// ```
+pub fn run_length_encode<I>(iter: I) -> Vec<(I::Item, usize)>
+where
+    I: IntoIterator,
+    I::Item: PartialEq,
+{
+    let mut runs: Vec<(I::Item, usize)> = Vec::new();
+    for item in iter {
+        match runs.last_mut() {
+            Some((last, count)) if *last == item => *count += 1,
+            _ => runs.push((item, 1)),
+        }
+    }
+    runs
+}
+
+/// Expands pairs of an item and a count into that many copies of the item. This is the inverse
+/// of [run_length_encode](crate::list::run_length_encode).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::run_length_decode;
+/// let decoded: String = run_length_decode(vec![('a', 3), ('b', 1)]).into_iter().collect();
+/// assert_eq!(decoded, "aaab");
+/// ```
+pub fn run_length_decode<I, T>(iter: I) -> Vec<T>
+where
+    I: IntoIterator<Item = (T, usize)>,
+    T: Clone,
+{
+    iter.into_iter()
+        .flat_map(|(item, count)| core::iter::repeat_n(item, count))
+        .collect()
+}
+
+/// Counts the number of times each distinct item occurs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::frequencies;
+/// let counts = frequencies("hello".chars());
+/// assert_eq!(counts[&'l'], 2);
+/// assert_eq!(counts[&'h'], 1);
+/// assert_eq!(counts.get(&'z'), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn frequencies<I>(iter: I) -> HashMap<I::Item, usize>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    let mut counts = HashMap::new();
+    for item in iter {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Splits items in two according to a function, collecting the `Left` results in the first
+/// list and the `Right` results in the second.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::Either;
+/// # use fp_std::list::partition_map;
+/// let parse_or_keep = partition_map(|s: &str| match s.parse::<u32>() {
+///     Ok(n) => Either::Left(n),
+///     Err(..) => Either::Right(s),
+/// });
+/// assert_eq!(parse_or_keep(vec!["1", "a", "2", "b"]), (vec![1, 2], vec!["a", "b"]));
+/// ```
+pub fn partition_map<I, A, B, F>(f: F) -> impl Fn(I) -> (Vec<A>, Vec<B>)
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> Either<A, B>,
+{
+    move |iter| {
+        let mut lefts = Vec::new();
+        let mut rights = Vec::new();
+        for item in iter {
+            match f(item) {
+                Either::Left(a) => lefts.push(a),
+                Either::Right(b) => rights.push(b),
+            }
+        }
+        (lefts, rights)
+    }
+}
+
+/// Splits items in two according to a function in a single pass, as with
+/// [partition_map](crate::list::partition_map), but taking the items directly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::Either;
+/// # use fp_std::list::partition_with;
+/// let (evens, odds) = partition_with(
+///     |n: u32| if n % 2 == 0 { Either::Left(n / 2) } else { Either::Right(n) },
+///     1..=6,
+/// );
+/// assert_eq!(evens, vec![1, 2, 3]);
+/// assert_eq!(odds, vec![1, 3, 5]);
+/// ```
+pub fn partition_with<I, A, B, F>(f: F, iter: I) -> (Vec<A>, Vec<B>)
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> Either<A, B>,
+{
+    partition_map(f)(iter)
+}
+
+/// Splits each item into a pair with a function, collecting the first elements in one list and
+/// the second elements in another, in a single pass.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::unzip_with;
+/// let (names, ages) = unzip_with(
+///     |line: &str| {
+///         let (name, age) = line.split_once(':').unwrap();
+///         (name, age.parse::<u32>().unwrap())
+///     },
+///     vec!["ann:31", "bo:27"],
+/// );
+/// assert_eq!(names, vec!["ann", "bo"]);
+/// assert_eq!(ages, vec![31, 27]);
+/// ```
+pub fn unzip_with<I, A, B, F>(f: F, iter: I) -> (Vec<A>, Vec<B>)
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> (A, B),
+{
+    let iter = iter.into_iter();
+    let (lower, _) = iter.size_hint();
+    let mut lefts = Vec::with_capacity(lower);
+    let mut rights = Vec::with_capacity(lower);
+    for item in iter {
+        let (a, b) = f(item);
+        lefts.push(a);
+        rights.push(b);
+    }
+    (lefts, rights)
+}
+
+/* SPLITTING ******************************************************************/
+
+/// What [split_when](crate::list::split_when) does with the items it splits on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// Separators are discarded. As with `str::split`, there is always one more group than
+    /// there are separators, so some groups may be empty.
+    Drop,
+    /// Each separator begins the group which follows it, such as when splitting on record
+    /// headers. Empty groups are not produced.
+    Leading,
+    /// Each separator ends the group which precedes it, such as when splitting on record
+    /// terminators. Empty groups are not produced.
+    Trailing,
+}
+
+/// Splits items into groups wherever an item matches the predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::{split_when, Separator};
+/// let on_zero = split_when(|x: &u32| *x == 0, Separator::Drop);
+/// assert_eq!(on_zero(vec![1, 2, 0, 3, 0]), vec![vec![1, 2], vec![3], vec![]]);
+///
+/// let on_header = split_when(|line: &&str| line.starts_with('#'), Separator::Leading);
+/// assert_eq!(
+///     on_header(vec!["# a", "1", "# b", "2", "3"]),
+///     vec![vec!["# a", "1"], vec!["# b", "2", "3"]],
+/// );
+///
+/// let on_end = split_when(|x: &char| *x == ';', Separator::Trailing);
+/// assert_eq!(on_end("a;bc;".chars()), vec![vec!['a', ';'], vec!['b', 'c', ';']]);
+/// ```
+pub fn split_when<I, F>(pred: F, separator: Separator) -> impl Fn(I) -> Vec<Vec<I::Item>>
+where
+    I: IntoIterator,
+    F: Fn(&I::Item) -> bool,
+{
+    move |iter| {
+        let mut groups = Vec::new();
+        let mut group = Vec::new();
+        for item in iter {
+            if !pred(&item) {
+                group.push(item);
+                continue;
+            }
+            match separator {
+                Separator::Drop => groups.push(core::mem::take(&mut group)),
+                Separator::Leading => {
+                    if !group.is_empty() {
+                        groups.push(core::mem::take(&mut group));
+                    }
+                    group.push(item);
+                }
+                Separator::Trailing => {
+                    group.push(item);
+                    groups.push(core::mem::take(&mut group));
+                }
+            }
+        }
+        if separator == Separator::Drop || !group.is_empty() {
+            groups.push(group);
+        }
+        groups
+    }
+}
+
+/// Splits items in two just before the first item which matches the predicate. The matching
+/// item begins the second half; if no item matches, the second half is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::split_at_first;
+/// let at_blank = split_at_first(|line: &&str| line.is_empty());
+/// assert_eq!(
+///     at_blank(vec!["Host: a", "Accept: b", "", "body"]),
+///     (vec!["Host: a", "Accept: b"], vec!["", "body"]),
+/// );
+/// assert_eq!(at_blank(vec!["x"]), (vec!["x"], vec![]));
+/// ```
+pub fn split_at_first<I, F>(pred: F) -> impl Fn(I) -> (Vec<I::Item>, Vec<I::Item>)
+where
+    I: IntoIterator,
+    F: Fn(&I::Item) -> bool,
+{
+    move |iter| {
+        let mut iter = iter.into_iter();
+        let mut before = Vec::new();
+        for item in iter.by_ref() {
+            if pred(&item) {
+                let mut after = alloc::vec![item];
+                after.extend(iter);
+                return (before, after);
+            }
+            before.push(item);
+        }
+        (before, Vec::new())
+    }
+}
+
+/// Splits items in two: the longest prefix of items which satisfy the predicate, and the rest.
+/// This is [split_at_first](crate::list::split_at_first) with the predicate negated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::span;
+/// let digits = span(|c: &char| c.is_ascii_digit());
+/// let (number, unit) = digits("250ms".chars());
+/// assert_eq!(number, vec!['2', '5', '0']);
+/// assert_eq!(unit, vec!['m', 's']);
+/// ```
+pub fn span<I, F>(pred: F) -> impl Fn(I) -> (Vec<I::Item>, Vec<I::Item>)
+where
+    I: IntoIterator,
+    F: Fn(&I::Item) -> bool,
+{
+    split_at_first(move |item: &I::Item| !pred(item))
+}
+
+/// What [batch_by_weight](crate::list::batch_by_weight) does with an item which weighs more
+/// than a whole batch may.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Oversize {
+    /// The item is put in a batch of its own, which is over the limit.
+    Alone,
+    /// The item is discarded.
+    Drop,
+}
+
+/// Packs items, in order, into batches whose total weight is at most `max_weight`. A new batch
+/// is started whenever the next item would not fit in the current one, so no batch is empty.
+/// Items which weigh more than `max_weight` on their own are handled according to `oversize`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::{batch_by_weight, Oversize};
+/// let lines = vec!["alpha", "be", "gamma", "a very long line", "de"];
+/// assert_eq!(
+///     batch_by_weight(8, |s: &&str| s.len(), Oversize::Alone, lines.clone()),
+///     vec![vec!["alpha", "be"], vec!["gamma"], vec!["a very long line"], vec!["de"]],
+/// );
+/// assert_eq!(
+///     batch_by_weight(8, |s: &&str| s.len(), Oversize::Drop, lines),
+///     vec![vec!["alpha", "be"], vec!["gamma", "de"]],
+/// );
+/// ```
+pub fn batch_by_weight<I, F>(
+    max_weight: usize,
+    weigh: F,
+    oversize: Oversize,
+    iter: I,
+) -> Vec<Vec<I::Item>>
+where
+    I: IntoIterator,
+    F: Fn(&I::Item) -> usize,
+{
+    let mut batches = Vec::new();
+    let mut batch = Vec::new();
+    let mut weight = 0usize;
+    for item in iter {
+        let item_weight = weigh(&item);
+        if item_weight > max_weight {
+            if oversize == Oversize::Alone {
+                if !batch.is_empty() {
+                    batches.push(core::mem::take(&mut batch));
+                    weight = 0;
+                }
+                batches.push(alloc::vec![item]);
+            }
+            continue;
+        }
+        if weight.saturating_add(item_weight) > max_weight && !batch.is_empty() {
+            batches.push(core::mem::take(&mut batch));
+            weight = 0;
+        }
+        weight += item_weight;
+        batch.push(item);
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+/* SORTING ********************************************************************/
+use crate::ord::Comparator;
+
+/// Sorts items using a [Comparator](crate::ord::Comparator), or anything which can be
+/// converted into one. The sort is stable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::sort_with;
+/// # use fp_std::ord::Comparator;
+/// let by_length = sort_with(Comparator::comparing(|s: &&str| s.len()));
+/// assert_eq!(by_length(vec!["ccc", "a", "bb"]), vec!["a", "bb", "ccc"]);
+///
+/// let descending = sort_with(|a: &u32, b: &u32| b.cmp(a));
+/// assert_eq!(descending(vec![1, 3, 2]), vec![3, 2, 1]);
+/// ```
+pub fn sort_with<I, C>(comparator: C) -> impl Fn(I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    I::Item: 'static,
+    C: Into<Comparator<I::Item>>,
+{
+    let comparator = comparator.into();
+    move |iter| {
+        let mut items: Vec<I::Item> = iter.into_iter().collect();
+        comparator.sort(&mut items);
+        items
+    }
+}
+
+/* ROTATION *******************************************************************/
+
+/// Rotates items by `n` places, so that the first `n` items move to the end. A negative `n`
+/// rotates the other way, so that the last `-n` items move to the front. Rotating by more than
+/// the number of items wraps around.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::rotate;
+/// assert_eq!(rotate(1)(vec![1, 2, 3, 4]), vec![2, 3, 4, 1]);
+/// assert_eq!(rotate(-1)(vec![1, 2, 3, 4]), vec![4, 1, 2, 3]);
+/// assert_eq!(rotate(6)(vec![1, 2, 3, 4]), vec![3, 4, 1, 2]);
+/// assert_eq!(rotate(3)(Vec::<u32>::new()), vec![]);
+/// ```
+pub fn rotate<I>(n: isize) -> impl Fn(I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+{
+    move |iter| {
+        let mut items: Vec<I::Item> = iter.into_iter().collect();
+        if !items.is_empty() {
+            let mid = n.rem_euclid(items.len() as isize) as usize;
+            items.rotate_left(mid);
+        }
+        items
+    }
+}
+
+/// Takes `n` items, starting over from the first item each time the items run out. If there
+/// are no items, the result is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::cycle_take;
+/// let shifts = cycle_take(5);
+/// assert_eq!(shifts(vec!["ann", "bo"]), vec!["ann", "bo", "ann", "bo", "ann"]);
+/// assert_eq!(shifts(Vec::<&str>::new()), Vec::<&str>::new());
+/// ```
+pub fn cycle_take<I>(n: usize) -> impl Fn(I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Clone,
+{
+    move |iter| {
+        let items: Vec<I::Item> = iter.into_iter().collect();
+        items.iter().cloned().cycle().take(n).collect()
+    }
+}
+
+/* SELECTION ******************************************************************/
+use alloc::collections::BinaryHeap;
+use core::cmp::Reverse;
+
+/// Finds the `k` largest items, from largest to smallest, without sorting all of them. If
+/// there are fewer than `k` items, all of them are returned.
+///
+/// This takes `O(n log k)` time for `n` items, and `O(k)` space.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::max_n;
+/// let top_three = max_n(3);
+/// assert_eq!(top_three(vec![4, 1, 5, 9, 2, 6]), vec![9, 6, 5]);
+/// assert_eq!(top_three(vec![1, 2]), vec![2, 1]);
+/// assert_eq!(max_n(usize::MAX)(vec![1, 2]), vec![2, 1]);
+/// ```
+pub fn max_n<I>(k: usize) -> impl Fn(I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    move |iter| {
+        let iter = iter.into_iter();
+        let mut heap = BinaryHeap::with_capacity(k.min(iter.size_hint().0).saturating_add(1));
+        for item in iter {
+            heap.push(Reverse(item));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
+    }
+}
+
+/// Finds the `k` smallest items, from smallest to largest, without sorting all of them. If
+/// there are fewer than `k` items, all of them are returned.
+///
+/// This takes `O(n log k)` time for `n` items, and `O(k)` space.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::min_n;
+/// let bottom_two = min_n(2);
+/// assert_eq!(bottom_two(vec![4, 1, 5, 9, 2, 6]), vec![1, 2]);
+/// ```
+pub fn min_n<I>(k: usize) -> impl Fn(I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    move |iter| {
+        let iter = iter.into_iter();
+        let mut heap = BinaryHeap::with_capacity(k.min(iter.size_hint().0).saturating_add(1));
+        for item in iter {
+            heap.push(item);
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec()
+    }
+}
+
+/// Removes every item which is equivalent to the item before it, so that only the first of
+/// each run of equivalent items is kept.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::dedup_consecutive_by;
+/// let words = vec!["Hi", "hi", "HI", "there", "hi"];
+/// let deduped = dedup_consecutive_by(|a: &&str, b: &&str| a.eq_ignore_ascii_case(b), words);
+/// assert_eq!(deduped, vec!["Hi", "there", "hi"]);
+/// ```
+pub fn dedup_consecutive_by<I, F>(eq_fn: F, iter: I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    F: Fn(&I::Item, &I::Item) -> bool,
+{
+    let mut items: Vec<I::Item> = Vec::new();
+    for item in iter {
+        if !items.last().is_some_and(|last| eq_fn(last, &item)) {
+            items.push(item);
+        }
+    }
+    items
+}
+
+/// Keeps the items whose paired flag is `true`, stopping when either the flags or the items
+/// run out.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::compress;
+/// let enabled = vec![true, false, true];
+/// assert_eq!(compress(enabled, vec!["gzip", "brotli", "zstd", "lz4"]), vec!["gzip", "zstd"]);
+/// ```
+pub fn compress<F, I>(flags: F, iter: I) -> Vec<I::Item>
+where
+    F: IntoIterator<Item = bool>,
+    I: IntoIterator,
+{
+    flags
+        .into_iter()
+        .zip(iter)
+        .filter_map(|(flag, item)| flag.then_some(item))
+        .collect()
+}
+
+/* SET OPERATIONS *************************************************************/
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+/// Combines two lists into one containing each distinct item of either of them, in the order
+/// in which they are first seen, the first list's before the second's.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::union_vec;
+/// assert_eq!(union_vec(vec![3, 1, 3], vec![2, 1, 4]), vec![3, 1, 2, 4]);
+/// ```
+#[cfg(feature = "std")]
+pub fn union_vec<I, J, T>(a: I, b: J) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    J: IntoIterator<Item = T>,
+    T: Eq + Hash + Clone,
+{
+    union_vec_by(T::clone, a, b)
+}
+
+/// Combines two lists into one containing each distinct item of the first which is also in
+/// the second, in the order in which they are first seen.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::intersect_vec;
+/// assert_eq!(intersect_vec(vec![3, 1, 2, 3], vec![2, 3]), vec![3, 2]);
+/// ```
+#[cfg(feature = "std")]
+pub fn intersect_vec<I, J, T>(a: I, b: J) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    J: IntoIterator<Item = T>,
+    T: Eq + Hash + Clone,
+{
+    intersect_vec_by(T::clone, a, b)
+}
+
+/// Combines two lists into one containing each distinct item of the first which is not in the
+/// second, in the order in which they are first seen.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::difference_vec;
+/// assert_eq!(difference_vec(vec![3, 1, 2, 3], vec![2]), vec![3, 1]);
+/// ```
+#[cfg(feature = "std")]
+pub fn difference_vec<I, J, T>(a: I, b: J) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    J: IntoIterator<Item = T>,
+    T: Eq + Hash + Clone,
+{
+    difference_vec_by(T::clone, a, b)
+}
+
+/// Combines two lists into one containing the first item seen with each distinct key among
+/// either of them, in the order in which they are first seen, the first list's before the
+/// second's.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::union_vec_by;
+/// let saved = vec![("ann", 1), ("bo", 2)];
+/// let fresh = vec![("bo", 5), ("cy", 6)];
+/// let merged = union_vec_by(|user: &(&str, u32)| user.0, saved, fresh);
+/// assert_eq!(merged, vec![("ann", 1), ("bo", 2), ("cy", 6)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn union_vec_by<I, J, T, K, F>(key_fn: F, a: I, b: J) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    J: IntoIterator<Item = T>,
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let mut seen = HashSet::new();
+    a.into_iter()
+        .chain(b)
+        .filter(|item| seen.insert(key_fn(item)))
+        .collect()
+}
+
+/// Combines two lists into one containing the first item of the first list seen with each
+/// distinct key which is also the key of an item in the second, in the order in which they
+/// are first seen.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::intersect_vec_by;
+/// let words = intersect_vec_by(|s: &&str| s.to_lowercase(), vec!["Tea", "cake"], vec!["TEA"]);
+/// assert_eq!(words, vec!["Tea"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn intersect_vec_by<I, J, T, K, F>(key_fn: F, a: I, b: J) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    J: IntoIterator<Item = T>,
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let mut wanted: HashSet<K> = b.into_iter().map(|item| key_fn(&item)).collect();
+    a.into_iter()
+        .filter(|item| wanted.remove(&key_fn(item)))
+        .collect()
+}
+
+/// Combines two lists into one containing the first item of the first list seen with each
+/// distinct key which is not the key of any item in the second, in the order in which they
+/// are first seen.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::difference_vec_by;
+/// let pending = vec![(1, "draft"), (2, "review"), (3, "draft")];
+/// let done = vec![(2, "merged")];
+/// let left = difference_vec_by(|task: &(u32, &str)| task.0, pending, done);
+/// assert_eq!(left, vec![(1, "draft"), (3, "draft")]);
+/// ```
+#[cfg(feature = "std")]
+pub fn difference_vec_by<I, J, T, K, F>(key_fn: F, a: I, b: J) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    J: IntoIterator<Item = T>,
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let mut seen: HashSet<K> = b.into_iter().map(|item| key_fn(&item)).collect();
+    a.into_iter()
+        .filter(|item| seen.insert(key_fn(item)))
+        .collect()
+}
+
+/* RANDOMNESS *****************************************************************/
+#[cfg(feature = "rand")]
+use rand::seq::SliceRandom;
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+/// Collects items in a random order. With the same random number generator in the same state,
+/// the order is always the same.
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::shuffle;
+/// # use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let mut deck = shuffle(&mut rng, 1..=10);
+/// assert_ne!(deck, (1..=10).collect::<Vec<_>>());
+/// deck.sort();
+/// assert_eq!(deck, (1..=10).collect::<Vec<_>>());
+/// ```
+#[cfg(feature = "rand")]
+pub fn shuffle<R, I>(rng: &mut R, iter: I) -> Vec<I::Item>
+where
+    R: Rng + ?Sized,
+    I: IntoIterator,
+{
+    let mut items: Vec<I::Item> = iter.into_iter().collect();
+    items.shuffle(rng);
+    items
+}
+
+/// Chooses `k` items at random, each with equal probability, in a single pass over the items
+/// and keeping only `k` of them at a time. If there are fewer than `k` items, all of them are
+/// returned. The order of the chosen items is not meaningful.
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::sample;
+/// # use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let chosen = sample(&mut rng, 3, 0..1000);
+/// assert_eq!(chosen.len(), 3);
+/// assert!(chosen.iter().all(|x| *x < 1000));
+/// assert_eq!(sample(&mut rng, 3, vec!['a']), vec!['a']);
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample<R, I>(rng: &mut R, k: usize, iter: I) -> Vec<I::Item>
+where
+    R: Rng + ?Sized,
+    I: IntoIterator,
+{
+    let mut iter = iter.into_iter();
+    let mut reservoir: Vec<I::Item> = iter.by_ref().take(k).collect();
+    for (index, item) in iter.enumerate() {
+        let slot = rng.gen_range(0..=index + k);
+        if slot < k {
+            reservoir[slot] = item;
+        }
+    }
+    reservoir
+}
+
+/// Chooses one item of a slice at random, or `None` if the slice is empty.
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::choose;
+/// # use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let colours = ["red", "green", "blue"];
+/// assert!(colours.contains(choose(&mut rng, &colours).unwrap()));
+/// assert_eq!(choose(&mut rng, &[] as &[u32]), None);
+/// ```
+#[cfg(feature = "rand")]
+pub fn choose<'a, R, T>(rng: &mut R, slice: &'a [T]) -> Option<&'a T>
+where
+    R: Rng + ?Sized,
+{
+    slice.choose(rng)
+}
+
+/* DIFFING ********************************************************************/
+use crate::error::Error;
+
+/// One step in turning one list into another. See [diff](crate::list::diff).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Edit<T> {
+    /// The item is in both lists.
+    Keep(T),
+    /// The item is only in the old list.
+    Delete(T),
+    /// The item is only in the new list.
+    Insert(T),
+}
+
+/// Finds a shortest sequence of edits which turns `old` into `new`, using Myers' algorithm.
+///
+/// This takes `O((n + m) d)` time, where `n` and `m` are the lengths of the lists and `d` is the
+/// number of insertions and deletions, so it is fast for lists which are mostly the same.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::{diff, Edit};
+/// let old: Vec<char> = "abcd".chars().collect();
+/// let new: Vec<char> = "acde".chars().collect();
+/// assert_eq!(
+///     diff(&old, &new),
+///     vec![
+///         Edit::Keep('a'),
+///         Edit::Delete('b'),
+///         Edit::Keep('c'),
+///         Edit::Keep('d'),
+///         Edit::Insert('e'),
+///     ],
+/// );
+/// ```
+pub fn diff<T>(old: &[T], new: &[T]) -> Vec<Edit<T>>
+where
+    T: PartialEq + Clone,
+{
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let offset = n + m + 1;
+    let index = |k: isize| (k + offset) as usize;
+    let mut frontier = alloc::vec![0; 2 * offset as usize + 1];
+    let mut trace = Vec::new();
+    'search: for d in 0..=(n + m) {
+        trace.push(frontier.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && frontier[index(k - 1)] < frontier[index(k + 1)]) {
+                frontier[index(k + 1)]
+            } else {
+                frontier[index(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            frontier[index(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, frontier) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && frontier[index(k - 1)] < frontier[index(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = frontier[index(prev_k)];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Keep(old[x as usize].clone()));
+        }
+        if d > 0 {
+            if x == prev_x {
+                edits.push(Edit::Insert(new[prev_y as usize].clone()));
+            } else {
+                edits.push(Edit::Delete(old[prev_x as usize].clone()));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    edits.reverse();
+    edits
+}
+
+/// Applies a sequence of edits, such as those found by [diff](crate::list::diff), to a list.
+///
+/// Fails with [PatchMismatch](crate::error::Error::PatchMismatch) if the items to be kept or
+/// deleted are not the items of `old`, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::error::Error;
+/// # use fp_std::list::{diff, patch, Edit};
+/// let old = vec![1, 2, 3];
+/// let new = vec![1, 3, 4];
+/// let edits = diff(&old, &new);
+/// assert_eq!(patch(&edits, old), Ok(new));
+///
+/// assert_eq!(patch(&[Edit::Delete(2)], vec![1]), Err(Error::PatchMismatch));
+/// ```
+pub fn patch<T, I>(edits: &[Edit<T>], old: I) -> Result<Vec<T>, Error>
+where
+    T: PartialEq + Clone,
+    I: IntoIterator<Item = T>,
+{
+    let mut old = old.into_iter();
+    let mut new = Vec::new();
+    for edit in edits {
+        match edit {
+            Edit::Keep(expected) => match old.next() {
+                Some(item) if item == *expected => new.push(item),
+                _ => return Err(Error::PatchMismatch),
+            },
+            Edit::Delete(expected) => match old.next() {
+                Some(item) if item == *expected => {}
+                _ => return Err(Error::PatchMismatch),
+            },
+            Edit::Insert(item) => new.push(item.clone()),
+        }
+    }
+    match old.next() {
+        Some(..) => Err(Error::PatchMismatch),
+        None => Ok(new),
+    }
+}
+
+/// Finds the longest list of items with which every one of the given lists begins. With no
+/// lists, the result is empty.
+///
+/// Only as many items of each list are consumed as are needed to compare them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::longest_common_prefix;
+/// let paths = vec!["/srv/app/logs/a", "/srv/app/logs/b", "/srv/app/cache"];
+/// let shared = longest_common_prefix(paths.iter().map(|path| path.split('/')));
+/// assert_eq!(shared.join("/"), "/srv/app");
+///
+/// assert_eq!(longest_common_prefix(vec![vec![1, 2, 3], vec![1, 2]]), vec![1, 2]);
+/// assert_eq!(longest_common_prefix(Vec::<Vec<u8>>::new()), vec![]);
+/// ```
+pub fn longest_common_prefix<I, T>(lists: I) -> Vec<T>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator<Item = T>,
+    T: Eq,
+{
+    let mut lists = lists.into_iter();
+    let mut prefix: Vec<T> = match lists.next() {
+        Some(first) => first.into_iter().collect(),
+        None => return Vec::new(),
+    };
+    for list in lists {
+        let shared = prefix.iter().zip(list).take_while(|(a, b)| *a == b).count();
+        prefix.truncate(shared);
+        if prefix.is_empty() {
+            break;
+        }
+    }
+    prefix
+}
+
+/// Finds the longest list of items with which every one of the given lists ends. With no
+/// lists, the result is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::longest_common_suffix;
+/// let hosts = vec!["api.eu.example.com", "www.example.com", "example.com"];
+/// let shared = longest_common_suffix(hosts.iter().map(|host| host.split('.')));
+/// assert_eq!(shared.join("."), "example.com");
+///
+/// assert_eq!(longest_common_suffix(vec![vec![1, 2, 3], vec![3]]), vec![3]);
+/// ```
+pub fn longest_common_suffix<I, T>(lists: I) -> Vec<T>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator<Item = T>,
+    T: Eq,
+{
+    let mut lists = lists.into_iter();
+    let mut suffix: Vec<T> = match lists.next() {
+        Some(first) => first.into_iter().collect(),
+        None => return Vec::new(),
+    };
+    suffix.reverse();
+    for list in lists {
+        let list: Vec<T> = list.into_iter().collect();
+        let shared = suffix
+            .iter()
+            .zip(list.iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        suffix.truncate(shared);
+        if suffix.is_empty() {
+            break;
+        }
+    }
+    suffix.reverse();
+    suffix
+}
+
+/* ITERATOR ADAPTERS **********************************************************/
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{Fuse, Peekable};
+
+/// An iterator over chunks of another iterator's items. Constructed by
+/// [chunks_of](crate::list::chunks_of).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksOf<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for ChunksOf<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<_> = self.iter.by_ref().take(self.size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+/// Splits items into chunks of `size` items. The last chunk may be shorter, but is never empty.
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::chunks_of;
+/// let pairs = chunks_of(2);
+/// let chunks: Vec<_> = pairs(vec![1, 2, 3, 4, 5]).collect();
+/// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+pub fn chunks_of<I>(size: usize) -> impl Fn(I) -> ChunksOf<I::IntoIter>
+where
+    I: IntoIterator,
+{
+    assert!(size > 0, "chunk size must be greater than zero");
+    move |iter| ChunksOf {
+        iter: iter.into_iter(),
+        size,
+    }
+}
+
+/// An iterator which places a separator between each of another iterator's items. Constructed
+/// by [intersperse](crate::list::intersperse).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    separator: I::Item,
+    needs_separator: bool,
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.needs_separator && self.iter.peek().is_some() {
+            self.needs_separator = false;
+            return Some(self.separator.clone());
+        }
+        let item = self.iter.next()?;
+        self.needs_separator = true;
+        Some(item)
+    }
+}
+
+/// Places a separator between each pair of adjacent items.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::intersperse;
+/// let commas = intersperse(",");
+/// let joined: String = commas(vec!["a", "b", "c"]).collect();
+/// assert_eq!(joined, "a,b,c");
+/// ```
+pub fn intersperse<I>(separator: I::Item) -> impl Fn(I) -> Intersperse<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Clone,
+{
+    move |iter| Intersperse {
+        iter: iter.into_iter().peekable(),
+        separator: separator.clone(),
+        needs_separator: false,
+    }
+}
+
+/// An iterator which yields items while they satisfy a predicate, and then the first item which
+/// does not. Constructed by [take_while_inclusive](crate::list::take_while_inclusive).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeWhileInclusive<I, F> {
+    iter: I,
+    pred: F,
+    done: bool,
+}
+
+impl<I, F> Iterator for TakeWhileInclusive<I, F>
+where
+    I: Iterator,
+    F: Fn(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next()?;
+        self.done = !(self.pred)(&item);
+        Some(item)
+    }
+}
+
+/// Takes items while they satisfy a predicate, and then also the first item which does not.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::take_while_inclusive;
+/// let until_done = take_while_inclusive(|line: &&str| *line != "done");
+/// let lines: Vec<_> = until_done(vec!["a", "b", "done", "c"]).collect();
+/// assert_eq!(lines, vec!["a", "b", "done"]);
+/// ```
+pub fn take_while_inclusive<I, F>(pred: F) -> impl Fn(I) -> TakeWhileInclusive<I::IntoIter, F>
+where
+    I: IntoIterator,
+    F: Fn(&I::Item) -> bool + Clone,
+{
+    move |iter| TakeWhileInclusive {
+        iter: iter.into_iter(),
+        pred: pred.clone(),
+        done: false,
+    }
+}
+
+/// An iterator which merges the items of two sorted iterators into one sorted sequence.
+/// Constructed by [merge_sorted](crate::list::merge_sorted).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MergeSorted<I: Iterator, J: Iterator> {
+    left: Peekable<I>,
+    right: Peekable<J>,
+}
+
+impl<I, J> Clone for MergeSorted<I, J>
+where
+    I: Iterator + Clone,
+    J: Iterator + Clone,
+    I::Item: Clone,
+    J::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        MergeSorted {
+            left: self.left.clone(),
+            right: self.right.clone(),
+        }
+    }
+}
+
+impl<I, J> Debug for MergeSorted<I, J>
+where
+    I: Iterator + Debug,
+    J: Iterator + Debug,
+    I::Item: Debug,
+    J::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MergeSorted")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish()
+    }
+}
+
+impl<I, J> Iterator for MergeSorted<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match (self.left.peek(), self.right.peek()) {
+            (Some(left), Some(right)) if right < left => self.right.next(),
+            (Some(..), _) => self.left.next(),
+            (None, _) => self.right.next(),
+        }
+    }
+}
+
+/// Lazily merges two sorted lists into one sorted sequence. The merge is stable: of two equal
+/// items, the one from `left` comes first.
+///
+/// If either list is not sorted, the result is not sorted either, but every item still
+/// appears in it exactly once.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::merge_sorted;
+/// let merged: Vec<_> = merge_sorted(vec![1, 4, 6], vec![2, 3, 7]).collect();
+/// assert_eq!(merged, vec![1, 2, 3, 4, 6, 7]);
+/// ```
+pub fn merge_sorted<I, J>(left: I, right: J) -> MergeSorted<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+    I::Item: Ord,
+{
+    MergeSorted {
+        left: left.into_iter().peekable(),
+        right: right.into_iter().peekable(),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct KMergeHead<I: Iterator> {
+    item: I::Item,
+    index: usize,
+    iter: I,
+}
+
+impl<I: Iterator> PartialEq for KMergeHead<I>
+where
+    I::Item: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<I: Iterator> Eq for KMergeHead<I> where I::Item: Ord {}
+
+impl<I: Iterator> PartialOrd for KMergeHead<I>
+where
+    I::Item: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: Iterator> Ord for KMergeHead<I>
+where
+    I::Item: Ord,
+{
+    // Reversed, so that the greatest head in the heap is the least item, from the earliest
+    // of the lists if there is a tie.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .item
+            .cmp(&self.item)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+/// An iterator which merges the items of any number of sorted iterators into one sorted
+/// sequence. Constructed by [kmerge](crate::list::kmerge).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct KMerge<I: Iterator>
+where
+    I::Item: Ord,
+{
+    heap: BinaryHeap<KMergeHead<I>>,
+}
+
+impl<I> Clone for KMerge<I>
+where
+    I: Iterator + Clone,
+    I::Item: Ord + Clone,
+{
+    fn clone(&self) -> Self {
+        KMerge {
+            heap: self.heap.clone(),
+        }
+    }
+}
+
+impl<I> Debug for KMerge<I>
+where
+    I: Iterator + Debug,
+    I::Item: Ord + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("KMerge").field("heap", &self.heap).finish()
+    }
+}
+
+impl<I: Iterator> Iterator for KMerge<I>
+where
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let mut head = self.heap.pop()?;
+        match head.iter.next() {
+            Some(next) => {
+                let item = core::mem::replace(&mut head.item, next);
+                self.heap.push(head);
+                Some(item)
+            }
+            None => Some(head.item),
+        }
+    }
+}
+
+/// Lazily merges any number of sorted lists into one sorted sequence, keeping the next item of
+/// each list in a binary heap. The merge is stable: of two equal items, the one from the
+/// earlier list comes first.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::kmerge;
+/// let shards = vec![vec![(1, 'a'), (5, 'a')], vec![(1, 'b'), (2, 'b')], vec![(3, 'c')]];
+/// let merged: Vec<_> = kmerge(shards).collect();
+/// assert_eq!(merged, vec![(1, 'a'), (1, 'b'), (2, 'b'), (3, 'c'), (5, 'a')]);
+/// ```
+pub fn kmerge<I>(lists: I) -> KMerge<<I::Item as IntoIterator>::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Ord,
+{
+    let heap = lists
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, list)| {
+            let mut iter = list.into_iter();
+            let item = iter.next()?;
+            Some(KMergeHead { item, index, iter })
+        })
+        .collect();
+    KMerge { heap }
+}
+
+/// When [interleave](crate::list::interleave) and [round_robin](crate::list::round_robin) stop
+/// taking items from their iterators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stop {
+    /// Stop as soon as any iterator runs out, at the point when its turn comes.
+    Shortest,
+    /// Skip over the iterators which run out, continuing with the rest until all have run out.
+    Longest,
+}
+
+/// An iterator which alternates between the items of two iterators. Constructed by
+/// [interleave](crate::list::interleave).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Interleave<I, J> {
+    left: Fuse<I>,
+    right: Fuse<J>,
+    left_next: bool,
+    stop: Stop,
+    done: bool,
+}
+
+impl<I, J> Iterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let left_next = self.left_next;
+        self.left_next = !left_next;
+        let item = if left_next {
+            self.left.next()
+        } else {
+            self.right.next()
+        };
+        match (item, self.stop) {
+            (None, Stop::Longest) if left_next => self.right.next(),
+            (None, Stop::Longest) => self.left.next(),
+            (None, Stop::Shortest) => {
+                self.done = true;
+                None
+            }
+            (item, _) => item,
+        }
+    }
+}
+
+/// Lazily alternates between the items of two lists, starting with `left`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::{interleave, Stop};
+/// let all: Vec<_> = interleave(vec![1, 3, 5, 7], vec![2, 4], Stop::Longest).collect();
+/// assert_eq!(all, vec![1, 2, 3, 4, 5, 7]);
+///
+/// let even: Vec<_> = interleave(vec![1, 3, 5, 7], vec![2, 4], Stop::Shortest).collect();
+/// assert_eq!(even, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn interleave<I, J>(left: I, right: J, stop: Stop) -> Interleave<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+{
+    Interleave {
+        left: left.into_iter().fuse(),
+        right: right.into_iter().fuse(),
+        left_next: true,
+        stop,
+        done: false,
+    }
+}
+
+/// An iterator which takes one item from each of any number of iterators in turn. Constructed
+/// by [round_robin](crate::list::round_robin).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundRobin<I> {
+    iters: Vec<I>,
+    next: usize,
+    stop: Stop,
+}
+
+impl<I: Iterator> Iterator for RoundRobin<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        while !self.iters.is_empty() {
+            if self.next >= self.iters.len() {
+                self.next = 0;
+            }
+            match self.iters[self.next].next() {
+                Some(item) => {
+                    self.next += 1;
+                    return Some(item);
+                }
+                None if self.stop == Stop::Longest => {
+                    self.iters.remove(self.next);
+                }
+                None => self.iters.clear(),
+            }
+        }
+        None
+    }
+}
+
+/// Lazily takes one item from each of any number of lists in turn, in the order in which the
+/// lists are given. This is useful for scheduling work fairly between several sources.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::{round_robin, Stop};
+/// let queues = vec![vec!["a1", "a2", "a3"], vec!["b1"], vec!["c1", "c2"]];
+/// let fair: Vec<_> = round_robin(queues.clone(), Stop::Longest).collect();
+/// assert_eq!(fair, vec!["a1", "b1", "c1", "a2", "c2", "a3"]);
+///
+/// let rounds: Vec<_> = round_robin(queues, Stop::Shortest).collect();
+/// assert_eq!(rounds, vec!["a1", "b1", "c1", "a2"]);
+/// ```
+pub fn round_robin<I>(lists: I, stop: Stop) -> RoundRobin<<I::Item as IntoIterator>::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+{
+    RoundRobin {
+        iters: lists.into_iter().map(IntoIterator::into_iter).collect(),
+        next: 0,
+        stop,
+    }
+}
+
+/* INDEXED FUNCTIONS **********************************************************/
+
+/// Pairs every item with its index.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::with_index;
+/// assert_eq!(with_index(vec!['a', 'b']), vec![(0, 'a'), (1, 'b')]);
+/// ```
+pub fn with_index<I: IntoIterator>(iter: I) -> Vec<(usize, I::Item)> {
+    iter.into_iter().enumerate().collect()
+}
+
+/// Transforms every item with a function of its index and the item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::map_indexed;
+/// let numbered = map_indexed(|i, s: &str| format!("{}. {}", i + 1, s));
+/// assert_eq!(numbered(vec!["eggs", "milk"]), vec!["1. eggs", "2. milk"]);
+/// ```
+pub fn map_indexed<I, U, F>(f: F) -> impl Fn(I) -> Vec<U>
+where
+    I: IntoIterator,
+    F: Fn(usize, I::Item) -> U,
+{
+    move |iter| iter.into_iter().enumerate().map(|(i, x)| f(i, x)).collect()
+}
+
+/// Keeps only the items which satisfy a predicate of their index and the item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::filter_indexed;
+/// let every_other = filter_indexed(|i, _: &char| i % 2 == 0);
+/// assert_eq!(every_other("abcde".chars()), vec!['a', 'c', 'e']);
+/// ```
+pub fn filter_indexed<I, F>(pred: F) -> impl Fn(I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    F: Fn(usize, &I::Item) -> bool,
+{
+    move |iter| {
+        iter.into_iter()
+            .enumerate()
+            .filter(|(i, x)| pred(*i, x))
+            .map(|(_, x)| x)
+            .collect()
+    }
+}
+
+/// Folds items into an accumulator, starting from `init`, with a function of the accumulator,
+/// the index of the item, and the item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::fold_indexed;
+/// let weighted_sum = fold_indexed(0, |acc, i, x: usize| acc + i * x);
+/// assert_eq!(weighted_sum(vec![5, 3, 2]), 7);
+/// ```
+pub fn fold_indexed<I, B, F>(init: B, f: F) -> impl Fn(I) -> B
+where
+    I: IntoIterator,
+    B: Clone,
+    F: Fn(B, usize, I::Item) -> B,
+{
+    move |iter| {
+        iter.into_iter()
+            .enumerate()
+            .fold(init.clone(), |acc, (i, x)| f(acc, i, x))
+    }
+}
+
+/* FOLDS **********************************************************************/
+use crate::reducer;
+use core::ops::ControlFlow;
+
+/// Folds items into an accumulator, starting from `init`, until the folding function breaks.
+/// The function continues the fold by returning `ControlFlow::Continue` with the new
+/// accumulator, or stops it early by returning `ControlFlow::Break` with the final one.
+///
+/// This is [reduce](crate::reducer::reduce) with the
+/// [fold_while](crate::reducer::fold_while) reducer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::fold_while;
+/// # use std::ops::ControlFlow;
+/// let sum_below_10 = fold_while(0, |acc, x| {
+///     if acc + x > 10 {
+///         ControlFlow::Break(acc)
+///     } else {
+///         ControlFlow::Continue(acc + x)
+///     }
+/// });
+/// assert_eq!(sum_below_10(vec![1, 2, 3, 4, 5, 6]), 10);
+/// assert_eq!(sum_below_10(vec![1, 2]), 3);
+/// ```
+pub fn fold_while<I, B, F>(init: B, f: F) -> impl Fn(I) -> B
+where
+    I: IntoIterator,
+    B: Clone,
+    F: Fn(B, I::Item) -> ControlFlow<B, B>,
+{
+    reducer::reduce(reducer::fold_while(init, f))
+}
+
+fn fold_while_from<I, B, F>(init: B, f: F, iter: I) -> B
+where
+    I: IntoIterator,
+    F: Fn(B, I::Item) -> ControlFlow<B, B>,
+{
+    let mut acc = init;
+    for item in iter {
+        match f(acc, item) {
+            ControlFlow::Continue(next) => acc = next,
+            ControlFlow::Break(done) => return done,
+        }
+    }
+    acc
+}
+
+/// Folds items into an accumulator, starting from `init`, stopping at the first error. The
+/// result is the final accumulator if every step succeeded, or the first error otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::fold_result;
+/// let sum_parsed = fold_result(0, |acc: u32, s: &str| s.parse::<u32>().map(|x| acc + x));
+/// assert_eq!(sum_parsed(vec!["1", "2", "3"]), Ok(6));
+/// assert!(sum_parsed(vec!["1", "x", "3"]).is_err());
+/// ```
+pub fn fold_result<I, B, E, F>(init: B, f: F) -> impl Fn(I) -> Result<B, E>
+where
+    I: IntoIterator,
+    B: Clone,
+    F: Fn(B, I::Item) -> Result<B, E>,
+{
+    move |iter| iter.into_iter().try_fold(init.clone(), &f)
+}
+
+/// Folds items into an accumulator, starting from `init`, stopping at the first `None`. The
+/// result is the final accumulator if every step produced one, or `None` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::fold_option;
+/// let checked_product = fold_option(1, |acc: u8, x: u8| acc.checked_mul(x));
+/// assert_eq!(checked_product(vec![2, 3, 4]), Some(24));
+/// assert_eq!(checked_product(vec![16, 16]), None);
+/// ```
+pub fn fold_option<I, B, F>(init: B, f: F) -> impl Fn(I) -> Option<B>
+where
+    I: IntoIterator,
+    B: Clone,
+    F: Fn(B, I::Item) -> Option<B>,
+{
+    move |iter| iter.into_iter().try_fold(init.clone(), &f)
+}
+
+/// Folds items into an accumulator, starting from `init`, collecting every intermediate
+/// accumulator. The result starts with `init`, and ends with the final accumulator.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::scan_left;
+/// let running_total = scan_left(0, |acc, x: u32| acc + x);
+/// assert_eq!(running_total(vec![1, 2, 3]), vec![0, 1, 3, 6]);
+/// ```
+pub fn scan_left<I, B, F>(init: B, f: F) -> impl Fn(I) -> Vec<B>
+where
+    I: IntoIterator,
+    B: Clone,
+    F: Fn(B, I::Item) -> B,
+{
+    move |iter| {
+        let mut acc = init.clone();
+        let mut scanned = alloc::vec![acc.clone()];
+        for item in iter {
+            acc = f(acc, item);
+            scanned.push(acc.clone());
+        }
+        scanned
+    }
+}
+
+/* APPLICATIVES ***************************************************************/
+
+/// Applies each function to the value in the same position, stopping when either list runs
+/// out. This is the "zipping" applicative for lists.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::zip_apply;
+/// let checks: Vec<fn(&str) -> bool> = vec![|s| !s.is_empty(), |s| s.len() < 5];
+/// assert_eq!(zip_apply(checks, vec!["", "abcdef"]), vec![false, false]);
+/// ```
+pub fn zip_apply<FI, I, A, B, F>(fns: FI, values: I) -> Vec<B>
+where
+    FI: IntoIterator<Item = F>,
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> B,
+{
+    fns.into_iter()
+        .zip(values)
+        .map(|(f, value)| f(value))
+        .collect()
+}
+
+/// Applies every function to every value, producing the results of the first function
+/// followed by the results of the second, and so on. This is the "cross product" applicative
+/// for lists.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::ap;
+/// let ops: Vec<fn(u32) -> u32> = vec![|x| x + 1, |x| x * 10];
+/// assert_eq!(ap(ops, vec![1, 2, 3]), vec![2, 3, 4, 10, 20, 30]);
+/// ```
+pub fn ap<FI, I, A, B, F>(fns: FI, values: I) -> Vec<B>
+where
+    FI: IntoIterator<Item = F>,
+    I: IntoIterator<Item = A>,
+    A: Clone,
+    F: Fn(A) -> B,
+{
+    let values: Vec<A> = values.into_iter().collect();
+    fns.into_iter()
+        .flat_map(|f| values.iter().cloned().map(f).collect::<Vec<_>>())
+        .collect()
+}
+
+/// Combines the items of two lists pairwise with a function, stopping when either list runs
+/// out.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::zip_with;
+/// let dot = zip_with(|a: i32, b: i32| a * b);
+/// assert_eq!(dot(vec![1, 2, 3], vec![4, 5]), vec![4, 10]);
+/// ```
+pub fn zip_with<I, J, C, F>(f: F) -> impl Fn(I, J) -> Vec<C>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    F: Fn(I::Item, J::Item) -> C,
+{
+    move |left, right| left.into_iter().zip(right).map(|(a, b)| f(a, b)).collect()
+}
+
+/* CONVERSIONS ****************************************************************/
+use alloc::collections::BTreeMap;
+
+/// Collects items into a `Vec`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::to_vec;
+/// let doubled = to_vec((1..4).map(|x| x * 2));
+/// assert_eq!(doubled, vec![2, 4, 6]);
+/// ```
+pub fn to_vec<I: IntoIterator>(iter: I) -> Vec<I::Item> {
+    iter.into_iter().collect()
+}
+
+/// Collects key-value pairs into a `HashMap`. When a key occurs more than once, the last value
+/// is kept.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::to_hashmap;
+/// let map = to_hashmap(vec![("a", 1), ("b", 2), ("a", 3)]);
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map["a"], 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn to_hashmap<I, K, V>(iter: I) -> HashMap<K, V>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Eq + Hash,
+{
+    iter.into_iter().collect()
+}
+
+/// Collects items into a `HashSet`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::to_hashset;
+/// let set = to_hashset("hello".chars());
+/// assert_eq!(set.len(), 4);
+/// assert!(set.contains(&'l'));
+/// ```
+#[cfg(feature = "std")]
+pub fn to_hashset<I>(iter: I) -> HashSet<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    iter.into_iter().collect()
+}
+
+/// Collects key-value pairs into a `BTreeMap`. When a key occurs more than once, the last value
+/// is kept.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::to_btreemap;
+/// let map = to_btreemap(vec![("b", 2), ("a", 1)]);
+/// assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![("a", 1), ("b", 2)]);
+/// ```
+pub fn to_btreemap<I, K, V>(iter: I) -> BTreeMap<K, V>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Ord,
+{
+    iter.into_iter().collect()
+}
+
+/* ITERATOR EXTENSION *********************************************************/
+
+/// Exposes the functions of this module as methods on iterators, for those who prefer method
+/// syntax. Each method behaves exactly as the free function of the same name.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::IterExt;
+/// let chunks: Vec<String> = vec!["a", "b", "c", "d", "e"]
+///     .into_iter()
+///     .chunks_of(2)
+///     .map(|chunk| chunk.into_iter().interspersed("-").collect())
+///     .collect();
+/// assert_eq!(chunks, vec!["a-b", "c-d", "e"]);
+/// ```
+pub trait IterExt: Iterator + Sized {
+    /// Groups items by a computed key. See [group_by](crate::list::group_by).
+    #[cfg(feature = "std")]
+    fn group_by<K, F>(self, key_fn: F) -> HashMap<K, Vec<Self::Item>>
+    where
+        K: Eq + Hash,
+        F: Fn(&Self::Item) -> K,
+    {
+        group_by(key_fn)(self)
+    }
+
+    /// Indexes items by a computed key. See [index_by](crate::list::index_by).
+    #[cfg(feature = "std")]
+    fn index_by<K, F>(self, key_fn: F) -> HashMap<K, Self::Item>
+    where
+        K: Eq + Hash,
+        F: Fn(&Self::Item) -> K,
+    {
+        index_by(key_fn)(self)
+    }
+
+    /// Groups adjacent items which have equal keys. See [group_runs](crate::list::group_runs).
+    fn group_runs<K, F>(self, key_fn: F) -> Vec<(K, Vec<Self::Item>)>
+    where
+        K: PartialEq,
+        F: Fn(&Self::Item) -> K,
+    {
+        group_runs(key_fn)(self)
+    }
+
+    /// Counts the number of times each distinct item occurs. See
+    /// [frequencies](crate::list::frequencies).
+    #[cfg(feature = "std")]
+    fn frequencies(self) -> HashMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash,
+    {
+        frequencies(self)
+    }
+
+    /// Splits items into groups wherever an item matches the predicate. See
+    /// [split_when](crate::list::split_when).
+    fn split_when<F>(self, pred: F, separator: Separator) -> Vec<Vec<Self::Item>>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        split_when(pred, separator)(self)
+    }
+
+    /// Splits items in two just before the first item which matches the predicate. See
+    /// [split_at_first](crate::list::split_at_first).
+    fn split_at_first<F>(self, pred: F) -> (Vec<Self::Item>, Vec<Self::Item>)
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        split_at_first(pred)(self)
+    }
+
+    /// Splits items in two after the longest prefix which satisfies the predicate. See
+    /// [span](crate::list::span).
+    fn span<F>(self, pred: F) -> (Vec<Self::Item>, Vec<Self::Item>)
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        split_at_first(move |item: &Self::Item| !pred(item))(self)
+    }
+
+    /// Splits items into chunks of `size` items. See [chunks_of](crate::list::chunks_of).
+    fn chunks_of(self, size: usize) -> ChunksOf<Self> {
+        chunks_of(size)(self)
+    }
+
+    /// Places a separator between each pair of adjacent items. See
+    /// [intersperse](crate::list::intersperse).
+    ///
+    /// This method is not named `intersperse` to avoid colliding with the unstable method of
+    /// the same name on `Iterator`.
+    fn interspersed(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+    {
+        intersperse(separator)(self)
+    }
+
+    /// Takes items while they satisfy a predicate, and then the first which does not. See
+    /// [take_while_inclusive](crate::list::take_while_inclusive).
+    fn take_while_inclusive<F>(self, pred: F) -> TakeWhileInclusive<Self, F>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        TakeWhileInclusive {
+            iter: self,
+            pred,
+            done: false,
+        }
+    }
+
+    /// Combines items pairwise with those of another list. See [zip_with](crate::list::zip_with).
+    fn zip_with<J, C, F>(self, other: J, f: F) -> Vec<C>
+    where
+        J: IntoIterator,
+        F: Fn(Self::Item, J::Item) -> C,
+    {
+        zip_with(f)(self, other)
+    }
+
+    /// Splits items in two according to a function. See
+    /// [partition_map](crate::list::partition_map).
+    fn partition_map<A, B, F>(self, f: F) -> (Vec<A>, Vec<B>)
+    where
+        F: Fn(Self::Item) -> Either<A, B>,
+    {
+        partition_map(f)(self)
+    }
+
+    /// Splits each item into a pair, collecting the elements into two lists. See
+    /// [unzip_with](crate::list::unzip_with).
+    fn unzip_with<A, B, F>(self, f: F) -> (Vec<A>, Vec<B>)
+    where
+        F: Fn(Self::Item) -> (A, B),
+    {
+        unzip_with(f, self)
+    }
+
+    /// Folds items into an accumulator until the folding function breaks. See
+    /// [fold_while](crate::list::fold_while).
+    fn fold_while<B, F>(self, init: B, f: F) -> B
+    where
+        F: Fn(B, Self::Item) -> ControlFlow<B, B>,
+    {
+        fold_while_from(init, f, self)
+    }
+
+    /// Folds items into an accumulator, collecting every intermediate accumulator. See
+    /// [scan_left](crate::list::scan_left).
+    fn scan_left<B, F>(self, init: B, f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: Fn(B, Self::Item) -> B,
+    {
+        scan_left(init, f)(self)
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+/* PARALLEL FUNCTIONS *********************************************************/
+#[cfg(feature = "std")]
+use fp_core::monoid::Monoid;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Transforms every item in parallel, collecting the results in their original order.
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::par_map;
+/// fn double(x: usize) -> usize { x * 2 }
+/// let double_all = par_map(double);
+/// assert_eq!(double_all(vec![1, 2, 3]), vec![2, 4, 6]);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_map<I, A, B, F>(f: F) -> impl Fn(I) -> Vec<B>
+where
+    I: IntoParallelIterator<Item = A>,
+    F: Fn(A) -> B + Sync + Send,
+    B: Send,
+{
+    move |iter| iter.into_par_iter().map(&f).collect()
+}
+
+/// Keeps only the items which satisfy a predicate, testing them in parallel, and collecting
+/// them in their original order.
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::par_filter;
+/// let evens = par_filter(|x: &usize| x % 2 == 0);
+/// assert_eq!(evens(vec![1, 2, 3, 4]), vec![2, 4]);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_filter<I, A, F>(pred: F) -> impl Fn(I) -> Vec<A>
+where
+    I: IntoParallelIterator<Item = A>,
+    F: Fn(&A) -> bool + Sync + Send,
+    A: Send,
+{
+    move |iter| iter.into_par_iter().filter(&pred).collect()
+}
+
+/// Groups items by a key computed in parallel. Within each group, the items remain in their
+/// original order.
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::par_group_by;
+/// let by_parity = par_group_by(|x: &usize| x % 2);
+/// let groups = by_parity(vec![1, 2, 3, 4, 5]);
+/// assert_eq!(groups[&0], vec![2, 4]);
+/// assert_eq!(groups[&1], vec![1, 3, 5]);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_group_by<I, A, K, F>(key_fn: F) -> impl Fn(I) -> HashMap<K, Vec<A>>
+where
+    I: IntoParallelIterator<Item = A>,
+    F: Fn(&A) -> K + Sync + Send,
+    A: Send,
+    K: Eq + Hash + Send,
+{
+    move |iter| {
+        iter.into_par_iter()
+            .fold(HashMap::new, |mut groups: HashMap<K, Vec<A>>, item| {
+                groups.entry(key_fn(&item)).or_default().push(item);
+                groups
+            })
+            .reduce(HashMap::new, |mut left, right| {
+                for (key, items) in right {
+                    left.entry(key).or_default().extend(items);
+                }
+                left
+            })
+    }
+}
+
+/// Maps every item into a [Monoid](fp_core::monoid::Monoid) in parallel, combining the
+/// results. Since a monoid's combination is associative, the result is the same as if it
+/// had been computed sequentially.
+///
+/// Requires the `rayon` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::par_fold_map;
+/// let total_length = par_fold_map(|s: &str| s.len() as i64);
+/// assert_eq!(total_length(vec!["a", "bc", "def"]), 6);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_fold_map<I, A, M, F>(f: F) -> impl Fn(I) -> M
+where
+    I: IntoParallelIterator<Item = A>,
+    F: Fn(A) -> M + Sync + Send,
+    M: Monoid + Send,
+{
+    move |iter| iter.into_par_iter().map(&f).reduce(M::empty, M::combine)
+}
+
+/// Maps every item into a [Monoid](fp_core::monoid::Monoid), combining the results of each
+/// chunk of `chunk_size` items first, and then combining the results of the chunks. Since a
+/// monoid's combination is associative, the result is the same as an ordinary fold. The
+/// chunks are folded sequentially; to fold them in parallel, see
+/// [par_fold_map_chunked](crate::list::par_fold_map_chunked).
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::fold_map_parallel;
+/// let total_length = fold_map_parallel(2, |s: &str| s.len() as i64);
+/// assert_eq!(total_length(vec!["a", "bc", "def", "ghij", "k"]), 11);
+/// ```
+#[cfg(feature = "std")]
+pub fn fold_map_parallel<I, A, M, F>(chunk_size: usize, f: F) -> impl Fn(I) -> M
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> M,
+    M: Monoid,
+{
+    assert!(chunk_size > 0, "chunk size must be greater than zero");
+    move |iter| {
+        chunks_of(chunk_size)(iter)
+            .map(|chunk| chunk.into_iter().map(&f).fold(M::empty(), M::combine))
+            .fold(M::empty(), M::combine)
+    }
+}
+
+/// Maps every item into a [Monoid](fp_core::monoid::Monoid), as
+/// [fold_map_parallel](crate::list::fold_map_parallel) does, but folds the chunks in parallel.
+///
+/// Requires the `rayon` feature.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::list::par_fold_map_chunked;
+/// let total_length = par_fold_map_chunked(2, |s: &str| s.len() as i64);
+/// assert_eq!(total_length(vec!["a", "bc", "def", "ghij", "k"]), 11);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_fold_map_chunked<I, A, M, F>(chunk_size: usize, f: F) -> impl Fn(I) -> M
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> M + Sync + Send,
+    A: Send,
+    M: Monoid + Send,
+{
+    assert!(chunk_size > 0, "chunk size must be greater than zero");
+    move |iter| {
+        let chunks: Vec<Vec<A>> = chunks_of(chunk_size)(iter).collect();
+        chunks
+            .into_par_iter()
+            .map(|chunk| chunk.into_iter().map(&f).fold(M::empty(), M::combine))
+            .reduce(M::empty, M::combine)
+    }
+}
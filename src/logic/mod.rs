@@ -0,0 +1,144 @@
+//! Functions for combining predicates, written as plain functions of type `Fn(&T) -> bool`.
+//! For predicates which can be stored and combined at runtime, see
+//! [Predicate](crate::predicate::Predicate).
+//!
+//! ```rust
+//! # use fp_std::logic::{both, complement};
+//! let lowercase_word = both(complement(str::is_empty), |s: &str| {
+//!     s.starts_with(char::is_lowercase)
+//! });
+//! let words = vec!["", "apple", "Banana", "cherry"];
+//! let matching: Vec<_> = words.into_iter().filter(|s| lowercase_word(s)).collect();
+//! assert_eq!(matching, vec!["apple", "cherry"]);
+//! ```
+
+/* COMBINATORS ****************************************************************/
+
+/// Creates a predicate which is satisfied when `pred` is not.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::logic::complement;
+/// let odd = complement(|x: &u32| x % 2 == 0);
+/// assert!(odd(&3));
+/// assert!(!odd(&4));
+/// ```
+pub fn complement<T: ?Sized, P>(pred: P) -> impl Fn(&T) -> bool
+where
+    P: Fn(&T) -> bool,
+{
+    move |t| !pred(t)
+}
+
+/// Creates a predicate which is satisfied when both `p` and `q` are. If `p` is not satisfied,
+/// `q` is not tested.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::logic::both;
+/// let teen = both(|age: &u32| *age >= 13, |age: &u32| *age < 20);
+/// assert!(teen(&15));
+/// assert!(!teen(&21));
+/// ```
+pub fn both<T: ?Sized, P, Q>(p: P, q: Q) -> impl Fn(&T) -> bool
+where
+    P: Fn(&T) -> bool,
+    Q: Fn(&T) -> bool,
+{
+    move |t| p(t) && q(t)
+}
+
+/// Creates a predicate which is satisfied when either `p` or `q` is. If `p` is satisfied, `q`
+/// is not tested.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::logic::either;
+/// let blank = either(str::is_empty, |s: &str| s.trim().is_empty());
+/// assert!(blank("  "));
+/// assert!(!blank("a"));
+/// ```
+pub fn either<T: ?Sized, P, Q>(p: P, q: Q) -> impl Fn(&T) -> bool
+where
+    P: Fn(&T) -> bool,
+    Q: Fn(&T) -> bool,
+{
+    move |t| p(t) || q(t)
+}
+
+/// Creates a predicate which is satisfied when exactly one of `p` and `q` is.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::logic::xor_pred;
+/// let exactly_one = xor_pred(|x: &i32| *x < 0, |x: &i32| *x % 2 == 0);
+/// assert!(exactly_one(&-3));
+/// assert!(exactly_one(&4));
+/// assert!(!exactly_one(&-4));
+/// assert!(!exactly_one(&3));
+/// ```
+pub fn xor_pred<T: ?Sized, P, Q>(p: P, q: Q) -> impl Fn(&T) -> bool
+where
+    P: Fn(&T) -> bool,
+    Q: Fn(&T) -> bool,
+{
+    move |t| p(t) != q(t)
+}
+
+/* COLLECTIONS ****************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Creates a predicate which is satisfied when every one of `preds` is. The predicates are
+/// tested in order, stopping at the first which is not satisfied. With no predicates, every
+/// value satisfies the result.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::logic::all_pass;
+/// let checks: Vec<fn(&str) -> bool> = vec![
+///     |s| s.len() >= 8,
+///     |s| s.chars().any(|c| c.is_ascii_digit()),
+/// ];
+/// let strong = all_pass(checks);
+/// assert!(strong("hunter22!"));
+/// assert!(!strong("hunter2"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn all_pass<T: ?Sized, I, P>(preds: I) -> impl Fn(&T) -> bool
+where
+    I: IntoIterator<Item = P>,
+    P: Fn(&T) -> bool,
+{
+    let preds: Vec<P> = preds.into_iter().collect();
+    move |t| preds.iter().all(|pred| pred(t))
+}
+
+/// Creates a predicate which is satisfied when any one of `preds` is. The predicates are
+/// tested in order, stopping at the first which is satisfied. With no predicates, no value
+/// satisfies the result.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::logic::any_pass;
+/// let checks: Vec<Box<dyn Fn(&u32) -> bool>> = vec![Box::new(|x| *x == 0), Box::new(|x| *x > 99)];
+/// let out_of_range = any_pass(checks);
+/// assert!(out_of_range(&0));
+/// assert!(out_of_range(&100));
+/// assert!(!out_of_range(&50));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn any_pass<T: ?Sized, I, P>(preds: I) -> impl Fn(&T) -> bool
+where
+    I: IntoIterator<Item = P>,
+    P: Fn(&T) -> bool,
+{
+    let preds: Vec<P> = preds.into_iter().collect();
+    move |t| preds.iter().any(|pred| pred(t))
+}
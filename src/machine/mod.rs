@@ -0,0 +1,456 @@
+//! State machines as values, which fold a sequence of inputs into a state, producing outputs
+//! along the way, so that event handlers can be built from small machines and tested without
+//! any side effects.
+//!
+//! A [Mealy](crate::machine::Mealy) machine produces an output from each transition, while a
+//! [Moore](crate::machine::Moore) machine produces its output from its state alone.
+//!
+//! ```rust
+//! # use fp_std::machine::Mealy;
+//! // A turnstile, which unlocks when a coin is inserted, and locks again once pushed.
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! enum Event {
+//!     Coin,
+//!     Push,
+//! }
+//!
+//! let turnstile = Mealy::new(true, |locked, event| match (locked, event) {
+//!     (true, Event::Coin) => (false, "unlocked"),
+//!     (false, Event::Push) => (true, "locked"),
+//!     (locked, _) => (locked, "ignored"),
+//! });
+//! let (turnstile, outputs) = turnstile.run_fold(vec![Event::Push, Event::Coin, Event::Push]);
+//! assert_eq!(outputs, vec!["ignored", "unlocked", "locked"]);
+//! assert!(*turnstile.state());
+//! ```
+
+/* MEALY MACHINES *************************************************************/
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+type MealyStep<S, I, O> = dyn Fn(S, I) -> (S, O);
+
+/// A state machine in state `S`, which takes inputs of type `I`, and produces an output of
+/// type `O` from each transition.
+pub struct Mealy<S, I, O> {
+    state: S,
+    step: Box<MealyStep<S, I, O>>,
+}
+
+impl<S: 'static, I: 'static, O: 'static> Mealy<S, I, O> {
+    /// Creates a machine in state `init`, with a function which takes the state and an input
+    /// to the next state and an output.
+    pub fn new<F>(init: S, f: F) -> Self
+    where
+        F: Fn(S, I) -> (S, O) + 'static,
+    {
+        Mealy {
+            state: init,
+            step: Box::new(f),
+        }
+    }
+
+    /// Borrows the current state of the machine.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Discards the machine, returning its current state.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Feeds one input to the machine, returning the machine in its next state, along with the
+    /// output of the transition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Mealy;
+    /// let counter = Mealy::new(0, |n: u32, step: u32| (n + step, n));
+    /// let (counter, previous) = counter.step(5);
+    /// assert_eq!(previous, 0);
+    /// assert_eq!(*counter.state(), 5);
+    /// ```
+    pub fn step(self, input: I) -> (Self, O) {
+        let (state, output) = (self.step)(self.state, input);
+        (
+            Mealy {
+                state,
+                step: self.step,
+            },
+            output,
+        )
+    }
+
+    /// Feeds every input of a list to the machine, in order, returning the machine in its
+    /// final state, along with the output of each transition. The returned machine may be fed
+    /// more inputs later.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Mealy;
+    /// let running_total = Mealy::new(0, |total: i32, n: i32| (total + n, total + n));
+    /// let (running_total, totals) = running_total.run_fold(vec![1, 2, 3]);
+    /// assert_eq!(totals, vec![1, 3, 6]);
+    /// assert_eq!(running_total.run_fold(vec![-6]).1, vec![0]);
+    /// ```
+    pub fn run_fold<It>(self, inputs: It) -> (Self, Vec<O>)
+    where
+        It: IntoIterator<Item = I>,
+    {
+        let mut outputs = Vec::new();
+        let mut state = self.state;
+        for input in inputs {
+            let (next, output) = (self.step)(state, input);
+            state = next;
+            outputs.push(output);
+        }
+        (
+            Mealy {
+                state,
+                step: self.step,
+            },
+            outputs,
+        )
+    }
+
+    /// Creates a machine which feeds each output of this machine to `next` as its input,
+    /// producing the outputs of `next`. The state of the new machine is the states of both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Mealy;
+    /// let differences = Mealy::new(0, |previous: i32, n: i32| (n, n - previous));
+    /// let peak = Mealy::new(i32::MIN, |peak: i32, n: i32| (peak.max(n), peak.max(n)));
+    /// let (machine, peaks) = differences.then(peak).run_fold(vec![1, 5, 6, 2]);
+    /// assert_eq!(peaks, vec![1, 4, 4, 4]);
+    /// assert_eq!(*machine.state(), (2, 4));
+    /// ```
+    pub fn then<T, P>(self, next: Mealy<T, O, P>) -> Mealy<(S, T), I, P>
+    where
+        T: 'static,
+        P: 'static,
+    {
+        let (first, second) = (self.step, next.step);
+        Mealy::new((self.state, next.state), move |(s, t), input| {
+            let (s, o) = first(s, input);
+            let (t, p) = second(t, o);
+            ((s, t), p)
+        })
+    }
+
+    /// Creates a machine which feeds each input to both this machine and `other`, producing
+    /// the outputs of both. The state of the new machine is the states of both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Mealy;
+    /// let count = Mealy::new(0, |n: usize, _: char| (n + 1, n + 1));
+    /// let upper = Mealy::new(0, |n: usize, c: char| {
+    ///     let n = n + c.is_uppercase() as usize;
+    ///     (n, n)
+    /// });
+    /// let (_, stats) = count.zip(upper).run_fold("aBC".chars());
+    /// assert_eq!(stats, vec![(1, 0), (2, 1), (3, 2)]);
+    /// ```
+    pub fn zip<T, P>(self, other: Mealy<T, I, P>) -> Mealy<(S, T), I, (O, P)>
+    where
+        I: Clone,
+        T: 'static,
+        P: 'static,
+    {
+        let (left, right) = (self.step, other.step);
+        Mealy::new((self.state, other.state), move |(s, t), input: I| {
+            let (s, o) = left(s, input.clone());
+            let (t, p) = right(t, input);
+            ((s, t), (o, p))
+        })
+    }
+}
+
+impl<S: Debug, I, O> Debug for Mealy<S, I, O> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Mealy").field(&self.state).finish()
+    }
+}
+
+/* MOORE MACHINES *************************************************************/
+
+type MooreStep<S, I> = dyn Fn(S, I) -> S;
+type MooreOutput<S, O> = dyn Fn(&S) -> O;
+
+/// A state machine in state `S`, which takes inputs of type `I`, and produces an output of
+/// type `O` from each state it is in.
+pub struct Moore<S, I, O> {
+    state: S,
+    step: Box<MooreStep<S, I>>,
+    output: Box<MooreOutput<S, O>>,
+}
+
+impl<S: 'static, I: 'static, O: 'static> Moore<S, I, O> {
+    /// Creates a machine in state `init`, with a function which takes the state and an input
+    /// to the next state, and a function which produces the output of a state.
+    pub fn new<F, G>(init: S, step: F, output: G) -> Self
+    where
+        F: Fn(S, I) -> S + 'static,
+        G: Fn(&S) -> O + 'static,
+    {
+        Moore {
+            state: init,
+            step: Box::new(step),
+            output: Box::new(output),
+        }
+    }
+
+    /// Borrows the current state of the machine.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Discards the machine, returning its current state.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Produces the output of the current state of the machine.
+    pub fn output(&self) -> O {
+        (self.output)(&self.state)
+    }
+
+    /// Feeds one input to the machine, returning the machine in its next state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Moore;
+    /// let label = |&on: &bool| if on { "on" } else { "off" };
+    /// let light = Moore::new(false, |on: bool, ()| !on, label);
+    /// assert_eq!(light.output(), "off");
+    /// assert_eq!(light.step(()).output(), "on");
+    /// ```
+    pub fn step(self, input: I) -> Self {
+        Moore {
+            state: (self.step)(self.state, input),
+            step: self.step,
+            output: self.output,
+        }
+    }
+
+    /// Feeds every input of a list to the machine, in order, returning the machine in its
+    /// final state, along with the output of each state it moved to. The output of the state
+    /// it started in is not included. The returned machine may be fed more inputs later.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Moore;
+    /// let average = Moore::new(
+    ///     (0.0, 0),
+    ///     |(sum, count): (f64, u32), x: f64| (sum + x, count + 1),
+    ///     |&(sum, count): &(f64, u32)| sum / count as f64,
+    /// );
+    /// let (average, averages) = average.run_fold(vec![2.0, 4.0, 9.0]);
+    /// assert_eq!(averages, vec![2.0, 3.0, 5.0]);
+    /// assert_eq!(average.output(), 5.0);
+    /// ```
+    pub fn run_fold<It>(self, inputs: It) -> (Self, Vec<O>)
+    where
+        It: IntoIterator<Item = I>,
+    {
+        let mut outputs = Vec::new();
+        let mut state = self.state;
+        for input in inputs {
+            state = (self.step)(state, input);
+            outputs.push((self.output)(&state));
+        }
+        (
+            Moore {
+                state,
+                step: self.step,
+                output: self.output,
+            },
+            outputs,
+        )
+    }
+
+    /// Creates a machine which feeds the output of each state this machine moves to, to
+    /// `next` as its input, producing the outputs of `next`. The state of the new machine is
+    /// the states of both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Moore;
+    /// let total = Moore::new(0, |total: u32, n: u32| total + n, |total: &u32| *total);
+    /// let over_budget = Moore::new(
+    ///     0,
+    ///     |times: u32, total: u32| times + (total > 10) as u32,
+    ///     |times: &u32| *times,
+    /// );
+    /// let (machine, times) = total.then(over_budget).run_fold(vec![4, 8, 1]);
+    /// assert_eq!(times, vec![0, 1, 2]);
+    /// assert_eq!(*machine.state(), (13, 2));
+    /// ```
+    pub fn then<T, P>(self, next: Moore<T, O, P>) -> Moore<(S, T), I, P>
+    where
+        T: 'static,
+        P: 'static,
+    {
+        let (first, first_output, second) = (self.step, self.output, next.step);
+        let second_output = next.output;
+        Moore::new(
+            (self.state, next.state),
+            move |(s, t), input| {
+                let s = first(s, input);
+                let t = second(t, first_output(&s));
+                (s, t)
+            },
+            move |(_, t): &(S, T)| second_output(t),
+        )
+    }
+
+    /// Creates a machine which feeds each input to both this machine and `other`, producing
+    /// the outputs of both. The state of the new machine is the states of both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Moore;
+    /// let min = Moore::new(i32::MAX, |min: i32, n: i32| min.min(n), |min: &i32| *min);
+    /// let max = Moore::new(i32::MIN, |max: i32, n: i32| max.max(n), |max: &i32| *max);
+    /// let range = min.zip(max).run_fold(vec![3, -1, 7]).0;
+    /// assert_eq!(range.output(), (-1, 7));
+    /// ```
+    pub fn zip<T, P>(self, other: Moore<T, I, P>) -> Moore<(S, T), I, (O, P)>
+    where
+        I: Clone,
+        T: 'static,
+        P: 'static,
+    {
+        let (left, left_output) = (self.step, self.output);
+        let (right, right_output) = (other.step, other.output);
+        Moore::new(
+            (self.state, other.state),
+            move |(s, t), input: I| (left(s, input.clone()), right(t, input)),
+            move |(s, t): &(S, T)| (left_output(s), right_output(t)),
+        )
+    }
+}
+
+impl<S: Debug, I, O> Debug for Moore<S, I, O> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Moore").field(&self.state).finish()
+    }
+}
+
+/* STATE ACCESS ***************************************************************/
+#[cfg(feature = "std")]
+use crate::lens::LensMut;
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+
+#[cfg(feature = "std")]
+impl<S: 'static, I: 'static, O: 'static> Mealy<S, I, O> {
+    /// Borrows the part of the state of the machine that a lens focuses on, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Mealy;
+    /// # use fp_std::tuple::LensSecond;
+    /// let log = Mealy::new(("log", 0), |(name, n): (&str, u32), _: ()| ((name, n + 1), n));
+    /// assert_eq!(log.step(()).0.view_state(LensSecond), Some(&1));
+    /// ```
+    pub fn view_state<L, A>(&self, _lens: L) -> Option<&A>
+    where
+        L: Lens<S, A>,
+    {
+        L::get(&self.state)
+    }
+
+    /// Replaces the part of the state of the machine that a lens focuses on with the result
+    /// of a function of it, so that the machine can be adjusted between inputs. If the lens
+    /// focuses on nothing, the machine is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Mealy;
+    /// # use fp_std::tuple::LensFirst;
+    /// let scaled = Mealy::new((1, 0), |(scale, total): (i32, i32), n: i32| {
+    ///     ((scale, total + n * scale), total + n * scale)
+    /// });
+    /// let (scaled, _) = scaled.step(1);
+    /// let (_, total) = scaled.over_state(LensFirst, |scale| scale * 10).step(1);
+    /// assert_eq!(total, 11);
+    /// ```
+    pub fn over_state<L, A, F>(mut self, _lens: L, f: F) -> Self
+    where
+        L: LensMut<S, A>,
+        F: FnOnce(&A) -> A,
+    {
+        if let Some(a) = L::get_mut(&mut self.state) {
+            *a = f(a);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: 'static, I: 'static, O: 'static> Moore<S, I, O> {
+    /// Borrows the part of the state of the machine that a lens focuses on, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Moore;
+    /// # use fp_std::tuple::LensFirst;
+    /// let last_two = Moore::new(
+    ///     (0, 0),
+    ///     |(_, b): (u32, u32), n: u32| (b, n),
+    ///     |&(a, b): &(u32, u32)| a + b,
+    /// );
+    /// let last_two = last_two.step(4).step(5);
+    /// assert_eq!(last_two.view_state(LensFirst), Some(&4));
+    /// assert_eq!(last_two.output(), 9);
+    /// ```
+    pub fn view_state<L, A>(&self, _lens: L) -> Option<&A>
+    where
+        L: Lens<S, A>,
+    {
+        L::get(&self.state)
+    }
+
+    /// Replaces the part of the state of the machine that a lens focuses on with the result
+    /// of a function of it, so that the machine can be adjusted between inputs. If the lens
+    /// focuses on nothing, the machine is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::machine::Moore;
+    /// # use fp_std::tuple::LensSecond;
+    /// let thermostat = Moore::new(
+    ///     (18, 20),
+    ///     |(_, target): (i32, i32), reading: i32| (reading, target),
+    ///     |&(reading, target): &(i32, i32)| reading < target,
+    /// );
+    /// assert!(thermostat.output());
+    /// assert!(!thermostat.over_state(LensSecond, |_| 16).output());
+    /// ```
+    pub fn over_state<L, A, F>(mut self, _lens: L, f: F) -> Self
+    where
+        L: LensMut<S, A>,
+        F: FnOnce(&A) -> A,
+    {
+        if let Some(a) = L::get_mut(&mut self.state) {
+            *a = f(a);
+        }
+        self
+    }
+}
@@ -0,0 +1,731 @@
+//! Functions for manipulating maps, such as `HashMap` and `BTreeMap`, and anything else which
+//! can be iterated as key-value pairs.
+//!
+//! Functions which produce a map can produce any type of map, so the type is usually inferred
+//! from where the result is used.
+
+/* TRANSFORMATIONS ************************************************************/
+use core::iter::FromIterator;
+
+/// Transforms every entry of a map, keys and values together.
+///
+/// If multiple entries are transformed to the same key, only the last of them is kept, as with
+/// `collect`. For a `HashMap`, which of them that is depends on its iteration order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::map_entries;
+/// # use std::collections::{BTreeMap, HashMap};
+/// let prices: HashMap<&str, u32> = vec![("apple", 120), ("pear", 95)].into_iter().collect();
+/// let labels: BTreeMap<String, f64> = map_entries(|(name, cents): (&str, u32)| {
+///     (name.to_uppercase(), cents as f64 / 100.0)
+/// })(prices);
+/// assert_eq!(labels["APPLE"], 1.2);
+/// assert_eq!(labels["PEAR"], 0.95);
+/// ```
+pub fn map_entries<M, K, V, N, F>(f: F) -> impl Fn(M) -> N
+where
+    M: IntoIterator,
+    N: FromIterator<(K, V)>,
+    F: Fn(M::Item) -> (K, V),
+{
+    move |map| map.into_iter().map(&f).collect()
+}
+
+/// Transforms every value of a map, keeping its key.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::map_values;
+/// # use std::collections::HashMap;
+/// let prices: HashMap<&str, u32> = vec![("apple", 120), ("pear", 95)].into_iter().collect();
+/// let in_dollars: HashMap<&str, f64> = map_values(|cents: u32| cents as f64 / 100.0)(prices);
+/// assert_eq!(in_dollars["pear"], 0.95);
+/// ```
+pub fn map_values<M, K, V, W, N, F>(f: F) -> impl Fn(M) -> N
+where
+    M: IntoIterator<Item = (K, V)>,
+    N: FromIterator<(K, W)>,
+    F: Fn(V) -> W,
+{
+    move |map| {
+        map.into_iter()
+            .map(|(key, value)| (key, f(value)))
+            .collect()
+    }
+}
+
+/// Transforms every key of a map, keeping its value. If multiple keys are transformed to the
+/// same key, only the last of their entries is kept, as with
+/// [map_entries](crate::map::map_entries).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::map_keys;
+/// # use std::collections::BTreeMap;
+/// let scores: BTreeMap<&str, u32> = vec![("ann", 3), ("bo", 5)].into_iter().collect();
+/// let upper: BTreeMap<String, u32> = map_keys(str::to_uppercase)(scores);
+/// assert_eq!(upper["BO"], 5);
+/// ```
+pub fn map_keys<M, K, J, V, N, F>(f: F) -> impl Fn(M) -> N
+where
+    M: IntoIterator<Item = (K, V)>,
+    N: FromIterator<(J, V)>,
+    F: Fn(K) -> J,
+{
+    move |map| {
+        map.into_iter()
+            .map(|(key, value)| (f(key), value))
+            .collect()
+    }
+}
+
+/// Keeps only the entries of a map whose keys satisfy a predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::filter_keys;
+/// # use std::collections::BTreeMap;
+/// let env: BTreeMap<&str, &str> = vec![("APP_PORT", "80"), ("HOME", "/root")]
+///     .into_iter()
+///     .collect();
+/// let app: BTreeMap<&str, &str> = filter_keys(|key: &&str| key.starts_with("APP_"))(env);
+/// assert_eq!(app.into_iter().collect::<Vec<_>>(), vec![("APP_PORT", "80")]);
+/// ```
+pub fn filter_keys<M, K, V, N, P>(pred: P) -> impl Fn(M) -> N
+where
+    M: IntoIterator<Item = (K, V)>,
+    N: FromIterator<(K, V)>,
+    P: Fn(&K) -> bool,
+{
+    move |map| map.into_iter().filter(|(key, _)| pred(key)).collect()
+}
+
+/// Swaps the keys and values of a map. If multiple keys have the same value, only the last of
+/// them is kept, as with `collect`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::invert;
+/// # use std::collections::BTreeMap;
+/// let codes: BTreeMap<&str, u16> = vec![("ok", 200), ("not found", 404)].into_iter().collect();
+/// let names: BTreeMap<u16, &str> = invert(codes);
+/// assert_eq!(names[&404], "not found");
+/// ```
+pub fn invert<M, K, V, N>(map: M) -> N
+where
+    M: IntoIterator<Item = (K, V)>,
+    N: FromIterator<(V, K)>,
+{
+    map.into_iter().map(|(key, value)| (value, key)).collect()
+}
+
+/* FOLDS **********************************************************************/
+#[cfg(feature = "std")]
+use fp_core::monoid::Monoid;
+
+/// Maps every value of a map into a [Monoid](fp_core::monoid::Monoid), and combines the
+/// results. The keys are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::fold_map_values;
+/// # use std::collections::BTreeMap;
+/// let stock: BTreeMap<&str, Vec<u32>> = vec![("apple", vec![3, 4]), ("pear", vec![5])]
+///     .into_iter()
+///     .collect();
+/// let total = fold_map_values(|counts: Vec<u32>| counts.iter().sum::<u32>() as i64);
+/// assert_eq!(total(stock), 12);
+/// ```
+#[cfg(feature = "std")]
+pub fn fold_map_values<M, K, V, O, F>(f: F) -> impl Fn(M) -> O
+where
+    M: IntoIterator<Item = (K, V)>,
+    O: Monoid,
+    F: Fn(V) -> O,
+{
+    move |map| {
+        map.into_iter()
+            .map(|(_, value)| f(value))
+            .fold(O::empty(), O::combine)
+    }
+}
+
+/* DIFFING ********************************************************************/
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// The differences between two maps, as computed by [diff](crate::map::diff).
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapDiff<K: Eq + Hash, V> {
+    /// The entries which are only in the new map.
+    pub added: HashMap<K, V>,
+    /// The entries which are only in the old map.
+    pub removed: HashMap<K, V>,
+    /// The entries whose values differ between the maps, as pairs of their old and new values.
+    pub changed: HashMap<K, (V, V)>,
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V> MapDiff<K, V> {
+    /// Whether the maps which were compared are equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares two maps, reporting which entries were added, removed, or changed between them.
+/// Entries which are the same in both maps are not reported.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::diff;
+/// # use std::collections::HashMap;
+/// let old: HashMap<&str, u32> = vec![("replicas", 2), ("port", 80), ("debug", 1)]
+///     .into_iter()
+///     .collect();
+/// let new: HashMap<&str, u32> = vec![("replicas", 3), ("port", 80), ("timeout", 30)]
+///     .into_iter()
+///     .collect();
+/// let changes = diff(old, new);
+/// assert_eq!(changes.added, vec![("timeout", 30)].into_iter().collect());
+/// assert_eq!(changes.removed, vec![("debug", 1)].into_iter().collect());
+/// assert_eq!(changes.changed, vec![("replicas", (2, 3))].into_iter().collect());
+/// ```
+#[cfg(feature = "std")]
+pub fn diff<K, V>(old: HashMap<K, V>, mut new: HashMap<K, V>) -> MapDiff<K, V>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+{
+    let mut removed = HashMap::new();
+    let mut changed = HashMap::new();
+    for (key, old_value) in old {
+        match new.remove(&key) {
+            None => {
+                removed.insert(key, old_value);
+            }
+            Some(new_value) if new_value == old_value => {}
+            Some(new_value) => {
+                changed.insert(key, (old_value, new_value));
+            }
+        }
+    }
+    MapDiff {
+        added: new,
+        removed,
+        changed,
+    }
+}
+
+/// Applies the differences computed by [diff](crate::map::diff) to a map: removed entries are
+/// removed, and added or changed entries are set to their new values.
+///
+/// Applying the diff of two maps to the first of them produces the second.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::{apply_diff, diff};
+/// # use std::collections::HashMap;
+/// let old: HashMap<&str, u32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+/// let new: HashMap<&str, u32> = vec![("b", 3), ("c", 4)].into_iter().collect();
+/// let changes = diff(old.clone(), new.clone());
+/// assert_eq!(apply_diff(changes, old), new);
+/// ```
+#[cfg(feature = "std")]
+pub fn apply_diff<K, V>(diff: MapDiff<K, V>, mut map: HashMap<K, V>) -> HashMap<K, V>
+where
+    K: Eq + Hash,
+{
+    for key in diff.removed.keys() {
+        map.remove(key);
+    }
+    map.extend(diff.added);
+    map.extend(diff.changed.into_iter().map(|(key, (_, new))| (key, new)));
+    map
+}
+
+/* JOINS **********************************************************************/
+#[cfg(feature = "std")]
+use crate::either::These;
+
+/// Joins two maps on their keys, pairing the values of each key which is in both of them.
+/// Keys which are only in one of the maps are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::zip_maps;
+/// # use std::collections::HashMap;
+/// let names: HashMap<u32, &str> = vec![(1, "ann"), (2, "bo")].into_iter().collect();
+/// let ages: HashMap<u32, u8> = vec![(1, 31), (3, 40)].into_iter().collect();
+/// assert_eq!(zip_maps(names, ages), vec![(1, ("ann", 31))].into_iter().collect());
+/// ```
+#[cfg(feature = "std")]
+pub fn zip_maps<K, V1, V2>(a: HashMap<K, V1>, mut b: HashMap<K, V2>) -> HashMap<K, (V1, V2)>
+where
+    K: Eq + Hash,
+{
+    a.into_iter()
+        .filter_map(|(key, v1)| {
+            let v2 = b.remove(&key)?;
+            Some((key, (v1, v2)))
+        })
+        .collect()
+}
+
+/// Joins two maps on their keys, keeping every key which is in either of them, along with
+/// whichever of its values are present.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::These;
+/// # use fp_std::map::full_join;
+/// # use std::collections::HashMap;
+/// let names: HashMap<u32, &str> = vec![(1, "ann"), (2, "bo")].into_iter().collect();
+/// let ages: HashMap<u32, u8> = vec![(1, 31), (3, 40)].into_iter().collect();
+/// let joined = full_join(names, ages);
+/// assert_eq!(joined[&1], These::Both("ann", 31));
+/// assert_eq!(joined[&2], These::Left("bo"));
+/// assert_eq!(joined[&3], These::Right(40));
+/// ```
+#[cfg(feature = "std")]
+pub fn full_join<K, V1, V2>(a: HashMap<K, V1>, mut b: HashMap<K, V2>) -> HashMap<K, These<V1, V2>>
+where
+    K: Eq + Hash,
+{
+    let mut joined: HashMap<K, These<V1, V2>> = a
+        .into_iter()
+        .map(|(key, v1)| {
+            let these = match b.remove(&key) {
+                Some(v2) => These::Both(v1, v2),
+                None => These::Left(v1),
+            };
+            (key, these)
+        })
+        .collect();
+    joined.extend(b.into_iter().map(|(key, v2)| (key, These::Right(v2))));
+    joined
+}
+
+/// Merges two maps into one with the keys of both, combining the values of each key which is
+/// in both maps with `f`, the value from `a` first.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::merge_with;
+/// # use std::collections::HashMap;
+/// let monday: HashMap<&str, u32> = vec![("apple", 3), ("pear", 1)].into_iter().collect();
+/// let tuesday: HashMap<&str, u32> = vec![("apple", 2), ("plum", 4)].into_iter().collect();
+/// let sold = merge_with(|a, b| a + b, monday, tuesday);
+/// assert_eq!((sold["apple"], sold["pear"], sold["plum"]), (5, 1, 4));
+/// ```
+#[cfg(feature = "std")]
+pub fn merge_with<K, V, F>(f: F, mut a: HashMap<K, V>, b: HashMap<K, V>) -> HashMap<K, V>
+where
+    K: Eq + Hash,
+    F: Fn(V, V) -> V,
+{
+    for (key, value) in b {
+        let value = match a.remove(&key) {
+            Some(existing) => f(existing, value),
+            None => value,
+        };
+        a.insert(key, value);
+    }
+    a
+}
+
+/* SELECTION ******************************************************************/
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+/// Creates a function which keeps only the entries of a map whose keys are among `keys`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::pick;
+/// # use std::collections::HashMap;
+/// let user: HashMap<&str, &str> = vec![("name", "ann"), ("email", "a@b.c"), ("password", "x")]
+///     .into_iter()
+///     .collect();
+/// let public: HashMap<&str, &str> = pick(vec!["name", "email"])(user);
+/// assert_eq!(public.len(), 2);
+/// assert!(!public.contains_key("password"));
+/// ```
+#[cfg(feature = "std")]
+pub fn pick<I, M, K, V, N>(keys: I) -> impl Fn(M) -> N
+where
+    I: IntoIterator<Item = K>,
+    M: IntoIterator<Item = (K, V)>,
+    N: FromIterator<(K, V)>,
+    K: Eq + Hash,
+{
+    let keys: HashSet<K> = keys.into_iter().collect();
+    move |map| {
+        map.into_iter()
+            .filter(|(key, _)| keys.contains(key))
+            .collect()
+    }
+}
+
+/// Creates a function which drops the entries of a map whose keys are among `keys`, keeping
+/// the rest.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::omit;
+/// # use std::collections::HashMap;
+/// let user: HashMap<&str, &str> = vec![("name", "ann"), ("password", "x")].into_iter().collect();
+/// let safe: HashMap<&str, &str> = omit(vec!["password"])(user);
+/// assert_eq!(safe, vec![("name", "ann")].into_iter().collect());
+/// ```
+#[cfg(feature = "std")]
+pub fn omit<I, M, K, V, N>(keys: I) -> impl Fn(M) -> N
+where
+    I: IntoIterator<Item = K>,
+    M: IntoIterator<Item = (K, V)>,
+    N: FromIterator<(K, V)>,
+    K: Eq + Hash,
+{
+    let keys: HashSet<K> = keys.into_iter().collect();
+    move |map| {
+        map.into_iter()
+            .filter(|(key, _)| !keys.contains(key))
+            .collect()
+    }
+}
+
+/* CONVERSIONS ****************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Collects key-value pairs into a `HashMap`. If a key appears more than once, the last value
+/// for it is kept. To combine them instead, see [from_pairs_with](crate::map::from_pairs_with).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::from_pairs;
+/// let ages = from_pairs(vec![("ann", 31), ("bo", 27), ("ann", 32)]);
+/// assert_eq!(ages.len(), 2);
+/// assert_eq!(ages["ann"], 32);
+/// ```
+#[cfg(feature = "std")]
+pub fn from_pairs<I, K, V>(pairs: I) -> HashMap<K, V>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Eq + Hash,
+{
+    pairs.into_iter().collect()
+}
+
+/// Creates a function which collects key-value pairs into a `HashMap`, resolving each
+/// duplicate key by combining the value already collected for it with the new one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::from_pairs_with;
+/// let totals = from_pairs_with(|total, amount| total + amount);
+/// let spent = totals(vec![("food", 12), ("rent", 800), ("food", 30)]);
+/// assert_eq!(spent["food"], 42);
+/// assert_eq!(spent["rent"], 800);
+/// ```
+#[cfg(feature = "std")]
+pub fn from_pairs_with<I, K, V, F>(resolve: F) -> impl Fn(I) -> HashMap<K, V>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Eq + Hash,
+    F: Fn(V, V) -> V,
+{
+    move |pairs| {
+        let mut map = HashMap::new();
+        for (key, value) in pairs {
+            let value = match map.remove(&key) {
+                Some(existing) => resolve(existing, value),
+                None => value,
+            };
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+/// Converts a map into a `Vec` of its key-value pairs, in its iteration order. For a `HashMap`,
+/// that order is unspecified; to make it deterministic, see
+/// [to_pairs_sorted](crate::map::to_pairs_sorted).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::to_pairs;
+/// # use std::collections::BTreeMap;
+/// let sizes: BTreeMap<&str, u32> = vec![("s", 1), ("m", 2)].into_iter().collect();
+/// assert_eq!(to_pairs(sizes), vec![("m", 2), ("s", 1)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_pairs<M, K, V>(map: M) -> Vec<(K, V)>
+where
+    M: IntoIterator<Item = (K, V)>,
+{
+    map.into_iter().collect()
+}
+
+/// Converts a map into a `Vec` of its key-value pairs, sorted by key.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::{from_pairs, to_pairs_sorted};
+/// let sizes = from_pairs(vec![("s", 1), ("xl", 4), ("m", 2)]);
+/// assert_eq!(to_pairs_sorted(sizes), vec![("m", 2), ("s", 1), ("xl", 4)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_pairs_sorted<M, K, V>(map: M) -> Vec<(K, V)>
+where
+    M: IntoIterator<Item = (K, V)>,
+    K: Ord,
+{
+    let mut pairs = to_pairs(map);
+    pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+    pairs
+}
+
+/* COUNTERS *******************************************************************/
+#[cfg(feature = "std")]
+use core::ops::{Add, Sub};
+#[cfg(feature = "std")]
+use fp_core::empty::Empty;
+#[cfg(feature = "std")]
+use fp_core::semigroup::Semigroup;
+#[cfg(feature = "std")]
+use std::collections::hash_map::Entry;
+
+/// A count of how many times each distinct key has occurred. Only keys which have occurred are
+/// stored, so every stored count is positive.
+///
+/// Counters form a [Monoid](fp_core::monoid::Monoid) under [add](crate::map::Counter::add),
+/// whose empty value has counted nothing, so counts can be computed separately and then
+/// combined.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::Counter;
+/// let words: Counter<&str> = "the cat saw the other cat and the dog".split(' ').collect();
+/// assert_eq!(words.count(&"the"), 3);
+/// assert_eq!(words.count(&"bird"), 0);
+/// assert_eq!(words.most_common(2), vec![(&"the", 3), (&"cat", 2)]);
+/// assert_eq!(words.total(), 9);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct Counter<K> {
+    counts: HashMap<K, usize>,
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Counter<K> {
+    /// Creates a counter which has counted nothing.
+    pub fn new() -> Self {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Counts one more occurrence of a key.
+    pub fn increment(&mut self, key: K) {
+        *self.counts.entry(key).or_insert(0) += 1;
+    }
+
+    /// The number of times a key has occurred.
+    pub fn count(&self, key: &K) -> usize {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// The number of distinct keys which have occurred.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Whether nothing has been counted.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The number of occurrences of all keys together.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Adds the counts of another counter to this one's.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::map::Counter;
+    /// let monday: Counter<char> = "aab".chars().collect();
+    /// let tuesday: Counter<char> = "bc".chars().collect();
+    /// let both = monday.add(tuesday);
+    /// assert_eq!((both.count(&'a'), both.count(&'b'), both.count(&'c')), (2, 2, 1));
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, other: Self) -> Self {
+        for (key, count) in other.counts {
+            *self.counts.entry(key).or_insert(0) += count;
+        }
+        self
+    }
+
+    /// Subtracts the counts of another counter from this one's. Counts which would fall to zero
+    /// or below are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::map::Counter;
+    /// let stock: Counter<&str> = vec!["apple", "apple", "pear"].into_iter().collect();
+    /// let sold: Counter<&str> = vec!["apple", "pear", "pear"].into_iter().collect();
+    /// let left = stock.subtract(sold);
+    /// assert_eq!(left.count(&"apple"), 1);
+    /// assert_eq!(left.count(&"pear"), 0);
+    /// assert_eq!(left.len(), 1);
+    /// ```
+    pub fn subtract(mut self, other: Self) -> Self {
+        for (key, count) in other.counts {
+            if let Entry::Occupied(mut entry) = self.counts.entry(key) {
+                if *entry.get() > count {
+                    *entry.get_mut() -= count;
+                } else {
+                    entry.remove();
+                }
+            }
+        }
+        self
+    }
+
+    /// The `n` keys which have occurred most often, with their counts, from most to least.
+    /// Keys which have occurred equally often are in no particular order.
+    pub fn most_common(&self, n: usize) -> Vec<(&K, usize)> {
+        let mut counts: Vec<_> = self
+            .counts
+            .iter()
+            .map(|(key, count)| (key, *count))
+            .collect();
+        counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+        counts.truncate(n);
+        counts
+    }
+
+    /// Takes the counts out of the counter, as a map from each key to its count.
+    pub fn into_map(self) -> HashMap<K, usize> {
+        self.counts
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Default for Counter<K> {
+    fn default() -> Self {
+        Counter::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> PartialEq for Counter<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Eq for Counter<K> {}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Extend<K> for Counter<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, keys: I) {
+        keys.into_iter().for_each(|key| self.increment(key));
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> FromIterator<K> for Counter<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(keys: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(keys);
+        counter
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K> IntoIterator for Counter<K> {
+    type Item = (K, usize);
+    type IntoIter = std::collections::hash_map::IntoIter<K, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Add for Counter<K> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Counter::add(self, other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Sub for Counter<K> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.subtract(other)
+    }
+}
+
+/// Counters are combined by [add](crate::map::Counter::add).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::map::{fold_map_values, Counter};
+/// # use std::collections::HashMap;
+/// let mut shelves = HashMap::new();
+/// shelves.insert("top", vec!["jam", "tea"]);
+/// shelves.insert("bottom", vec!["tea", "tea"]);
+/// let totals = fold_map_values(|items: Vec<&str>| items.into_iter().collect::<Counter<_>>());
+/// assert_eq!(totals(shelves).count(&"tea"), 3);
+/// ```
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Semigroup for Counter<K> {
+    fn combine(self, other: Self) -> Self {
+        self.add(other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Empty for Counter<K> {
+    fn empty() -> Self {
+        Counter::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash> Monoid for Counter<K> {}
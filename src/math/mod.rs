@@ -0,0 +1,155 @@
+//! Curried numeric functions, for composing arithmetic with other functions.
+//!
+//! ```rust
+//! # use fp_std::math::{add, between, mul};
+//! # use fp_std::tuple::map_second;
+//! let widen = map_second(mul(2));
+//! assert_eq!(widen(("width", 40)), ("width", 80));
+//!
+//! let shifted: Vec<i32> = (1..=5).map(add(10)).filter(between(12, 14)).collect();
+//! assert_eq!(shifted, vec![12, 13, 14]);
+//! ```
+
+/* ARITHMETIC *****************************************************************/
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Creates a function which adds `n` to its argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::add;
+/// let next: Vec<i32> = vec![1, 2, 3].into_iter().map(add(1)).collect();
+/// assert_eq!(next, vec![2, 3, 4]);
+/// ```
+pub fn add<T>(n: T) -> impl Fn(T) -> T::Output
+where
+    T: Add + Copy,
+{
+    move |x| x + n
+}
+
+/// Creates a function which subtracts `n` from its argument.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::sub;
+/// assert_eq!(sub(1.5)(4.0), 2.5);
+/// ```
+pub fn sub<T>(n: T) -> impl Fn(T) -> T::Output
+where
+    T: Sub + Copy,
+{
+    move |x| x - n
+}
+
+/// Creates a function which multiplies its argument by `n`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::mul;
+/// assert_eq!(mul(3)(7), 21);
+/// ```
+pub fn mul<T>(n: T) -> impl Fn(T) -> T::Output
+where
+    T: Mul + Copy,
+{
+    move |x| x * n
+}
+
+/// Creates a function which divides its argument by `n`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::div;
+/// assert_eq!(div(2)(7), 3);
+/// ```
+pub fn div<T>(n: T) -> impl Fn(T) -> T::Output
+where
+    T: Div + Copy,
+{
+    move |x| x / n
+}
+
+/// Creates a function which computes the remainder of dividing its argument by `n`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::modulo;
+/// let hours: Vec<u32> = vec![22, 25, 47].into_iter().map(modulo(24u32)).collect();
+/// assert_eq!(hours, vec![22, 1, 23]);
+/// ```
+pub fn modulo<T>(n: T) -> impl Fn(T) -> T::Output
+where
+    T: Rem + Copy,
+{
+    move |x| x % n
+}
+
+/// Negates a number.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::negate;
+/// let flipped: Vec<i32> = vec![1, -2].into_iter().map(negate).collect();
+/// assert_eq!(flipped, vec![-1, 2]);
+/// ```
+pub fn negate<T>(x: T) -> T::Output
+where
+    T: Neg,
+{
+    -x
+}
+
+/* RANGES *********************************************************************/
+
+/// Creates a function which limits its argument to the range from `lo` to `hi`, inclusive.
+///
+/// Unlike [Ord::clamp](core::cmp::Ord::clamp), this only requires `PartialOrd`, so it works
+/// on floating point numbers too.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::clamp;
+/// let unit = clamp(0.0, 1.0);
+/// assert_eq!(unit(1.5), 1.0);
+/// assert_eq!(unit(-0.5), 0.0);
+/// assert_eq!(unit(0.25), 0.25);
+/// ```
+pub fn clamp<T>(lo: T, hi: T) -> impl Fn(T) -> T
+where
+    T: PartialOrd + Copy,
+{
+    move |x| {
+        if x < lo {
+            lo
+        } else if x > hi {
+            hi
+        } else {
+            x
+        }
+    }
+}
+
+/// Creates a predicate which checks whether its argument is in the range from `lo` to `hi`,
+/// inclusive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::math::between;
+/// let teens: Vec<u32> = (10..22).filter(between(13, 19)).collect();
+/// assert_eq!(teens, vec![13, 14, 15, 16, 17, 18, 19]);
+/// ```
+pub fn between<T>(lo: T, hi: T) -> impl Fn(&T) -> bool
+where
+    T: PartialOrd,
+{
+    move |x| lo <= *x && *x <= hi
+}
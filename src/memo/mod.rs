@@ -0,0 +1,105 @@
+//! Functions which remember their results, and can report on how often they did.
+//!
+//! For plain closures which cache their results, see [memoize](crate::function::memoize) and
+//! its relatives.
+
+/* MEMOIZED FUNCTIONS *********************************************************/
+use core::cell::{Cell, RefCell};
+use core::fmt::{self, Formatter};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// A function which caches its results, and reports on the use of its cache. Unlike the
+/// closures returned by [memoize_by](crate::function::memoize_by), the cache can be inspected
+/// and cleared.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::memo::Memoized;
+/// let square = Memoized::new(|x: u64| x * x);
+/// assert_eq!(square.call(3), 9);
+/// assert_eq!(square.call(3), 9);
+/// assert_eq!(square.call(4), 16);
+/// assert_eq!((square.hits(), square.misses()), (1, 2));
+/// assert_eq!(square.cache_len(), 2);
+///
+/// let squares: Vec<u64> = vec![3, 5].into_iter().map(square.as_fn()).collect();
+/// assert_eq!(squares, vec![9, 25]);
+///
+/// square.clear();
+/// assert_eq!(square.cache_len(), 0);
+/// ```
+pub struct Memoized<A, B, F> {
+    f: F,
+    cache: RefCell<HashMap<A, B>>,
+    hits: Cell<u64>,
+    misses: Cell<u64>,
+}
+
+impl<A, B, F> Memoized<A, B, F>
+where
+    A: Eq + Hash + Clone,
+    B: Clone,
+    F: Fn(A) -> B,
+{
+    /// Wraps a function with an empty cache.
+    pub fn new(f: F) -> Self {
+        Memoized {
+            f,
+            cache: RefCell::new(HashMap::new()),
+            hits: Cell::new(0),
+            misses: Cell::new(0),
+        }
+    }
+
+    /// Calls the function, or returns the cached result if it has been called with the same
+    /// argument before.
+    pub fn call(&self, a: A) -> B {
+        if let Some(b) = self.cache.borrow().get(&a) {
+            self.hits.set(self.hits.get() + 1);
+            return B::clone(b);
+        }
+        self.misses.set(self.misses.get() + 1);
+        let b = (self.f)(a.clone());
+        self.cache.borrow_mut().insert(a, b.clone());
+        b
+    }
+
+    /// Borrows this as a plain function, for use with `Iterator::map` and the like.
+    pub fn as_fn(&self) -> impl Fn(A) -> B + '_ {
+        move |a| self.call(a)
+    }
+
+    /// The number of results in the cache.
+    pub fn cache_len(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// The number of calls which were answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.get()
+    }
+
+    /// The number of calls which had to call the function.
+    pub fn misses(&self) -> u64 {
+        self.misses.get()
+    }
+
+    /// Empties the cache, and resets the counters.
+    pub fn clear(&self) {
+        self.cache.borrow_mut().clear();
+        self.hits.set(0);
+        self.misses.set(0);
+    }
+}
+
+impl<A, B, F> fmt::Debug for Memoized<A, B, F> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Memoized")
+            .field("cache_len", &self.cache.borrow().len())
+            .field("hits", &self.hits.get())
+            .field("misses", &self.misses.get())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,401 @@
+//! Wrappers which give common types a [Monoid](fp_core::monoid::Monoid) instance, and
+//! functions for combining values of any monoid.
+//!
+//! Since fp-core's traits cannot be implemented here for types such as `Option` and tuples,
+//! the wrappers in this module stand in for them. fp-core does make `String` and `Vec` into
+//! monoids, but in its version 0.1 their empty values recurse forever, so
+//! [Concat](crate::monoid::Concat) provides them instead.
+//!
+//! The `Default` value of each wrapper is its empty value, so that generic code which starts a
+//! fold from `Default::default()` still finds the identity.
+//!
+//! ```rust
+//! # use fp_std::monoid::{fold_map, Max, Pair, Sum};
+//! let orders = vec![("tea", 3), ("cake", 5), ("bun", 2)];
+//! let Pair(Sum(total), Max(largest)) = fold_map(|(_, n)| Pair(Sum(n), Max(n)), orders);
+//! assert_eq!(total, 10);
+//! assert_eq!(largest, 5);
+//! ```
+
+/* FUNCTIONS ******************************************************************/
+use fp_core::empty::Empty;
+use fp_core::monoid::Monoid;
+use fp_core::semigroup::Semigroup;
+
+/// Combines every item, in order, starting from the empty value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{concat, Sum};
+/// assert_eq!(concat(vec![Sum(2), Sum(3)]), Sum(5));
+/// assert_eq!(concat(Vec::<Sum<u8>>::new()), Sum(0));
+/// ```
+pub fn concat<I, M>(iter: I) -> M
+where
+    I: IntoIterator<Item = M>,
+    M: Monoid,
+{
+    iter.into_iter().fold(M::empty(), M::combine)
+}
+
+/// Maps every item into a monoid, and combines the results, in order, starting from the empty
+/// value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{fold_map, Product};
+/// let Product(volume) = fold_map(Product, vec![2, 3, 4]);
+/// assert_eq!(volume, 24);
+/// ```
+pub fn fold_map<I, M, F>(f: F, iter: I) -> M
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> M,
+    M: Monoid,
+{
+    iter.into_iter().map(f).fold(M::empty(), M::combine)
+}
+
+/* NUMBERS ********************************************************************/
+
+/// The monoid of numbers under addition, whose empty value is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{concat, Sum};
+/// assert_eq!(concat(vec![Sum(1.5), Sum(2.0)]), Sum(3.5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<T>(pub T);
+
+/// The monoid of numbers under multiplication, whose empty value is one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{concat, Product};
+/// assert_eq!(concat(vec![Product(2u8), Product(5)]), Product(10));
+/// assert_eq!(concat(Vec::<Product<i64>>::new()), Product(1));
+/// assert_eq!(Product::<u32>::default(), Product(1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Product<T>(pub T);
+
+macro_rules! numeric_monoid_impls {
+    ($zero:literal $one:literal: $($t:ty)*) => ($(
+        impl Semigroup for Sum<$t> {
+            fn combine(self, other: Self) -> Self {
+                Sum(self.0 + other.0)
+            }
+        }
+
+        impl Empty for Sum<$t> {
+            fn empty() -> Self {
+                Sum($zero)
+            }
+        }
+
+        impl Monoid for Sum<$t> {}
+
+        impl Default for Sum<$t> {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        impl Semigroup for Product<$t> {
+            fn combine(self, other: Self) -> Self {
+                Product(self.0 * other.0)
+            }
+        }
+
+        impl Empty for Product<$t> {
+            fn empty() -> Self {
+                Product($one)
+            }
+        }
+
+        impl Monoid for Product<$t> {}
+
+        impl Default for Product<$t> {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+    )*)
+}
+
+numeric_monoid_impls! { 0 1: usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+numeric_monoid_impls! { 0.0 1.0: f32 f64 }
+
+/* SEQUENCES ******************************************************************/
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The monoid of strings or lists under concatenation, whose empty value is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{concat, fold_map, Concat};
+/// let Concat(csv) = fold_map(|n: u32| Concat(format!("{},", n)), vec![1, 2]);
+/// assert_eq!(csv, "1,2,");
+/// assert_eq!(concat(Vec::<Concat<Vec<u8>>>::new()), Concat(vec![]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Concat<T>(pub T);
+
+impl Semigroup for Concat<String> {
+    fn combine(mut self, other: Self) -> Self {
+        self.0.push_str(&other.0);
+        self
+    }
+}
+
+impl Empty for Concat<String> {
+    fn empty() -> Self {
+        Concat(String::new())
+    }
+}
+
+impl Monoid for Concat<String> {}
+
+impl Default for Concat<String> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> Semigroup for Concat<Vec<T>> {
+    fn combine(mut self, mut other: Self) -> Self {
+        self.0.append(&mut other.0);
+        self
+    }
+}
+
+impl<T> Empty for Concat<Vec<T>> {
+    fn empty() -> Self {
+        Concat(Vec::new())
+    }
+}
+
+impl<T> Monoid for Concat<Vec<T>> {}
+
+impl<T> Default for Concat<Vec<T>> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/* ORDERINGS ******************************************************************/
+use crate::bounded::Bounded;
+
+/// The monoid of values under taking the greatest, whose empty value is the least value of
+/// the type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{fold_map, Max};
+/// assert_eq!(fold_map(Max, vec!['b', 'z', 'a']), Max('z'));
+/// assert_eq!(fold_map(Max, Vec::<u8>::new()), Max(0));
+/// assert_eq!(Max::<i32>::default(), Max(i32::MIN));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<T>(pub T);
+
+impl<T: Ord> Semigroup for Max<T> {
+    fn combine(self, other: Self) -> Self {
+        Max(self.0.max(other.0))
+    }
+}
+
+impl<T: Bounded> Empty for Max<T> {
+    fn empty() -> Self {
+        Max(T::min_value())
+    }
+}
+
+impl<T: Ord + Bounded> Monoid for Max<T> {}
+
+impl<T: Bounded> Default for Max<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// The monoid of values under taking the least, whose empty value is the greatest value of
+/// the type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{fold_map, Min};
+/// assert_eq!(fold_map(Min, vec![3i8, -2, 7]), Min(-2));
+/// assert_eq!(fold_map(Min, Vec::<i8>::new()), Min(127));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<T>(pub T);
+
+impl<T: Ord> Semigroup for Min<T> {
+    fn combine(self, other: Self) -> Self {
+        Min(self.0.min(other.0))
+    }
+}
+
+impl<T: Bounded> Empty for Min<T> {
+    fn empty() -> Self {
+        Min(T::max_value())
+    }
+}
+
+impl<T: Ord + Bounded> Monoid for Min<T> {}
+
+impl<T: Bounded> Default for Min<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/* OPTIONS ********************************************************************/
+
+/// The monoid of optional values which keeps the first value which is present.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{fold_map, First};
+/// let config = vec![None, Some("env"), Some("file")];
+/// assert_eq!(fold_map(First, config), First(Some("env")));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct First<T>(pub Option<T>);
+
+impl<T> Semigroup for First<T> {
+    fn combine(self, other: Self) -> Self {
+        First(self.0.or(other.0))
+    }
+}
+
+impl<T> Empty for First<T> {
+    fn empty() -> Self {
+        First(None)
+    }
+}
+
+impl<T> Monoid for First<T> {}
+
+impl<T> Default for First<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// The monoid of optional values which keeps the last value which is present.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{fold_map, Last};
+/// let overrides = vec![Some(1), Some(2), None];
+/// assert_eq!(fold_map(Last, overrides), Last(Some(2)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Last<T>(pub Option<T>);
+
+impl<T> Semigroup for Last<T> {
+    fn combine(self, other: Self) -> Self {
+        Last(other.0.or(self.0))
+    }
+}
+
+impl<T> Empty for Last<T> {
+    fn empty() -> Self {
+        Last(None)
+    }
+}
+
+impl<T> Monoid for Last<T> {}
+
+impl<T> Default for Last<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// The monoid of optional values of a semigroup, which combines the values which are present,
+/// and whose empty value is `None`. This makes a monoid of any semigroup.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{fold_map, Maybe, Max};
+/// let highest = fold_map(|n: Option<u32>| Maybe(n.map(Max)), vec![Some(3), None, Some(9)]);
+/// assert_eq!(highest, Maybe(Some(Max(9))));
+/// assert_eq!(fold_map(|n: Option<u32>| Maybe(n.map(Max)), vec![None]), Maybe(None));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Maybe<T>(pub Option<T>);
+
+impl<T: Semigroup> Semigroup for Maybe<T> {
+    fn combine(self, other: Self) -> Self {
+        match (self.0, other.0) {
+            (Some(a), Some(b)) => Maybe(Some(a.combine(b))),
+            (a, b) => Maybe(a.or(b)),
+        }
+    }
+}
+
+impl<T> Empty for Maybe<T> {
+    fn empty() -> Self {
+        Maybe(None)
+    }
+}
+
+impl<T: Semigroup> Monoid for Maybe<T> {}
+
+impl<T> Default for Maybe<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/* TUPLES *********************************************************************/
+
+/// The monoid of pairs of monoids, which combines each element separately.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::monoid::{fold_map, Concat, Pair, Sum};
+/// let words = vec!["to", "be", "or"];
+/// let Pair(Sum(count), Concat(letters)) =
+///     fold_map(|w: &str| Pair(Sum(1), Concat(w.to_owned())), words);
+/// assert_eq!(count, 3);
+/// assert_eq!(letters, "tobeor");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pair<A, B>(pub A, pub B);
+
+impl<A: Semigroup, B: Semigroup> Semigroup for Pair<A, B> {
+    fn combine(self, other: Self) -> Self {
+        Pair(self.0.combine(other.0), self.1.combine(other.1))
+    }
+}
+
+impl<A: Empty, B: Empty> Empty for Pair<A, B> {
+    fn empty() -> Self {
+        Pair(A::empty(), B::empty())
+    }
+}
+
+impl<A: Monoid, B: Monoid> Monoid for Pair<A, B> {}
+
+impl<A: Empty, B: Empty> Default for Pair<A, B> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
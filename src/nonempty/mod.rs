@@ -0,0 +1,249 @@
+//! Lists which always contain at least one item, so that taking their first or last item
+//! cannot fail.
+
+/* NON-EMPTY LISTS ************************************************************/
+use crate::error::Error;
+use alloc::vec::{self, Vec};
+use core::convert::TryFrom;
+use core::iter::{self, Chain, Once};
+use core::slice;
+
+/// A list with at least one item: a `head`, followed by any number of items in its `tail`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::nonempty::NonEmpty;
+/// let mut list = NonEmpty::new(1, vec![2, 3]);
+/// list.push(4);
+/// assert_eq!(list.first(), &1);
+/// assert_eq!(list.last(), &4);
+/// assert_eq!(list.len(), 4);
+/// assert_eq!(list.into_vec(), vec![1, 2, 3, 4]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmpty<T> {
+    /// The first item.
+    pub head: T,
+    /// The items after the first.
+    pub tail: Vec<T>,
+}
+
+impl<T> NonEmpty<T> {
+    /// Creates a list from its first item and the items after it.
+    pub const fn new(head: T, tail: Vec<T>) -> Self {
+        NonEmpty { head, tail }
+    }
+
+    /// Creates a list of one item.
+    pub const fn singleton(head: T) -> Self {
+        NonEmpty::new(head, Vec::new())
+    }
+
+    /// Converts a `Vec` into a list, failing with [Error::Empty](crate::error::Error::Empty) if
+    /// it has no items. This is also available as `TryFrom<Vec<T>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::error::Error;
+    /// # use fp_std::nonempty::NonEmpty;
+    /// assert_eq!(NonEmpty::from_vec(vec![1, 2]), Ok(NonEmpty::new(1, vec![2])));
+    /// assert_eq!(NonEmpty::<u32>::from_vec(vec![]), Err(Error::Empty));
+    /// ```
+    pub fn from_vec(vec: Vec<T>) -> Result<Self, Error> {
+        let mut items = vec.into_iter();
+        let head = items.next().ok_or(Error::Empty)?;
+        Ok(NonEmpty::new(head, items.collect()))
+    }
+
+    /// Returns the number of items in the list, which is never zero.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    /// Borrows the first item.
+    pub fn first(&self) -> &T {
+        &self.head
+    }
+
+    /// Borrows the last item.
+    pub fn last(&self) -> &T {
+        self.tail.last().unwrap_or(&self.head)
+    }
+
+    /// Adds an item to the end of the list.
+    pub fn push(&mut self, item: T) {
+        self.tail.push(item);
+    }
+
+    /// Iterates over the items, in order.
+    pub fn iter(&self) -> Chain<Once<&T>, slice::Iter<'_, T>> {
+        iter::once(&self.head).chain(self.tail.iter())
+    }
+
+    /// Transforms every item, keeping them in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::nonempty::NonEmpty;
+    /// let lengths = NonEmpty::new("a", vec!["bc"]).map(str::len);
+    /// assert_eq!(lengths, NonEmpty::new(1, vec![2]));
+    /// ```
+    pub fn map<U, F>(self, mut f: F) -> NonEmpty<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        let head = f(self.head);
+        NonEmpty::new(head, self.tail.into_iter().map(f).collect())
+    }
+
+    /// Applies an optional function to every item, succeeding only if it does for all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::nonempty::NonEmpty;
+    /// let parse = |s: &str| s.parse::<u32>().ok();
+    /// assert_eq!(NonEmpty::new("1", vec!["2"]).traverse(parse), Some(NonEmpty::new(1, vec![2])));
+    /// assert_eq!(NonEmpty::new("1", vec!["x"]).traverse(parse), None);
+    /// ```
+    pub fn traverse<U, F>(self, mut f: F) -> Option<NonEmpty<U>>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        let head = f(self.head)?;
+        let tail = self.tail.into_iter().map(f).collect::<Option<_>>()?;
+        Some(NonEmpty::new(head, tail))
+    }
+
+    /// Applies a fallible function to every item, succeeding only if it does for all of them.
+    /// Otherwise, the first error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::nonempty::NonEmpty;
+    /// let parse = |s: &str| s.parse::<u32>();
+    /// let parsed = NonEmpty::new("1", vec!["2"]).try_traverse(parse);
+    /// assert_eq!(parsed, Ok(NonEmpty::new(1, vec![2])));
+    /// assert!(NonEmpty::new("1", vec!["x"]).try_traverse(parse).is_err());
+    /// ```
+    pub fn try_traverse<U, E, F>(self, mut f: F) -> Result<NonEmpty<U>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let head = f(self.head)?;
+        let tail = self.tail.into_iter().map(f).collect::<Result<_, _>>()?;
+        Ok(NonEmpty::new(head, tail))
+    }
+
+    /// Converts the list into a `Vec` of its items, in order.
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len());
+        vec.push(self.head);
+        vec.extend(self.tail);
+        vec
+    }
+}
+
+impl<T> From<NonEmpty<T>> for Vec<T> {
+    fn from(list: NonEmpty<T>) -> Self {
+        list.into_vec()
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmpty<T> {
+    type Error = Error;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Error> {
+        NonEmpty::from_vec(vec)
+    }
+}
+
+impl<T> Extend<T> for NonEmpty<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.tail.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for NonEmpty<T> {
+    type Item = T;
+    type IntoIter = Chain<Once<T>, vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.head).chain(self.tail)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmpty<T> {
+    type Item = &'a T;
+    type IntoIter = Chain<Once<&'a T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/* INSTANCES ******************************************************************/
+#[cfg(feature = "std")]
+use fp_core::foldable::Foldable;
+#[cfg(feature = "std")]
+use fp_core::hkt::HKT;
+#[cfg(feature = "std")]
+use fp_core::semigroup::Semigroup;
+
+/// Combines lists by appending them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::nonempty::NonEmpty;
+/// use fp_core::semigroup::Semigroup;
+/// let combined = NonEmpty::new(1, vec![2]).combine(NonEmpty::singleton(3));
+/// assert_eq!(combined, NonEmpty::new(1, vec![2, 3]));
+/// ```
+#[cfg(feature = "std")]
+impl<T> Semigroup for NonEmpty<T> {
+    fn combine(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, U> HKT<U> for NonEmpty<T> {
+    type Current = T;
+    type Target = NonEmpty<U>;
+}
+
+/// Folds over the items in order, or in reverse order for `reduce_right`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::nonempty::NonEmpty;
+/// use fp_core::foldable::{fold_map, Foldable};
+/// let total: i64 = fold_map(NonEmpty::new(1, vec![2, 3]), |x| *x);
+/// assert_eq!(total, 6);
+/// let digits = NonEmpty::new(1, vec![2, 3]).reduce_right(0, |d, acc| acc * 10 + d);
+/// assert_eq!(digits, 321);
+/// ```
+#[cfg(feature = "std")]
+impl<T, B> Foldable<B> for NonEmpty<T> {
+    fn reduce<F>(self, b: B, f: F) -> B
+    where
+        F: Fn(B, &T) -> B,
+    {
+        self.iter().fold(b, f)
+    }
+
+    fn reduce_right<F>(self, b: B, f: F) -> B
+    where
+        F: Fn(&T, B) -> B,
+    {
+        self.iter().rev().fold(b, |b, t| f(t, b))
+    }
+}
@@ -0,0 +1,245 @@
+//! Functions for working with optional values.
+
+/* FALLBACKS ******************************************************************/
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+
+/// Evaluates each expression in turn, returning the first which is `Some`. Expressions after
+/// that one are not evaluated. If none of them are `Some`, the result is `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::first_some;
+/// # use std::collections::HashMap;
+/// let flags: HashMap<&str, &str> = vec![("port", "8080")].into_iter().collect();
+/// let config: HashMap<&str, &str> = vec![("port", "80"), ("host", "example.com")]
+///     .into_iter()
+///     .collect();
+/// let setting = |key| first_some!(flags.get(key), config.get(key), None);
+/// assert_eq!(setting("port"), Some(&"8080"));
+/// assert_eq!(setting("host"), Some(&"example.com"));
+/// assert_eq!(setting("user"), None);
+///
+/// fn unreachable() -> Option<u32> { panic!() }
+/// assert_eq!(first_some!(None, Some(1), unreachable()), Some(1));
+/// ```
+#[macro_export]
+macro_rules! first_some {
+    () => {
+        ::core::option::Option::None
+    };
+    ($first:expr $(, $rest:expr)* $(,)?) => {
+        match $first {
+            ::core::option::Option::Some(value) => ::core::option::Option::Some(value),
+            ::core::option::Option::None => $crate::first_some!($($rest),*),
+        }
+    };
+}
+
+/// Creates a function which takes the value out of an `Option`, or a copy of `default` if
+/// there is none, so that a pipeline which produces an `Option` can end in a plain value. This
+/// is the same as [unwrap_or](crate::option::unwrap_or).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose2;
+/// # use fp_std::option::default_to;
+/// let port = compose2(default_to(80), |s: &str| s.parse::<u16>().ok());
+/// assert_eq!(port("8080"), 8080);
+/// assert_eq!(port("http"), 80);
+/// ```
+pub fn default_to<A: Clone>(default: A) -> impl Fn(Option<A>) -> A {
+    unwrap_or(default)
+}
+
+/// Creates a function which transforms the value in an `Option`, or produces a copy of
+/// `default` if there is none. This is Haskell's `maybe`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option::from_option;
+/// let greeting = from_option("hello, stranger".to_owned(), |name: &str| {
+///     format!("hello, {}", name)
+/// });
+/// assert_eq!(greeting(Some("ann")), "hello, ann");
+/// assert_eq!(greeting(None), "hello, stranger");
+/// ```
+pub fn from_option<A, B, F>(default: B, f: F) -> impl Fn(Option<A>) -> B
+where
+    B: Clone,
+    F: Fn(A) -> B,
+{
+    move |option| match option {
+        Some(value) => f(value),
+        None => default.clone(),
+    }
+}
+
+/// Creates a function which copies the part of a structure focused on by a lens, or produces
+/// a copy of `default` if the lens focuses on nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::lens::LensIndex;
+/// # use fp_std::option::view_or;
+/// let third_or_zero = view_or(LensIndex::<2>, 0);
+/// assert_eq!(third_or_zero(&vec![4, 5, 6]), 6);
+/// assert_eq!(third_or_zero(&vec![4, 5]), 0);
+/// ```
+#[cfg(feature = "std")]
+pub fn view_or<L, S, A>(_lens: L, default: A) -> impl Fn(&S) -> A
+where
+    L: Lens<S, A>,
+    A: Clone,
+{
+    move |s| L::get(s).cloned().unwrap_or_else(|| default.clone())
+}
+
+/* COMBINATORS ****************************************************************/
+
+/// Creates a function which transforms the value in an `Option`, if there is one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option;
+/// let lengths: Vec<_> = vec![Some("abc"), None].into_iter().map(option::map(str::len)).collect();
+/// assert_eq!(lengths, vec![Some(3), None]);
+/// ```
+pub fn map<A, B, F>(f: F) -> impl Fn(Option<A>) -> Option<B>
+where
+    F: Fn(A) -> B,
+{
+    move |option| option.map(&f)
+}
+
+/// Creates a function which replaces the value in an `Option`, if there is one, with the
+/// result of an optional function of it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option;
+/// let parse = option::and_then(|s: &str| s.parse::<u32>().ok());
+/// assert_eq!(parse(Some("12")), Some(12));
+/// assert_eq!(parse(Some("x")), None);
+/// assert_eq!(parse(None), None);
+/// ```
+pub fn and_then<A, B, F>(f: F) -> impl Fn(Option<A>) -> Option<B>
+where
+    F: Fn(A) -> Option<B>,
+{
+    move |option| option.and_then(&f)
+}
+
+/// Creates a function which takes the value out of an `Option`, or a copy of `default` if
+/// there is none.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option;
+/// let or_zero = option::unwrap_or(0);
+/// assert_eq!(or_zero(Some(3)), 3);
+/// assert_eq!(or_zero(None), 0);
+/// ```
+pub fn unwrap_or<A: Clone>(default: A) -> impl Fn(Option<A>) -> A {
+    move |option| option.unwrap_or_else(|| default.clone())
+}
+
+/// Creates a function which pairs the value in an `Option` with a copy of the value in `other`,
+/// if both of them have one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option;
+/// let with_unit = option::zip(Some("kg"));
+/// assert_eq!(with_unit(Some(5)), Some((5, "kg")));
+/// assert_eq!(with_unit(None::<u32>), None);
+/// assert_eq!(option::zip(None::<&str>)(Some(5)), None);
+/// ```
+pub fn zip<A, B: Clone>(other: Option<B>) -> impl Fn(Option<A>) -> Option<(A, B)> {
+    move |option| option.zip(other.clone())
+}
+
+/// Lifts a function of two values into a function of two `Option`s, which applies it if both
+/// of them have a value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option;
+/// let add = option::lift2(|a: u32, b: u32| a + b);
+/// assert_eq!(add(Some(1), Some(2)), Some(3));
+/// assert_eq!(add(Some(1), None), None);
+/// ```
+pub fn lift2<A, B, C, F>(f: F) -> impl Fn(Option<A>, Option<B>) -> Option<C>
+where
+    F: Fn(A, B) -> C,
+{
+    move |a, b| Some(f(a?, b?))
+}
+
+/// Lifts a function of three values into a function of three `Option`s, which applies it if
+/// all of them have a value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option;
+/// let volume = option::lift3(|w: u32, h: u32, d: u32| w * h * d);
+/// assert_eq!(volume(Some(2), Some(3), Some(4)), Some(24));
+/// assert_eq!(volume(Some(2), None, Some(4)), None);
+/// ```
+pub fn lift3<A, B, C, D, F>(f: F) -> impl Fn(Option<A>, Option<B>, Option<C>) -> Option<D>
+where
+    F: Fn(A, B, C) -> D,
+{
+    move |a, b, c| Some(f(a?, b?, c?))
+}
+
+/* COLLECTIONS ****************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Collects the values of some `Option`s into a `Vec`, if every one of them has a value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option::sequence;
+/// assert_eq!(sequence(vec![Some(1), Some(2)]), Some(vec![1, 2]));
+/// assert_eq!(sequence(vec![Some(1), None]), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sequence<I, T>(options: I) -> Option<Vec<T>>
+where
+    I: IntoIterator<Item = Option<T>>,
+{
+    options.into_iter().collect()
+}
+
+/// Creates a function which applies an optional function to every item, collecting the values
+/// into a `Vec` if it produced one for every item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::option;
+/// let parse_all = option::traverse(|s: &str| s.parse::<u32>().ok());
+/// assert_eq!(parse_all(vec!["1", "2"]), Some(vec![1, 2]));
+/// assert_eq!(parse_all(vec!["1", "x"]), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn traverse<I, A, B, F>(f: F) -> impl Fn(I) -> Option<Vec<B>>
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> Option<B>,
+{
+    move |iter| iter.into_iter().map(&f).collect()
+}
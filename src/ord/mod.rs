@@ -0,0 +1,393 @@
+//! Functions for comparing and ordering values.
+
+/* ORDERING FUNCTIONS *********************************************************/
+use core::cmp::Ordering;
+
+/// Creates a comparison function which orders values by a key computed from each of them, for
+/// use with `slice::sort_by` and the like.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::comparing;
+/// let mut words = vec!["ccc", "a", "bb"];
+/// words.sort_by(comparing(|s: &&str| s.len()));
+/// assert_eq!(words, vec!["a", "bb", "ccc"]);
+/// ```
+pub fn comparing<A, K, F>(key_fn: F) -> impl Fn(&A, &A) -> Ordering
+where
+    K: Ord,
+    F: Fn(&A) -> K,
+{
+    move |a, b| key_fn(a).cmp(&key_fn(b))
+}
+
+/// Creates a comparison function which compares values by comparing the results of `f` on
+/// each of them with `cmp`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::on;
+/// let by_name = on(|user: &(u32, &str)| user.1, |a: &&str, b: &&str| a.cmp(b).reverse());
+/// let mut users = vec![(1, "ann"), (2, "cy"), (3, "bo")];
+/// users.sort_by(by_name);
+/// assert_eq!(users, vec![(2, "cy"), (3, "bo"), (1, "ann")]);
+/// ```
+pub fn on<A, B, F, C>(f: F, cmp: C) -> impl Fn(&A, &A) -> Ordering
+where
+    F: Fn(&A) -> B,
+    C: Fn(&B, &B) -> Ordering,
+{
+    move |a, b| cmp(&f(a), &f(b))
+}
+
+/// Creates a comparison function which orders values by a key, in ascending order. This is
+/// the same as [comparing](crate::ord::comparing), and reads well alongside
+/// [descend](crate::ord::descend).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::ascend;
+/// let mut prices = vec![("tea", 3), ("cake", 5), ("bun", 2)];
+/// prices.sort_by(ascend(|item: &(&str, u32)| item.1));
+/// assert_eq!(prices, vec![("bun", 2), ("tea", 3), ("cake", 5)]);
+/// ```
+pub fn ascend<A, K, F>(key_fn: F) -> impl Fn(&A, &A) -> Ordering
+where
+    K: Ord,
+    F: Fn(&A) -> K,
+{
+    comparing(key_fn)
+}
+
+/// Creates a comparison function which orders values by a key, in descending order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::descend;
+/// let mut prices = vec![("tea", 3), ("cake", 5), ("bun", 2)];
+/// prices.sort_by(descend(|item: &(&str, u32)| item.1));
+/// assert_eq!(prices, vec![("cake", 5), ("tea", 3), ("bun", 2)]);
+/// ```
+pub fn descend<A, K, F>(key_fn: F) -> impl Fn(&A, &A) -> Ordering
+where
+    K: Ord,
+    F: Fn(&A) -> K,
+{
+    move |a, b| key_fn(b).cmp(&key_fn(a))
+}
+
+/// Creates a function which returns the greater of two values according to a comparison
+/// function, or the second if they are equal, as with `core::cmp::max_by`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::{comparing, max_by_fn};
+/// let longest = vec!["ab", "abc", "xyz"]
+///     .into_iter()
+///     .reduce(max_by_fn(comparing(|s: &&str| s.len())));
+/// assert_eq!(longest, Some("xyz"));
+/// ```
+pub fn max_by_fn<A, C>(cmp: C) -> impl Fn(A, A) -> A
+where
+    C: Fn(&A, &A) -> Ordering,
+{
+    move |a, b| match cmp(&a, &b) {
+        Ordering::Greater => a,
+        _ => b,
+    }
+}
+
+/// Creates a function which returns the lesser of two values according to a comparison
+/// function, or the first if they are equal, as with `core::cmp::min_by`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::{comparing, min_by_fn};
+/// let shortest = vec!["ab", "abc", "xy"]
+///     .into_iter()
+///     .reduce(min_by_fn(comparing(|s: &&str| s.len())));
+/// assert_eq!(shortest, Some("ab"));
+/// ```
+pub fn min_by_fn<A, C>(cmp: C) -> impl Fn(A, A) -> A
+where
+    C: Fn(&A, &A) -> Ordering,
+{
+    move |a, b| match cmp(&a, &b) {
+        Ordering::Greater => b,
+        _ => a,
+    }
+}
+
+/* COMPARATORS ****************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::fmt::{self, Debug, Formatter};
+
+/// A boxed comparison between two values of type `A`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::Comparator;
+/// struct Player { name: &'static str, score: u32 }
+///
+/// let leaderboard = Comparator::comparing(|player: &Player| player.score)
+///     .reversed()
+///     .then(Comparator::comparing(|player: &Player| player.name));
+///
+/// let mut players = vec![
+///     Player { name: "cy", score: 3 },
+///     Player { name: "bo", score: 5 },
+///     Player { name: "ann", score: 3 },
+/// ];
+/// leaderboard.sort(&mut players);
+/// let names: Vec<&str> = players.iter().map(|player| player.name).collect();
+/// assert_eq!(names, vec!["bo", "ann", "cy"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct Comparator<A> {
+    compare: Box<Compare<A>>,
+}
+
+#[cfg(feature = "alloc")]
+type Compare<A> = dyn Fn(&A, &A) -> Ordering;
+
+#[cfg(feature = "alloc")]
+impl<A: 'static> Comparator<A> {
+    /// Creates a comparator from a function.
+    pub fn new<F>(compare: F) -> Self
+    where
+        F: Fn(&A, &A) -> Ordering + 'static,
+    {
+        Comparator {
+            compare: Box::new(compare),
+        }
+    }
+
+    /// Creates a comparator which uses the natural ordering of `A`.
+    pub fn natural() -> Self
+    where
+        A: Ord,
+    {
+        Comparator::new(A::cmp)
+    }
+
+    /// Creates a comparator which orders values by a key computed from each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::ord::Comparator;
+    /// let by_length = Comparator::comparing(|s: &&str| s.len());
+    /// let mut words = vec!["ccc", "a", "bb"];
+    /// by_length.sort(&mut words);
+    /// assert_eq!(words, vec!["a", "bb", "ccc"]);
+    /// ```
+    pub fn comparing<K, F>(key_fn: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&A) -> K + 'static,
+    {
+        Comparator::new(move |a, b| key_fn(a).cmp(&key_fn(b)))
+    }
+
+    /// Compares two values.
+    pub fn compare(&self, a: &A, b: &A) -> Ordering {
+        (self.compare)(a, b)
+    }
+
+    /// Combines two comparators into one which uses the second to break ties in the first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::ord::Comparator;
+    /// # use std::cmp::Ordering;
+    /// let by_length = Comparator::comparing(|s: &&str| s.len()).then(Comparator::natural());
+    /// assert_eq!(by_length.compare(&"ab", &"b"), Ordering::Greater);
+    /// assert_eq!(by_length.compare(&"ab", &"ba"), Ordering::Less);
+    /// ```
+    pub fn then(self, other: Self) -> Self {
+        Comparator::new(move |a, b| self.compare(a, b).then_with(|| other.compare(a, b)))
+    }
+
+    /// Reverses the order of this comparator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::ord::Comparator;
+    /// let descending = Comparator::<u32>::natural().reversed();
+    /// let mut numbers = vec![1, 3, 2];
+    /// descending.sort(&mut numbers);
+    /// assert_eq!(numbers, vec![3, 2, 1]);
+    /// ```
+    pub fn reversed(self) -> Self {
+        Comparator::new(move |a, b| self.compare(b, a))
+    }
+
+    /// Adapts this comparator to compare values of another type, by first converting them with
+    /// `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::ord::Comparator;
+    /// # use std::cmp::Ordering;
+    /// let by_length = Comparator::<usize>::natural().contramap(|s: &String| s.len());
+    /// assert_eq!(by_length.compare(&"ab".to_owned(), &"c".to_owned()), Ordering::Greater);
+    /// ```
+    pub fn contramap<B, F>(self, f: F) -> Comparator<B>
+    where
+        B: 'static,
+        F: Fn(&B) -> A + 'static,
+    {
+        Comparator::new(move |a, b| self.compare(&f(a), &f(b)))
+    }
+
+    /// Sorts a slice using this comparator. The sort is stable.
+    pub fn sort(&self, slice: &mut [A]) {
+        slice.sort_by(|a, b| self.compare(a, b))
+    }
+
+    /// Converts this comparator into a plain function, for use with `slice::sort_by` and the
+    /// like.
+    pub fn into_fn(self) -> impl Fn(&A, &A) -> Ordering {
+        move |a, b| self.compare(a, b)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: 'static, F> From<F> for Comparator<A>
+where
+    F: Fn(&A, &A) -> Ordering + 'static,
+{
+    fn from(compare: F) -> Self {
+        Comparator::new(compare)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A> Debug for Comparator<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Comparator(..)")
+    }
+}
+
+/* LENSES *********************************************************************/
+#[cfg(feature = "std")]
+use core::ops::Add;
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+
+/// Creates a comparator which orders values by the part of them focused on by a lens, in
+/// ascending order. Values which the lens does not focus on anything in come first.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::ascend_lens;
+/// # use fp_std::tuple::LensSecond;
+/// let mut scores = vec![("a", 3), ("b", 1), ("c", 2)];
+/// scores.sort_by(ascend_lens(LensSecond));
+/// assert_eq!(scores, vec![("b", 1), ("c", 2), ("a", 3)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn ascend_lens<L, S, A>(_lens: L) -> impl Fn(&S, &S) -> Ordering
+where
+    L: Lens<S, A>,
+    A: Ord,
+{
+    |a, b| L::get(a).cmp(&L::get(b))
+}
+
+/// Creates a comparator which orders values by the part of them focused on by a lens, in
+/// descending order. Values which the lens does not focus on anything in come last.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::descend_lens;
+/// # use fp_std::tuple::LensSecond;
+/// let mut scores = vec![("a", 3), ("b", 1), ("c", 2)];
+/// scores.sort_by(descend_lens(LensSecond));
+/// assert_eq!(scores, vec![("a", 3), ("c", 2), ("b", 1)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn descend_lens<L, S, A>(_lens: L) -> impl Fn(&S, &S) -> Ordering
+where
+    L: Lens<S, A>,
+    A: Ord,
+{
+    |a, b| L::get(b).cmp(&L::get(a))
+}
+
+/// Clamps the part of a structure focused on by a lens to lie between `lo` and `hi`,
+/// inclusive. If the lens focuses on nothing, the structure is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::clamp_lens;
+/// # use fp_std::tuple::LensSecond;
+/// assert_eq!(clamp_lens(LensSecond, 0.0, 1.0, &("alpha", 1.5)), ("alpha", 1.0));
+/// assert_eq!(clamp_lens(LensSecond, 0.0, 1.0, &("alpha", 0.5)), ("alpha", 0.5));
+/// ```
+#[cfg(feature = "std")]
+pub fn clamp_lens<L, S, A>(_lens: L, lo: A, hi: A, s: &S) -> S
+where
+    L: Lens<S, A>,
+    S: Clone,
+    A: PartialOrd + Clone,
+{
+    match L::get(s) {
+        Some(a) => L::set(clamp(a.clone(), lo, hi), s),
+        None => s.clone(),
+    }
+}
+
+/// Adds `delta` to the part of a structure focused on by a lens, clamping the result to lie
+/// between `lo` and `hi`, inclusive. If the lens focuses on nothing, the structure is returned
+/// unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::ord::over_clamped;
+/// # use fp_std::tuple::LensFirst;
+/// let player = (90, "ann");
+/// let healed = over_clamped(LensFirst, 0, 100, 25, &player);
+/// assert_eq!(healed, (100, "ann"));
+/// let hurt = over_clamped(LensFirst, 0, 100, -120, &player);
+/// assert_eq!(hurt, (0, "ann"));
+/// ```
+#[cfg(feature = "std")]
+pub fn over_clamped<L, S, A>(_lens: L, lo: A, hi: A, delta: A, s: &S) -> S
+where
+    L: Lens<S, A>,
+    S: Clone,
+    A: PartialOrd + Add<Output = A> + Clone,
+{
+    match L::get(s) {
+        Some(a) => L::set(clamp(a.clone() + delta, lo, hi), s),
+        None => s.clone(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn clamp<A: PartialOrd>(a: A, lo: A, hi: A) -> A {
+    if a < lo {
+        lo
+    } else if a > hi {
+        hi
+    } else {
+        a
+    }
+}
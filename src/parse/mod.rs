@@ -0,0 +1,264 @@
+//! Parser combinators, for building parsers of strings out of smaller parsers.
+//!
+//! ```rust
+//! # use fp_std::parse::{digits, literal, whitespace};
+//! let number = digits().map(|d| d.parse::<u32>().unwrap());
+//! let comma = whitespace().then(literal(",")).then(whitespace());
+//! let list = literal("[").then(number.sep_by(comma)).then(literal("]")).map(|((_, n), _)| n);
+//!
+//! assert_eq!(list.parse("[1, 2 ,3]"), Ok(vec![1, 2, 3]));
+//! assert_eq!(list.parse("[]"), Ok(vec![]));
+//! assert!(list.parse("[1, 2").is_err());
+//! ```
+
+/* PARSERS ********************************************************************/
+use crate::error::Error;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+type Run<'a, T> = dyn Fn(&'a str) -> Option<(T, &'a str)> + 'a;
+
+/// A parser of values of type `T` from the start of a string.
+///
+/// A parser either succeeds, producing a value and the rest of the input after the part it
+/// consumed, or fails, consuming nothing.
+pub struct Parser<'a, T> {
+    run: Box<Run<'a, T>>,
+}
+
+impl<'a, T: 'a> Parser<'a, T> {
+    /// Creates a parser from a function which parses the start of its input, returning the
+    /// value and the rest of the input, or `None` if it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::parse::Parser;
+    /// let any = Parser::new(|s: &str| {
+    ///     let c = s.chars().next()?;
+    ///     Some((c, &s[c.len_utf8()..]))
+    /// });
+    /// assert_eq!(any.run("ab"), Some(('a', "b")));
+    /// assert_eq!(any.run(""), None);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&'a str) -> Option<(T, &'a str)> + 'a,
+    {
+        Parser { run: Box::new(f) }
+    }
+
+    /// Creates a parser which consumes nothing and always succeeds with a value.
+    pub fn pure(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Parser::new(move |s| Some((value.clone(), s)))
+    }
+
+    /// Parses the start of the input, returning the value and the rest of the input.
+    pub fn run(&self, input: &'a str) -> Option<(T, &'a str)> {
+        (self.run)(input)
+    }
+
+    /// Parses the whole input, failing with [ParseFailed](crate::error::Error::ParseFailed) if
+    /// the parser fails, or if it does not consume all of the input.
+    pub fn parse(&self, input: &'a str) -> Result<T, Error> {
+        match self.run(input) {
+            Some((value, "")) => Ok(value),
+            _ => Err(Error::ParseFailed),
+        }
+    }
+
+    /// Creates a parser which transforms the value of this one.
+    pub fn map<U, F>(self, f: F) -> Parser<'a, U>
+    where
+        U: 'a,
+        F: Fn(T) -> U + 'a,
+    {
+        Parser::new(move |s| self.run(s).map(|(t, rest)| (f(t), rest)))
+    }
+
+    /// Creates a parser which runs this one, and then the parser that a function of its value
+    /// returns, on the rest of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::parse::{digits, Parser};
+    /// // A digit giving a count, followed by that many letters.
+    /// let counted = digits().and_then(|n| {
+    ///     let n: usize = n.parse().unwrap();
+    ///     Parser::new(move |s: &str| s.get(..n).map(|word| (word, &s[n..])))
+    /// });
+    /// assert_eq!(counted.run("3abcd"), Some(("abc", "d")));
+    /// assert_eq!(counted.run("5ab"), None);
+    /// ```
+    pub fn and_then<U, F>(self, f: F) -> Parser<'a, U>
+    where
+        U: 'a,
+        F: Fn(T) -> Parser<'a, U> + 'a,
+    {
+        Parser::new(move |s| {
+            let (t, rest) = self.run(s)?;
+            f(t).run(rest)
+        })
+    }
+
+    /// Creates a parser which runs this one and then `next` on the rest of the input,
+    /// producing both of their values.
+    pub fn then<U>(self, next: Parser<'a, U>) -> Parser<'a, (T, U)>
+    where
+        U: 'a,
+    {
+        Parser::new(move |s| {
+            let (t, rest) = self.run(s)?;
+            let (u, rest) = next.run(rest)?;
+            Some(((t, u), rest))
+        })
+    }
+
+    /// Creates a parser which runs this one, or if it fails, runs `other` on the same input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::parse::literal;
+    /// let answer = literal("yes").map(|_| true).or(literal("no").map(|_| false));
+    /// assert_eq!(answer.parse("no"), Ok(false));
+    /// assert!(answer.parse("maybe").is_err());
+    /// ```
+    pub fn or(self, other: Parser<'a, T>) -> Parser<'a, T> {
+        Parser::new(move |s| self.run(s).or_else(|| other.run(s)))
+    }
+
+    /// Creates a parser which runs this one as many times as it succeeds, producing all of
+    /// the values. It succeeds even if this one does not succeed at all, and stops once this
+    /// one succeeds without consuming anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::parse::char_is;
+    /// let letters = char_is(char::is_alphabetic).many();
+    /// assert_eq!(letters.run("ab1"), Some((vec!['a', 'b'], "1")));
+    /// assert_eq!(letters.run("1"), Some((vec![], "1")));
+    /// ```
+    pub fn many(self) -> Parser<'a, Vec<T>> {
+        Parser::new(move |mut s| {
+            let mut values = Vec::new();
+            while let Some((t, rest)) = self.run(s) {
+                if rest.len() == s.len() {
+                    break;
+                }
+                values.push(t);
+                s = rest;
+            }
+            Some((values, s))
+        })
+    }
+
+    /// Creates a parser which runs this one as many times as it succeeds, with `sep` between
+    /// each time, producing all of this one's values. It succeeds even if this one does not
+    /// succeed at all.
+    pub fn sep_by<U>(self, sep: Parser<'a, U>) -> Parser<'a, Vec<T>>
+    where
+        U: 'a,
+    {
+        Parser::new(move |s| {
+            let mut values = Vec::new();
+            let (t, mut s) = match self.run(s) {
+                Some(first) => first,
+                None => return Some((values, s)),
+            };
+            values.push(t);
+            while let Some((t, rest)) = sep.run(s).and_then(|(_, rest)| self.run(rest)) {
+                values.push(t);
+                s = rest;
+            }
+            Some((values, s))
+        })
+    }
+}
+
+impl<T> Debug for Parser<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Parser(..)")
+    }
+}
+
+/* PRIMITIVES *****************************************************************/
+
+/// Creates a parser of a single character which satisfies a predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::parse::char_is;
+/// let vowel = char_is(|c| "aeiou".contains(c));
+/// assert_eq!(vowel.run("ant"), Some(('a', "nt")));
+/// assert_eq!(vowel.run("bat"), None);
+/// ```
+pub fn char_is<'a, P>(pred: P) -> Parser<'a, char>
+where
+    P: Fn(char) -> bool + 'a,
+{
+    Parser::new(move |s: &'a str| {
+        let c = s.chars().next().filter(|c| pred(*c))?;
+        Some((c, &s[c.len_utf8()..]))
+    })
+}
+
+/// Creates a parser of exactly the provided text.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::parse::literal;
+/// assert_eq!(literal("let").run("let x"), Some(("let", " x")));
+/// assert_eq!(literal("let").run("lex"), None);
+/// ```
+pub fn literal<'a>(text: &'a str) -> Parser<'a, &'a str> {
+    Parser::new(move |s: &'a str| s.strip_prefix(text).map(|rest| (&s[..text.len()], rest)))
+}
+
+fn span<'a, P>(pred: P, at_least: usize) -> Parser<'a, &'a str>
+where
+    P: Fn(char) -> bool + 'a,
+{
+    Parser::new(move |s: &'a str| {
+        let end = s.find(|c| !pred(c)).unwrap_or(s.len());
+        if end < at_least {
+            None
+        } else {
+            Some(s.split_at(end))
+        }
+    })
+}
+
+/// Creates a parser of one or more ASCII digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::parse::digits;
+/// assert_eq!(digits().run("2024-01"), Some(("2024", "-01")));
+/// assert_eq!(digits().run("x"), None);
+/// ```
+pub fn digits<'a>() -> Parser<'a, &'a str> {
+    span(|c| c.is_ascii_digit(), 1)
+}
+
+/// Creates a parser of any amount of whitespace, including none.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::parse::whitespace;
+/// assert_eq!(whitespace().run(" \t x"), Some((" \t ", "x")));
+/// assert_eq!(whitespace().run("x"), Some(("", "x")));
+/// ```
+pub fn whitespace<'a>() -> Parser<'a, &'a str> {
+    span(char::is_whitespace, 0)
+}
@@ -0,0 +1,267 @@
+//! Predicates as values, which can be stored, combined at runtime, and passed across API
+//! boundaries without naming closure types.
+
+/* PREDICATES *****************************************************************/
+use alloc::boxed::Box;
+use core::fmt::{self, Debug, Formatter};
+
+/// A boxed test of a value of type `A`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::predicate::Predicate;
+/// let filters: Vec<Predicate<u32>> = vec![
+///     Predicate::new(|x| x % 2 == 0),
+///     Predicate::new(|x| *x > 10),
+/// ];
+/// let matching: Vec<u32> = (8..14)
+///     .filter(|x| filters.iter().all(|filter| filter.test(x)))
+///     .collect();
+/// assert_eq!(matching, vec![12]);
+/// ```
+pub struct Predicate<A> {
+    test: Box<dyn Fn(&A) -> bool>,
+}
+
+impl<A: 'static> Predicate<A> {
+    /// Creates a predicate from a function.
+    pub fn new<F>(test: F) -> Self
+    where
+        F: Fn(&A) -> bool + 'static,
+    {
+        Predicate {
+            test: Box::new(test),
+        }
+    }
+
+    /// Creates a predicate which every value satisfies.
+    pub fn always() -> Self {
+        Predicate::new(|_| true)
+    }
+
+    /// Creates a predicate which no value satisfies.
+    pub fn never() -> Self {
+        Predicate::new(|_| false)
+    }
+
+    /// Tests a value against this predicate.
+    pub fn test(&self, a: &A) -> bool {
+        (self.test)(a)
+    }
+
+    /// Combines two predicates into one which is satisfied when both of them are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::predicate::Predicate;
+    /// let teen = Predicate::new(|age: &u32| *age >= 13).and(Predicate::new(|age| *age < 20));
+    /// assert!(teen.test(&15));
+    /// assert!(!teen.test(&21));
+    /// ```
+    pub fn and(self, other: Self) -> Self {
+        Predicate::new(move |a| self.test(a) && other.test(a))
+    }
+
+    /// Combines two predicates into one which is satisfied when either of them is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::predicate::Predicate;
+    /// let blank = Predicate::new(|s: &String| s.is_empty())
+    ///     .or(Predicate::new(|s: &String| s.trim().is_empty()));
+    /// assert!(blank.test(&"  ".to_owned()));
+    /// assert!(!blank.test(&"a".to_owned()));
+    /// ```
+    pub fn or(self, other: Self) -> Self {
+        Predicate::new(move |a| self.test(a) || other.test(a))
+    }
+
+    /// Adapts this predicate to test values of another type, by first converting them with
+    /// `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::predicate::Predicate;
+    /// let long = Predicate::new(|len: &usize| *len > 3);
+    /// let long_word = long.contramap(|word: &&str| word.len());
+    /// assert!(long_word.test(&"word"));
+    /// assert!(!long_word.test(&"cat"));
+    /// ```
+    pub fn contramap<B, F>(self, f: F) -> Predicate<B>
+    where
+        B: 'static,
+        F: Fn(&B) -> A + 'static,
+    {
+        Predicate::new(move |b| self.test(&f(b)))
+    }
+
+    /// Converts this predicate into a plain function, for use with `Iterator::filter` and the
+    /// like.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::predicate::Predicate;
+    /// let even = Predicate::new(|x: &u32| x % 2 == 0).into_fn();
+    /// assert_eq!((1..=4).filter(even).collect::<Vec<_>>(), vec![2, 4]);
+    /// ```
+    pub fn into_fn(self) -> impl Fn(&A) -> bool {
+        move |a| self.test(a)
+    }
+}
+
+impl<A: 'static, F> From<F> for Predicate<A>
+where
+    F: Fn(&A) -> bool + 'static,
+{
+    fn from(test: F) -> Self {
+        Predicate::new(test)
+    }
+}
+
+impl<A> Debug for Predicate<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Predicate(..)")
+    }
+}
+
+/* COMBINATORS ****************************************************************/
+use core::ops::{BitAnd, BitOr, Not};
+
+/// Negates a predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::predicate::Predicate;
+/// let odd = !Predicate::new(|x: &u32| x % 2 == 0);
+/// assert!(odd.test(&3));
+/// ```
+impl<A: 'static> Not for Predicate<A> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Predicate::new(move |a| !self.test(a))
+    }
+}
+
+/// Combines predicates with [and](crate::predicate::Predicate::and).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::predicate::Predicate;
+/// let small_even = Predicate::from(|x: &u32| x % 2 == 0) & Predicate::from(|x: &u32| *x < 5);
+/// assert!(small_even.test(&4));
+/// assert!(!small_even.test(&6));
+/// ```
+impl<A: 'static> BitAnd for Predicate<A> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.and(other)
+    }
+}
+
+/// Combines predicates with [or](crate::predicate::Predicate::or).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::predicate::Predicate;
+/// let extreme = Predicate::from(|x: &i32| *x < -10) | Predicate::from(|x: &i32| *x > 10);
+/// assert!(extreme.test(&-20));
+/// assert!(!extreme.test(&0));
+/// ```
+impl<A: 'static> BitOr for Predicate<A> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.or(other)
+    }
+}
+
+/* MONOIDS ********************************************************************/
+#[cfg(feature = "std")]
+use fp_core::empty::Empty;
+#[cfg(feature = "std")]
+use fp_core::monoid::Monoid;
+#[cfg(feature = "std")]
+use fp_core::semigroup::Semigroup;
+
+/// The [Monoid](fp_core::monoid::Monoid) of predicates under
+/// [and](crate::predicate::Predicate::and), whose empty value is always satisfied.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::predicate::{All, Predicate};
+/// use fp_core::empty::Empty;
+/// use fp_core::semigroup::Semigroup;
+/// let rules = vec![Predicate::new(|x: &u32| *x > 1), Predicate::new(|x| *x < 5)];
+/// let All(valid) = rules.into_iter().map(All).fold(All::empty(), Semigroup::combine);
+/// assert!(valid.test(&3));
+/// assert!(!valid.test(&7));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct All<A>(pub Predicate<A>);
+
+#[cfg(feature = "std")]
+impl<A: 'static> Semigroup for All<A> {
+    fn combine(self, other: Self) -> Self {
+        All(self.0.and(other.0))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: 'static> Empty for All<A> {
+    fn empty() -> Self {
+        All(Predicate::always())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: 'static> Monoid for All<A> {}
+
+/// The [Monoid](fp_core::monoid::Monoid) of predicates under
+/// [or](crate::predicate::Predicate::or), whose empty value is never satisfied.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::predicate::{Any, Predicate};
+/// use fp_core::empty::Empty;
+/// use fp_core::semigroup::Semigroup;
+/// let banned = vec![
+///     Predicate::new(|s: &&str| s.contains("spam")),
+///     Predicate::new(|s: &&str| s.is_empty()),
+/// ];
+/// let Any(rejected) = banned.into_iter().map(Any).fold(Any::empty(), Semigroup::combine);
+/// assert!(rejected.test(&"spam!"));
+/// assert!(!rejected.test(&"hello"));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Any<A>(pub Predicate<A>);
+
+#[cfg(feature = "std")]
+impl<A: 'static> Semigroup for Any<A> {
+    fn combine(self, other: Self) -> Self {
+        Any(self.0.or(other.0))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: 'static> Empty for Any<A> {
+    fn empty() -> Self {
+        Any(Predicate::never())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: 'static> Monoid for Any<A> {}
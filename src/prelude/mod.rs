@@ -0,0 +1,49 @@
+//! The most commonly used functions, types, and traits of this crate, available with a single
+//! import.
+//!
+//! Only items whose names are unambiguous outside of their modules are included, so that
+//! glob importing the prelude does not shadow anything from `std` or from other crates. For
+//! example, [tuple::first](crate::tuple::first) and [tagged::map](crate::tagged::map) are left
+//! out, since their names only make sense alongside their module's name.
+//!
+//! ```rust
+//! use fp_std::prelude::*;
+//! let bump = apply_second(1, |x: u32, y: u32| x + y);
+//! assert_eq!(map_second(bump)(("a", 1)), ("a", 2));
+//! assert_eq!(first_some!(None, some(identity(3))), Some(3));
+//! ```
+
+/* FUNCTIONS ******************************************************************/
+pub use crate::curry::{curry2, curry3, curry4, curry5};
+#[cfg(feature = "std")]
+pub use crate::function::tap_with;
+pub use crate::function::{
+    always, apply_first, apply_second, compose2, compose3, defer, err, err_with, first_arg, flip,
+    identity, ok, pair_with, second_arg, some, supply, tap,
+};
+
+/* TUPLES *********************************************************************/
+pub use crate::tuple::{gather, map_first, map_second, spread};
+
+/* MACROS *********************************************************************/
+pub use crate::{compose, first_some, method, pipe, struct_ap, tag};
+
+/* TYPES **********************************************************************/
+pub use crate::either::Either;
+#[cfg(feature = "alloc")]
+pub use crate::function::Pipeline;
+#[cfg(feature = "alloc")]
+pub use crate::lazy::Lazy;
+#[cfg(feature = "alloc")]
+pub use crate::ord::Comparator;
+#[cfg(feature = "alloc")]
+pub use crate::predicate::Predicate;
+pub use crate::tagged::Tagged;
+
+/* TRAITS *********************************************************************/
+pub use crate::function::Pipe;
+pub use crate::iso::Iso;
+#[cfg(feature = "alloc")]
+pub use crate::list::IterExt;
+pub use crate::reducer::Reducer;
+pub use crate::traversal::{Traversal, Traverse};
@@ -0,0 +1,403 @@
+//! Prisms: optics which focus on one case of a sum type, such as the value inside a `Some`,
+//! which may or may not be present.
+//!
+//! For a prism to be lawful, previewing a reviewed value must find that same value, and
+//! reviewing a previewed value must rebuild the original structure.
+
+/* PRISMS *********************************************************************/
+
+/// An optic which focuses on an `A` inside an `S`, when the `S` is the right case.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::prism::Prism;
+/// struct Digit;
+///
+/// impl Prism<char, u32> for Digit {
+///     fn preview<'a>(&self, s: &'a char) -> Option<&'a u32> {
+///         const DIGITS: [u32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+///         s.to_digit(10).map(|d| &DIGITS[d as usize])
+///     }
+///
+///     fn matching(&self, s: char) -> Result<u32, char> {
+///         s.to_digit(10).ok_or(s)
+///     }
+///
+///     fn review(&self, a: u32) -> char {
+///         std::char::from_digit(a, 10).unwrap()
+///     }
+/// }
+///
+/// assert_eq!(Digit.preview(&'7'), Some(&7));
+/// assert_eq!(Digit.preview(&'x'), None);
+/// assert_eq!(Digit.review(3), '3');
+/// assert_eq!(Digit.over('4', |d| d + 1), '5');
+/// ```
+pub trait Prism<S, A> {
+    /// Borrows the focused value, if the structure is the right case.
+    fn preview<'a>(&self, s: &'a S) -> Option<&'a A>;
+
+    /// Takes the focused value out of the structure if it is the right case, or returns the
+    /// structure unchanged otherwise.
+    fn matching(&self, s: S) -> Result<A, S>;
+
+    /// Builds the structure out of a focused value.
+    fn review(&self, a: A) -> S;
+
+    /// Replaces the focused value with the result of a function of it, if the structure is the
+    /// right case.
+    fn over<F>(&self, s: S, f: F) -> S
+    where
+        F: FnOnce(A) -> A,
+    {
+        match self.matching(s) {
+            Ok(a) => self.review(f(a)),
+            Err(s) => s,
+        }
+    }
+}
+
+/* OPTIONS ********************************************************************/
+
+/// A prism for the value in an `Option`, when it is `Some`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::prism::{Prism, PrismSome};
+/// assert_eq!(PrismSome.preview(&Some(1)), Some(&1));
+/// assert_eq!(PrismSome.review(1), Some(1));
+/// assert_eq!(PrismSome.over(None, |x: u32| x + 1), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PrismSome;
+
+impl<A> Prism<Option<A>, A> for PrismSome {
+    fn preview<'a>(&self, s: &'a Option<A>) -> Option<&'a A> {
+        s.as_ref()
+    }
+
+    fn matching(&self, s: Option<A>) -> Result<A, Option<A>> {
+        s.ok_or(None)
+    }
+
+    fn review(&self, a: A) -> Option<A> {
+        Some(a)
+    }
+}
+
+/// A prism for the absence of a value in an `Option`, when it is `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::prism::{Prism, PrismNone};
+/// assert_eq!(PrismNone.preview(&None::<u32>), Some(&()));
+/// assert_eq!(PrismNone.preview(&Some(1)), None);
+/// assert_eq!(PrismNone.review(()), None::<u32>);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PrismNone;
+
+impl<A> Prism<Option<A>, ()> for PrismNone {
+    fn preview<'a>(&self, s: &'a Option<A>) -> Option<&'a ()> {
+        match s {
+            None => Some(&()),
+            Some(..) => None,
+        }
+    }
+
+    fn matching(&self, s: Option<A>) -> Result<(), Option<A>> {
+        match s {
+            None => Ok(()),
+            Some(a) => Err(Some(a)),
+        }
+    }
+
+    fn review(&self, _: ()) -> Option<A> {
+        None
+    }
+}
+
+/* RESULTS ********************************************************************/
+
+/// A prism for the value in a `Result`, when it is `Ok`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::prism::{Prism, PrismOk};
+/// let parsed: Result<u32, String> = Ok(1);
+/// assert_eq!(PrismOk.preview(&parsed), Some(&1));
+/// assert_eq!(PrismOk.over(parsed, |x| x * 10), Ok(10));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PrismOk;
+
+impl<T, E> Prism<Result<T, E>, T> for PrismOk {
+    fn preview<'a>(&self, s: &'a Result<T, E>) -> Option<&'a T> {
+        s.as_ref().ok()
+    }
+
+    fn matching(&self, s: Result<T, E>) -> Result<T, Result<T, E>> {
+        match s {
+            Ok(t) => Ok(t),
+            Err(e) => Err(Err(e)),
+        }
+    }
+
+    fn review(&self, t: T) -> Result<T, E> {
+        Ok(t)
+    }
+}
+
+/// A prism for the error in a `Result`, when it is `Err`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::prism::{Prism, PrismErr};
+/// let failed: Result<u32, &str> = Err("bad input");
+/// assert_eq!(PrismErr.preview(&failed), Some(&"bad input"));
+/// assert_eq!(PrismErr.over(Ok::<u32, &str>(1), |e| e), Ok(1));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PrismErr;
+
+impl<T, E> Prism<Result<T, E>, E> for PrismErr {
+    fn preview<'a>(&self, s: &'a Result<T, E>) -> Option<&'a E> {
+        s.as_ref().err()
+    }
+
+    fn matching(&self, s: Result<T, E>) -> Result<E, Result<T, E>> {
+        match s {
+            Ok(t) => Err(Ok(t)),
+            Err(e) => Ok(e),
+        }
+    }
+
+    fn review(&self, e: E) -> Result<T, E> {
+        Err(e)
+    }
+}
+
+/* EITHERS ********************************************************************/
+use crate::either::Either;
+
+/// A prism for the value in an [Either](crate::either::Either), when it is `Left`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::Either;
+/// # use fp_std::prism::{Prism, PrismLeft};
+/// let value: Either<u32, &str> = Either::Left(1);
+/// assert_eq!(PrismLeft.preview(&value), Some(&1));
+/// assert_eq!(PrismLeft.over(value, |n| n + 1), Either::Left(2));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PrismLeft;
+
+impl<L, R> Prism<Either<L, R>, L> for PrismLeft {
+    fn preview<'a>(&self, s: &'a Either<L, R>) -> Option<&'a L> {
+        match s {
+            Either::Left(l) => Some(l),
+            Either::Right(..) => None,
+        }
+    }
+
+    fn matching(&self, s: Either<L, R>) -> Result<L, Either<L, R>> {
+        match s {
+            Either::Left(l) => Ok(l),
+            Either::Right(r) => Err(Either::Right(r)),
+        }
+    }
+
+    fn review(&self, l: L) -> Either<L, R> {
+        Either::Left(l)
+    }
+}
+
+/// A prism for the value in an [Either](crate::either::Either), when it is `Right`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::either::Either;
+/// # use fp_std::prism::{Prism, PrismRight};
+/// let value: Either<u32, &str> = Either::Left(1);
+/// assert_eq!(PrismRight.preview(&value), None);
+/// assert_eq!(PrismRight.review("a"), Either::<u32, _>::Right("a"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PrismRight;
+
+impl<L, R> Prism<Either<L, R>, R> for PrismRight {
+    fn preview<'a>(&self, s: &'a Either<L, R>) -> Option<&'a R> {
+        match s {
+            Either::Left(..) => None,
+            Either::Right(r) => Some(r),
+        }
+    }
+
+    fn matching(&self, s: Either<L, R>) -> Result<R, Either<L, R>> {
+        match s {
+            Either::Left(l) => Err(Either::Left(l)),
+            Either::Right(r) => Ok(r),
+        }
+    }
+
+    fn review(&self, r: R) -> Either<L, R> {
+        Either::Right(r)
+    }
+}
+
+/* LENSES *********************************************************************/
+#[cfg(feature = "std")]
+use crate::traversal::{Traversal, TraversalExt};
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+
+/// A [Traversal](crate::traversal::Traversal) which focuses through a lens `L` on a `B`, and
+/// then through a prism `P` on the `A` inside it, if there is one. See
+/// [compose_lens_prism](crate::prism::compose_lens_prism).
+///
+/// Since the signatures of [Lens](fp_core::lens::Lens) offer no way to relate the lifetime of
+/// the intermediate type `B` to anything else, `B` must be `'static`.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct LensPrism<L, P, B> {
+    lens: PhantomData<fn() -> L>,
+    prism: P,
+    focus: PhantomData<fn() -> B>,
+}
+
+#[cfg(feature = "std")]
+impl<S, A, B, L, P> Traversal<S, A> for LensPrism<L, P, B>
+where
+    B: Clone + 'static,
+    L: Lens<S, B>,
+    P: Prism<B, A>,
+{
+    fn for_each<'a, F>(&self, s: &'a S, mut f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        if let Some(a) = L::get(s).and_then(|b| self.prism.preview(b)) {
+            f(a);
+        }
+    }
+
+    fn over<F>(&self, s: S, mut f: F) -> S
+    where
+        F: FnMut(A) -> A,
+    {
+        match L::get(&s).cloned().map(|b| self.prism.matching(b)) {
+            Some(Ok(a)) => L::set(self.prism.review(f(a)), &s),
+            _ => s,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L, P, B> TraversalExt for LensPrism<L, P, B> {}
+
+/// Composes a lens with a prism, to focus on the value inside the part of a structure that the
+/// lens focuses on, if it is the right case. Since the value may be missing, the result is a
+/// [Traversal](crate::traversal::Traversal).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::prism::{compose_lens_prism, PrismSome};
+/// # use fp_std::traversal::Traversal;
+/// # use fp_std::tuple::LensSecond;
+/// let score = compose_lens_prism(LensSecond, PrismSome);
+/// assert_eq!(score.over(("ann", Some(3)), |x: u32| x + 1), ("ann", Some(4)));
+/// assert_eq!(score.over(("bo", None), |x: u32| x + 1), ("bo", None));
+/// assert_eq!(score.to_vec(&("cy", Some(7))), vec![&7]);
+/// ```
+#[cfg(feature = "std")]
+pub fn compose_lens_prism<L, P, B>(_lens: L, prism: P) -> LensPrism<L, P, B> {
+    LensPrism {
+        lens: PhantomData,
+        prism,
+        focus: PhantomData,
+    }
+}
+
+/// A [Traversal](crate::traversal::Traversal) which focuses through a prism `P` on a `B`, if
+/// there is one, and then through a lens `L` on part of that `B`. See
+/// [compose_prism_lens](crate::prism::compose_prism_lens).
+///
+/// As for [LensPrism](crate::prism::LensPrism), `B` must be `'static`.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct PrismLens<P, L, B> {
+    prism: P,
+    lens: PhantomData<fn() -> L>,
+    focus: PhantomData<fn() -> B>,
+}
+
+#[cfg(feature = "std")]
+impl<S, A, B, P, L> Traversal<S, A> for PrismLens<P, L, B>
+where
+    A: Clone,
+    B: 'static,
+    P: Prism<S, B>,
+    L: Lens<B, A>,
+{
+    fn for_each<'a, F>(&self, s: &'a S, mut f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        if let Some(a) = self.prism.preview(s).and_then(L::get) {
+            f(a);
+        }
+    }
+
+    fn over<F>(&self, s: S, mut f: F) -> S
+    where
+        F: FnMut(A) -> A,
+    {
+        match self.prism.matching(s) {
+            Ok(b) => match L::get(&b).cloned() {
+                Some(a) => self.prism.review(L::set(f(a), &b)),
+                None => self.prism.review(b),
+            },
+            Err(s) => s,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P, L, B> TraversalExt for PrismLens<P, L, B> {}
+
+/// Composes a prism with a lens, to focus on part of the value inside a structure, if it is the
+/// right case. Since the value may be missing, the result is a
+/// [Traversal](crate::traversal::Traversal).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::prism::{compose_prism_lens, PrismOk};
+/// # use fp_std::traversal::Traversal;
+/// # use fp_std::tuple::LensFirst;
+/// let name = compose_prism_lens(PrismOk, LensFirst);
+/// let found: Result<(&str, u32), ()> = Ok(("ann", 3));
+/// assert_eq!(name.over(found, |s: &str| s.trim_end_matches('n')), Ok(("a", 3)));
+/// assert_eq!(name.over(Err::<(&str, u32), _>(()), |s: &str| s), Err(()));
+/// ```
+#[cfg(feature = "std")]
+pub fn compose_prism_lens<P, L, B>(prism: P, _lens: L) -> PrismLens<P, L, B> {
+    PrismLens {
+        prism,
+        lens: PhantomData,
+        focus: PhantomData,
+    }
+}
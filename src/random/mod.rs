@@ -0,0 +1,131 @@
+//! Random generators as values, which describe how to produce random data without producing
+//! it, and so can be composed before being run.
+//!
+//! Requires the `rand` feature.
+//!
+//! ```rust
+//! # use fp_std::random::Gen;
+//! # use rand::Rng;
+//! let die = Gen::new(|rng| rng.gen_range(1..=6));
+//! let pair = die.zip(Gen::new(|rng| rng.gen_range(1..=6)));
+//! let (a, b) = pair.sample(42);
+//! assert!((1..=6).contains(&a) && (1..=6).contains(&b));
+//! assert_eq!(pair.sample(42), (a, b));
+//! ```
+
+/* GENERATORS *****************************************************************/
+use core::fmt::{self, Debug, Formatter};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// A boxed function which generates a random `T`.
+///
+/// A generator is pure: given a random number generator in the same state, it always generates
+/// the same value.
+pub struct Gen<T> {
+    generate: Box<dyn Fn(&mut StdRng) -> T>,
+}
+
+impl<T: 'static> Gen<T> {
+    /// Creates a generator from a function of a random number generator.
+    pub fn new<F>(generate: F) -> Self
+    where
+        F: Fn(&mut StdRng) -> T + 'static,
+    {
+        Gen {
+            generate: Box::new(generate),
+        }
+    }
+
+    /// Creates a generator which always generates a copy of the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::random::Gen;
+    /// assert_eq!(Gen::constant('x').sample(0), 'x');
+    /// ```
+    pub fn constant(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Gen::new(move |_| value.clone())
+    }
+
+    /// Generates a value using the given random number generator.
+    pub fn generate(&self, rng: &mut StdRng) -> T {
+        (self.generate)(rng)
+    }
+
+    /// Generates a value using a random number generator seeded with `seed`. The same seed
+    /// always generates the same value.
+    pub fn sample(&self, seed: u64) -> T {
+        self.generate(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Transforms the generated values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::random::Gen;
+    /// # use rand::Rng;
+    /// let even = Gen::new(|rng| rng.gen_range(0..50u32)).map(|x| x * 2);
+    /// assert_eq!(even.sample(3) % 2, 0);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Gen<U>
+    where
+        U: 'static,
+        F: Fn(T) -> U + 'static,
+    {
+        Gen::new(move |rng| f(self.generate(rng)))
+    }
+
+    /// Chooses the next generator based on the value generated by this one, and generates a
+    /// value with that.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::random::Gen;
+    /// # use rand::Rng;
+    /// let word = Gen::new(|rng| rng.gen_range(1..5usize)).and_then(|len| {
+    ///     Gen::new(move |rng| (0..len).map(|_| rng.gen_range('a'..='z')).collect::<String>())
+    /// });
+    /// let sampled = word.sample(9);
+    /// assert!((1..5).contains(&sampled.len()));
+    /// ```
+    pub fn and_then<U, F>(self, f: F) -> Gen<U>
+    where
+        U: 'static,
+        F: Fn(T) -> Gen<U> + 'static,
+    {
+        Gen::new(move |rng| f(self.generate(rng)).generate(rng))
+    }
+
+    /// Combines two generators into one which generates a pair of their values.
+    pub fn zip<U: 'static>(self, other: Gen<U>) -> Gen<(T, U)> {
+        Gen::new(move |rng| (self.generate(rng), other.generate(rng)))
+    }
+
+    /// Creates a generator of `Vec`s of `n` values generated by this one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::random::Gen;
+    /// # use rand::Rng;
+    /// let coins = Gen::new(|rng| rng.gen_bool(0.5)).vec_of(8);
+    /// assert_eq!(coins.sample(1).len(), 8);
+    /// assert_eq!(coins.sample(1), coins.sample(1));
+    /// ```
+    pub fn vec_of(self, n: usize) -> Gen<Vec<T>> {
+        Gen::new(move |rng| (0..n).map(|_| self.generate(rng)).collect())
+    }
+}
+
+impl<T> Debug for Gen<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Gen(..)")
+    }
+}
@@ -0,0 +1,372 @@
+//! Computations which read from a shared environment, so that configuration and dependencies
+//! can be passed to every step of a computation without threading them through by hand.
+//!
+//! ```rust
+//! # use fp_std::reader::ReaderResult;
+//! struct Config {
+//!     base_url: String,
+//!     retries: u32,
+//! }
+//!
+//! fn endpoint(path: &'static str) -> ReaderResult<Config, String, String> {
+//!     ReaderResult::new(move |config: &Config| {
+//!         if config.base_url.is_empty() {
+//!             Err("no base url".to_owned())
+//!         } else {
+//!             Ok(format!("{}/{}", config.base_url, path))
+//!         }
+//!     })
+//! }
+//!
+//! let request = endpoint("users").and_then(|url| {
+//!     ReaderResult::asks(move |config: &Config| format!("GET {} x{}", url, config.retries))
+//! });
+//!
+//! let config = Config { base_url: "https://example.com".to_owned(), retries: 3 };
+//! assert_eq!(request.run(&config), Ok("GET https://example.com/users x3".to_owned()));
+//! let config = Config { base_url: String::new(), retries: 3 };
+//! assert_eq!(request.run(&config), Err("no base url".to_owned()));
+//! ```
+
+/* READERS ********************************************************************/
+use alloc::boxed::Box;
+use core::fmt::{self, Debug, Formatter};
+
+type Run<Env, A> = dyn Fn(&Env) -> A;
+
+/// A computation which reads from an environment of type `Env` to produce an `A`.
+///
+/// Combining readers with [and_then](crate::reader::Reader::and_then) passes the same
+/// environment to each of them. For computations which may fail, see
+/// [ReaderResult](crate::reader::ReaderResult).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reader::Reader;
+/// struct Theme {
+///     indent: usize,
+///     bullet: char,
+/// }
+///
+/// fn item(text: &'static str) -> Reader<Theme, String> {
+///     Reader::asks(move |theme: &Theme| {
+///         format!("{}{} {}", " ".repeat(theme.indent), theme.bullet, text)
+///     })
+/// }
+///
+/// let list = item("one").and_then(|one| item("two").map(move |two| format!("{}\n{}", one, two)));
+/// let theme = Theme { indent: 2, bullet: '*' };
+/// assert_eq!(list.run(&theme), "  * one\n  * two");
+/// ```
+pub struct Reader<Env, A> {
+    run: Box<Run<Env, A>>,
+}
+
+impl<Env: 'static, A: 'static> Reader<Env, A> {
+    /// Creates a reader from a function of the environment.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Env) -> A + 'static,
+    {
+        Reader { run: Box::new(f) }
+    }
+
+    /// Creates a reader which extracts a value from the environment. This is the same as
+    /// [new](crate::reader::Reader::new).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::Reader;
+    /// let len = Reader::asks(String::len);
+    /// assert_eq!(len.run(&"four".to_owned()), 4);
+    /// ```
+    pub fn asks<F>(f: F) -> Self
+    where
+        F: Fn(&Env) -> A + 'static,
+    {
+        Reader::new(f)
+    }
+
+    /// Creates a reader which ignores the environment and produces a value.
+    pub fn pure(a: A) -> Self
+    where
+        A: Clone,
+    {
+        Reader::new(move |_| a.clone())
+    }
+
+    /// Runs the computation with an environment.
+    pub fn run(&self, env: &Env) -> A {
+        (self.run)(env)
+    }
+
+    /// Converts the reader back into the function it runs.
+    pub fn into_fn(self) -> impl Fn(&Env) -> A {
+        move |env| self.run(env)
+    }
+
+    /// Creates a reader which transforms the value of this one.
+    pub fn map<B, F>(self, f: F) -> Reader<Env, B>
+    where
+        B: 'static,
+        F: Fn(A) -> B + 'static,
+    {
+        Reader::new(move |env| f(self.run(env)))
+    }
+
+    /// Creates a reader which runs the reader that a function of this one's value returns,
+    /// with the same environment.
+    pub fn and_then<B, F>(self, f: F) -> Reader<Env, B>
+    where
+        B: 'static,
+        F: Fn(A) -> Reader<Env, B> + 'static,
+    {
+        Reader::new(move |env| f(self.run(env)).run(env))
+    }
+
+    /// Creates a reader which runs this one with an environment derived from its own. This
+    /// may be a modified copy of the environment, or a part of a larger one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::Reader;
+    /// let shout = Reader::asks(|name: &String| format!("{}!", name));
+    /// let shout = shout.local(|name: &String| name.to_uppercase());
+    /// assert_eq!(shout.run(&"hey".to_owned()), "HEY!");
+    /// ```
+    pub fn local<Outer, F>(self, f: F) -> Reader<Outer, A>
+    where
+        Outer: 'static,
+        F: Fn(&Outer) -> Env + 'static,
+    {
+        Reader::new(move |outer| self.run(&f(outer)))
+    }
+
+    /// Converts this reader into a [ReaderResult](crate::reader::ReaderResult) which always
+    /// succeeds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::Reader;
+    /// let retries = Reader::asks(|limit: &u32| *limit)
+    ///     .into_result()
+    ///     .and_then_result(|n| if n > 0 { Ok(n) } else { Err("retries must be positive") });
+    /// assert_eq!(retries.run(&3), Ok(3));
+    /// assert_eq!(retries.run(&0), Err("retries must be positive"));
+    /// ```
+    pub fn into_result<E: 'static>(self) -> ReaderResult<Env, A, E> {
+        ReaderResult::asks(move |env| self.run(env))
+    }
+}
+
+impl<Env: Clone + 'static> Reader<Env, Env> {
+    /// Creates a reader which produces a copy of the environment itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::Reader;
+    /// let greeting = Reader::<String, String>::ask().map(|name| format!("hi {}", name));
+    /// assert_eq!(greeting.run(&"bo".to_owned()), "hi bo");
+    /// ```
+    pub fn ask() -> Self {
+        Reader::new(Env::clone)
+    }
+}
+
+impl<Env, A> Debug for Reader<Env, A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Reader(..)")
+    }
+}
+
+/* FALLIBLE READERS ***********************************************************/
+
+type RunResult<Env, A, E> = dyn Fn(&Env) -> Result<A, E>;
+
+/// A fallible computation which reads from an environment of type `Env`, producing either an
+/// `A` or an error `E`.
+///
+/// Combining readers with [and_then](crate::reader::ReaderResult::and_then) passes the same
+/// environment to each of them, and stops at the first error.
+pub struct ReaderResult<Env, A, E> {
+    run: Box<RunResult<Env, A, E>>,
+}
+
+impl<Env: 'static, A: 'static, E: 'static> ReaderResult<Env, A, E> {
+    /// Creates a reader from a fallible function of the environment.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Env) -> Result<A, E> + 'static,
+    {
+        ReaderResult { run: Box::new(f) }
+    }
+
+    /// Creates a reader which extracts a value from the environment, and does not fail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::ReaderResult;
+    /// let len = ReaderResult::<String, usize, ()>::asks(String::len);
+    /// assert_eq!(len.run(&"four".to_owned()), Ok(4));
+    /// ```
+    pub fn asks<F>(f: F) -> Self
+    where
+        F: Fn(&Env) -> A + 'static,
+    {
+        ReaderResult::new(move |env| Ok(f(env)))
+    }
+
+    /// Creates a reader which ignores the environment and succeeds with a value.
+    pub fn ok(a: A) -> Self
+    where
+        A: Clone,
+    {
+        ReaderResult::new(move |_| Ok(a.clone()))
+    }
+
+    /// Creates a reader which ignores the environment and fails with an error.
+    pub fn err(e: E) -> Self
+    where
+        E: Clone,
+    {
+        ReaderResult::new(move |_| Err(e.clone()))
+    }
+
+    /// Creates a reader which ignores the environment and produces a result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::ReaderResult;
+    /// let port = ReaderResult::<(), u16, _>::from_result("8080".parse::<u16>());
+    /// assert_eq!(port.run(&()), Ok(8080));
+    /// ```
+    pub fn from_result(result: Result<A, E>) -> Self
+    where
+        A: Clone,
+        E: Clone,
+    {
+        ReaderResult::new(move |_| result.clone())
+    }
+
+    /// Runs the computation with an environment.
+    pub fn run(&self, env: &Env) -> Result<A, E> {
+        (self.run)(env)
+    }
+
+    /// Converts the reader back into the function it runs.
+    pub fn into_fn(self) -> impl Fn(&Env) -> Result<A, E> {
+        move |env| self.run(env)
+    }
+
+    /// Creates a reader which transforms the value of this one, if it succeeds.
+    pub fn map<B, F>(self, f: F) -> ReaderResult<Env, B, E>
+    where
+        B: 'static,
+        F: Fn(A) -> B + 'static,
+    {
+        ReaderResult::new(move |env| self.run(env).map(&f))
+    }
+
+    /// Creates a reader which transforms the error of this one, if it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::ReaderResult;
+    /// let port = ReaderResult::new(|s: &String| s.parse::<u16>()).map_err(|e| e.to_string());
+    /// assert_eq!(port.run(&"80".to_owned()), Ok(80));
+    /// assert_eq!(port.run(&"x".to_owned()), Err("invalid digit found in string".to_owned()));
+    /// ```
+    pub fn map_err<D, F>(self, f: F) -> ReaderResult<Env, A, D>
+    where
+        D: 'static,
+        F: Fn(E) -> D + 'static,
+    {
+        ReaderResult::new(move |env| self.run(env).map_err(&f))
+    }
+
+    /// Creates a reader which, if this one succeeds, runs the reader that a function of its
+    /// value returns, with the same environment.
+    pub fn and_then<B, F>(self, f: F) -> ReaderResult<Env, B, E>
+    where
+        B: 'static,
+        F: Fn(A) -> ReaderResult<Env, B, E> + 'static,
+    {
+        ReaderResult::new(move |env| f(self.run(env)?).run(env))
+    }
+
+    /// Creates a reader which, if this one succeeds, transforms its value with a fallible
+    /// function which does not need the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::ReaderResult;
+    /// let timeout = ReaderResult::asks(|env: &Vec<(&str, &str)>| {
+    ///     env.iter().find(|(k, _)| *k == "TIMEOUT").map(|(_, v)| v.to_string())
+    /// })
+    /// .and_then_result(|v| v.ok_or("TIMEOUT is not set"));
+    /// assert_eq!(timeout.run(&vec![("TIMEOUT", "30")]), Ok("30".to_owned()));
+    /// assert_eq!(timeout.run(&vec![]), Err("TIMEOUT is not set"));
+    /// ```
+    pub fn and_then_result<B, F>(self, f: F) -> ReaderResult<Env, B, E>
+    where
+        B: 'static,
+        F: Fn(A) -> Result<B, E> + 'static,
+    {
+        ReaderResult::new(move |env| self.run(env).and_then(&f))
+    }
+
+    /// Creates a reader which runs this one with an environment derived from its own. This
+    /// may be a modified copy of the environment, or a part of a larger one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::ReaderResult;
+    /// struct App {
+    ///     db_url: String,
+    /// }
+    ///
+    /// let host = ReaderResult::new(|url: &String| {
+    ///     url.split('@').nth(1).map(str::to_owned).ok_or("no host")
+    /// });
+    /// let host = host.local(|app: &App| app.db_url.clone());
+    /// assert_eq!(host.run(&App { db_url: "pg://me@db".to_owned() }), Ok("db".to_owned()));
+    /// ```
+    pub fn local<Outer, F>(self, f: F) -> ReaderResult<Outer, A, E>
+    where
+        Outer: 'static,
+        F: Fn(&Outer) -> Env + 'static,
+    {
+        ReaderResult::new(move |outer| self.run(&f(outer)))
+    }
+}
+
+impl<Env: Clone + 'static, E: 'static> ReaderResult<Env, Env, E> {
+    /// Creates a reader which produces a copy of the environment itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::reader::ReaderResult;
+    /// let greeting = ReaderResult::<String, String, ()>::ask().map(|name| format!("hi {}", name));
+    /// assert_eq!(greeting.run(&"bo".to_owned()), Ok("hi bo".to_owned()));
+    /// ```
+    pub fn ask() -> Self {
+        ReaderResult::new(|env: &Env| Ok(env.clone()))
+    }
+}
+
+impl<Env, A, E> Debug for ReaderResult<Env, A, E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("ReaderResult(..)")
+    }
+}
@@ -0,0 +1,328 @@
+//! Reducers: accumulations described as values, separately from the iteration which drives
+//! them, so that the same accumulation can be reused wherever items come from.
+//!
+//! ```rust
+//! # use fp_std::reducer::{count, reduce};
+//! let count_words = reduce(count());
+//! assert_eq!(count_words("the quick brown fox".split(' ')), 4);
+//! ```
+
+/* REDUCERS *******************************************************************/
+use core::ops::ControlFlow;
+
+/// An accumulation of items of type `A` into a result of type `B`.
+///
+/// A reduction starts from the state produced by `init`, passes each item to `step` in turn,
+/// and finally converts the state into the result with `complete`. The `step` may stop the
+/// reduction early by returning `ControlFlow::Break`, in which case no more items are consumed
+/// but the state is still completed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{reduce, Reducer};
+/// # use std::ops::ControlFlow;
+/// /// Finds the first item greater than the threshold.
+/// struct FirstAbove(u32);
+///
+/// impl Reducer<u32, Option<u32>> for FirstAbove {
+///     type State = Option<u32>;
+///
+///     fn init(&self) -> Option<u32> {
+///         None
+///     }
+///
+///     fn step(&self, _: Option<u32>, item: u32) -> ControlFlow<Option<u32>, Option<u32>> {
+///         if item > self.0 {
+///             ControlFlow::Break(Some(item))
+///         } else {
+///             ControlFlow::Continue(None)
+///         }
+///     }
+///
+///     fn complete(&self, state: Option<u32>) -> Option<u32> {
+///         state
+///     }
+/// }
+///
+/// assert_eq!(reduce(FirstAbove(2))(1..), Some(3));
+/// ```
+pub trait Reducer<A, B> {
+    /// The accumulated state of a reduction in progress.
+    type State;
+
+    /// Produces the state from which a reduction starts.
+    fn init(&self) -> Self::State;
+
+    /// Accumulates an item into the state, either continuing or stopping the reduction.
+    fn step(&self, state: Self::State, item: A) -> ControlFlow<Self::State, Self::State>;
+
+    /// Converts the final state into the result of the reduction.
+    fn complete(&self, state: Self::State) -> B;
+}
+
+/// Creates a function which reduces every item of a list with a reducer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{reduce, sum};
+/// let total = reduce(sum());
+/// assert_eq!(total(vec![1, 2, 3]), 6);
+/// ```
+pub fn reduce<I, A, B, R>(reducer: R) -> impl Fn(I) -> B
+where
+    I: IntoIterator<Item = A>,
+    R: Reducer<A, B>,
+{
+    move |iter| {
+        let mut state = reducer.init();
+        for item in iter {
+            match reducer.step(state, item) {
+                ControlFlow::Continue(next) => state = next,
+                ControlFlow::Break(done) => return reducer.complete(done),
+            }
+        }
+        reducer.complete(state)
+    }
+}
+
+/* STANDARD REDUCERS **********************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Add;
+
+/// A reducer which collects items into a `Vec`. See [into_vec](crate::reducer::into_vec).
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntoVec;
+
+#[cfg(feature = "alloc")]
+impl<A> Reducer<A, Vec<A>> for IntoVec {
+    type State = Vec<A>;
+
+    fn init(&self) -> Vec<A> {
+        Vec::new()
+    }
+
+    fn step(&self, mut state: Vec<A>, item: A) -> ControlFlow<Vec<A>, Vec<A>> {
+        state.push(item);
+        ControlFlow::Continue(state)
+    }
+
+    fn complete(&self, state: Vec<A>) -> Vec<A> {
+        state
+    }
+}
+
+/// Creates a reducer which collects items into a `Vec`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{into_vec, reduce};
+/// assert_eq!(reduce(into_vec())("abc".chars()), vec!['a', 'b', 'c']);
+/// ```
+#[cfg(feature = "alloc")]
+pub const fn into_vec() -> IntoVec {
+    IntoVec
+}
+
+/// A reducer which counts items. See [count](crate::reducer::count).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Count;
+
+impl<A> Reducer<A, usize> for Count {
+    type State = usize;
+
+    fn init(&self) -> usize {
+        0
+    }
+
+    fn step(&self, state: usize, _: A) -> ControlFlow<usize, usize> {
+        ControlFlow::Continue(state + 1)
+    }
+
+    fn complete(&self, state: usize) -> usize {
+        state
+    }
+}
+
+/// Creates a reducer which counts items.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{count, reduce};
+/// assert_eq!(reduce(count())(vec!['a', 'b']), 2);
+/// ```
+pub const fn count() -> Count {
+    Count
+}
+
+/// A reducer which adds items together, starting from their default value. See
+/// [sum](crate::reducer::sum).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sum;
+
+impl<A> Reducer<A, A> for Sum
+where
+    A: Add<Output = A> + Default,
+{
+    type State = A;
+
+    fn init(&self) -> A {
+        A::default()
+    }
+
+    fn step(&self, state: A, item: A) -> ControlFlow<A, A> {
+        ControlFlow::Continue(state + item)
+    }
+
+    fn complete(&self, state: A) -> A {
+        state
+    }
+}
+
+/// Creates a reducer which adds items together, starting from their default value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{reduce, sum};
+/// assert_eq!(reduce(sum())(vec![1.5, 2.5]), 4.0);
+/// assert_eq!(reduce(sum())(Vec::<u32>::new()), 0);
+/// ```
+pub const fn sum() -> Sum {
+    Sum
+}
+
+/// A reducer which keeps only the last item. See [last](crate::reducer::last).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Last;
+
+impl<A> Reducer<A, Option<A>> for Last {
+    type State = Option<A>;
+
+    fn init(&self) -> Option<A> {
+        None
+    }
+
+    fn step(&self, _: Option<A>, item: A) -> ControlFlow<Option<A>, Option<A>> {
+        ControlFlow::Continue(Some(item))
+    }
+
+    fn complete(&self, state: Option<A>) -> Option<A> {
+        state
+    }
+}
+
+/// Creates a reducer which keeps only the last item, if there are any.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{last, reduce};
+/// assert_eq!(reduce(last())(vec![1, 2, 3]), Some(3));
+/// assert_eq!(reduce(last())(Vec::<u32>::new()), None);
+/// ```
+pub const fn last() -> Last {
+    Last
+}
+
+/// A reducer built from a folding function. See [fold](crate::reducer::fold).
+#[derive(Clone, Copy, Debug)]
+pub struct Fold<B, F> {
+    init: B,
+    f: F,
+}
+
+impl<A, B, F> Reducer<A, B> for Fold<B, F>
+where
+    B: Clone,
+    F: Fn(B, A) -> B,
+{
+    type State = B;
+
+    fn init(&self) -> B {
+        self.init.clone()
+    }
+
+    fn step(&self, state: B, item: A) -> ControlFlow<B, B> {
+        ControlFlow::Continue((self.f)(state, item))
+    }
+
+    fn complete(&self, state: B) -> B {
+        state
+    }
+}
+
+/// Creates a reducer which folds every item into an accumulator, starting from `init`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{fold, reduce};
+/// let product = fold(1, |acc, x: u64| acc * x);
+/// assert_eq!(reduce(product)(1..=5), 120);
+/// ```
+pub fn fold<A, B, F>(init: B, f: F) -> Fold<B, F>
+where
+    F: Fn(B, A) -> B,
+{
+    Fold { init, f }
+}
+
+/// A reducer built from a folding function which may stop early. See
+/// [fold_while](crate::reducer::fold_while).
+#[derive(Clone, Copy, Debug)]
+pub struct FoldWhile<B, F> {
+    init: B,
+    f: F,
+}
+
+impl<A, B, F> Reducer<A, B> for FoldWhile<B, F>
+where
+    B: Clone,
+    F: Fn(B, A) -> ControlFlow<B, B>,
+{
+    type State = B;
+
+    fn init(&self) -> B {
+        self.init.clone()
+    }
+
+    fn step(&self, state: B, item: A) -> ControlFlow<B, B> {
+        (self.f)(state, item)
+    }
+
+    fn complete(&self, state: B) -> B {
+        state
+    }
+}
+
+/// Creates a reducer which folds items into an accumulator, starting from `init`, until the
+/// folding function breaks. This is the reducer behind
+/// [list::fold_while](crate::list::fold_while).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::{fold_while, reduce};
+/// # use std::ops::ControlFlow;
+/// let longest_prefix = fold_while(String::new(), |mut acc, word: &str| {
+///     if acc.len() + word.len() > 6 {
+///         ControlFlow::Break(acc)
+///     } else {
+///         acc.push_str(word);
+///         ControlFlow::Continue(acc)
+///     }
+/// });
+/// assert_eq!(reduce(longest_prefix)(vec!["ab", "cde", "fgh", "i"]), "abcde");
+/// ```
+pub fn fold_while<A, B, F>(init: B, f: F) -> FoldWhile<B, F>
+where
+    F: Fn(B, A) -> ControlFlow<B, B>,
+{
+    FoldWhile { init, f }
+}
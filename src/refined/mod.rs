@@ -0,0 +1,268 @@
+//! Newtypes whose values are checked against a predicate when they are constructed, so that
+//! once a value has been validated, its type says so.
+//!
+//! ```rust
+//! # use fp_std::error::Error;
+//! # use fp_std::refined::{And, InRange, NonZero, Refined};
+//! type Percent = Refined<u8, InRange<0, 100>>;
+//! type Quantity = Refined<u32, And<NonZero, InRange<1, 999>>>;
+//!
+//! fn discount(price: u32, percent: Percent) -> u32 {
+//!     price - price * u32::from(*percent) / 100
+//! }
+//!
+//! assert_eq!(discount(200, Percent::new(25).unwrap()), 150);
+//! assert_eq!(Percent::new(101), Err(Error::RefinementFailed));
+//! assert!(Quantity::new(0).is_err());
+//! ```
+
+/* REFINED VALUES *************************************************************/
+use crate::error::Error;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// A predicate on values of type `T`, which is named by a type so that it can be part of the
+/// type of a [Refined](crate::refined::Refined) value.
+pub trait Refinement<T: ?Sized> {
+    /// Whether the value satisfies the predicate.
+    fn test(value: &T) -> bool;
+}
+
+/// A value of type `T` which is known to satisfy the [Refinement](crate::refined::Refinement)
+/// `P`.
+///
+/// Since the value is checked when it is constructed, and can only be borrowed immutably
+/// after that, it satisfies the refinement for as long as it exists.
+pub struct Refined<T, P> {
+    value: T,
+    refinement: PhantomData<fn() -> P>,
+}
+
+impl<T, P: Refinement<T>> Refined<T, P> {
+    /// Checks that a value satisfies the refinement, failing with
+    /// [RefinementFailed](crate::error::Error::RefinementFailed) if it does not.
+    pub fn new(value: T) -> Result<Self, Error> {
+        if P::test(&value) {
+            Ok(Refined {
+                value,
+                refinement: PhantomData,
+            })
+        } else {
+            Err(Error::RefinementFailed)
+        }
+    }
+
+    /// Transforms the value, checking that the result still satisfies the refinement.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::refined::{NonZero, Refined};
+    /// let stock: Refined<u32, NonZero> = Refined::new(2).unwrap();
+    /// let stock = stock.try_map(|n| n - 1).unwrap();
+    /// assert_eq!(*stock, 1);
+    /// assert!(stock.try_map(|n| n - 1).is_err());
+    /// ```
+    pub fn try_map<F>(self, f: F) -> Result<Self, Error>
+    where
+        F: FnOnce(T) -> T,
+    {
+        Refined::new(f(self.value))
+    }
+}
+
+impl<T, P> Refined<T, P> {
+    /// Removes the refinement, returning the underlying value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, P> Deref for Refined<T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, P> AsRef<T> for Refined<T, P> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Clone, P> Clone for Refined<T, P> {
+    fn clone(&self) -> Self {
+        Refined {
+            value: self.value.clone(),
+            refinement: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy, P> Copy for Refined<T, P> {}
+
+impl<T: Debug, P> Debug for Refined<T, P> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: Display, P> Display for Refined<T, P> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: PartialEq, P> PartialEq for Refined<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, P> Eq for Refined<T, P> {}
+
+impl<T: PartialOrd, P> PartialOrd for Refined<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, P> Ord for Refined<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, P> Hash for Refined<T, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+/* REFINEMENTS ****************************************************************/
+use core::convert::TryInto;
+
+/// The refinement of values which are not equal to their type's default, such as numbers
+/// which are not zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::refined::{NonZero, Refined};
+/// assert!(Refined::<i64, NonZero>::new(-1).is_ok());
+/// assert!(Refined::<f64, NonZero>::new(0.0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NonZero;
+
+impl<T: Default + PartialEq> Refinement<T> for NonZero {
+    fn test(value: &T) -> bool {
+        *value != T::default()
+    }
+}
+
+/// The refinement of strings which contain something other than whitespace.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::refined::{NonBlank, Refined};
+/// assert!(Refined::<String, NonBlank>::new(" name ".to_owned()).is_ok());
+/// assert!(Refined::<&str, NonBlank>::new(" \t").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NonBlank;
+
+impl<T: AsRef<str> + ?Sized> Refinement<T> for NonBlank {
+    fn test(value: &T) -> bool {
+        !value.as_ref().trim().is_empty()
+    }
+}
+
+/// The refinement of integers from `MIN` to `MAX`, inclusive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::refined::{InRange, Refined};
+/// type Hour = Refined<u8, InRange<0, 23>>;
+/// assert!(Hour::new(23).is_ok());
+/// assert!(Hour::new(24).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InRange<const MIN: i128, const MAX: i128>;
+
+impl<T, const MIN: i128, const MAX: i128> Refinement<T> for InRange<MIN, MAX>
+where
+    T: Clone + TryInto<i128>,
+{
+    fn test(value: &T) -> bool {
+        value
+            .clone()
+            .try_into()
+            .is_ok_and(|value| MIN <= value && value <= MAX)
+    }
+}
+
+/* COMBINATORS ****************************************************************/
+
+/// The refinement of values which satisfy both `P` and `Q`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::refined::{And, InRange, Refined};
+/// type Teen = Refined<u8, And<InRange<13, 255>, InRange<0, 19>>>;
+/// assert!(Teen::new(15).is_ok());
+/// assert!(Teen::new(20).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct And<P, Q>(PhantomData<fn() -> (P, Q)>);
+
+impl<T: ?Sized, P: Refinement<T>, Q: Refinement<T>> Refinement<T> for And<P, Q> {
+    fn test(value: &T) -> bool {
+        P::test(value) && Q::test(value)
+    }
+}
+
+/// The refinement of values which satisfy at least one of `P` and `Q`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::refined::{InRange, Or, Refined};
+/// type OffPeak = Refined<u8, Or<InRange<0, 6>, InRange<22, 23>>>;
+/// assert!(OffPeak::new(23).is_ok());
+/// assert!(OffPeak::new(12).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Or<P, Q>(PhantomData<fn() -> (P, Q)>);
+
+impl<T: ?Sized, P: Refinement<T>, Q: Refinement<T>> Refinement<T> for Or<P, Q> {
+    fn test(value: &T) -> bool {
+        P::test(value) || Q::test(value)
+    }
+}
+
+/// The refinement of values which do not satisfy `P`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::refined::{NonZero, Not, Refined};
+/// assert!(Refined::<u8, Not<NonZero>>::new(0).is_ok());
+/// assert!(Refined::<u8, Not<NonZero>>::new(1).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Not<P>(PhantomData<fn() -> P>);
+
+impl<T: ?Sized, P: Refinement<T>> Refinement<T> for Not<P> {
+    fn test(value: &T) -> bool {
+        !P::test(value)
+    }
+}
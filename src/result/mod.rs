@@ -0,0 +1,512 @@
+//! Functions for manipulating results.
+
+/* COMBINATORS ****************************************************************/
+
+/// Creates a function which transforms a `Result`, with `f` if it is `Ok`, or with `g` if it is
+/// `Err`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::bimap;
+/// let describe = bimap(|n: u32| n * 2, |e: &str| e.len());
+/// assert_eq!(describe(Ok(4)), Ok(8));
+/// assert_eq!(describe(Err("bad")), Err(3));
+/// ```
+pub fn bimap<T, E, U, F, G, H>(f: G, g: H) -> impl Fn(Result<T, E>) -> Result<U, F>
+where
+    G: Fn(T) -> U,
+    H: Fn(E) -> F,
+{
+    move |result| match result {
+        Ok(t) => Ok(f(t)),
+        Err(e) => Err(g(e)),
+    }
+}
+
+/// Creates a function which transforms the error of a `Result`, if there is one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::map_err_fn;
+/// # use std::num::ParseIntError;
+/// let parsed: Vec<Result<u32, String>> = vec!["1", "x"]
+///     .into_iter()
+///     .map(str::parse::<u32>)
+///     .map(map_err_fn(|e: ParseIntError| e.to_string()))
+///     .collect();
+/// assert_eq!(parsed[0], Ok(1));
+/// assert_eq!(parsed[1], Err("invalid digit found in string".to_owned()));
+/// ```
+pub fn map_err_fn<T, E, F, G>(f: G) -> impl Fn(Result<T, E>) -> Result<T, F>
+where
+    G: Fn(E) -> F,
+{
+    move |result| result.map_err(&f)
+}
+
+/// Lifts a function of two values into a function of two `Result`s, which applies it if both
+/// of them are `Ok`, or returns the first error otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result;
+/// let add = result::lift2(|a: u32, b: u32| a + b);
+/// assert_eq!(add(Ok(1), Ok(2)), Ok::<_, &str>(3));
+/// assert_eq!(add(Err("no a"), Err("no b")), Err("no a"));
+/// ```
+pub fn lift2<A, B, C, E, F>(f: F) -> impl Fn(Result<A, E>, Result<B, E>) -> Result<C, E>
+where
+    F: Fn(A, B) -> C,
+{
+    move |a, b| Ok(f(a?, b?))
+}
+
+/// Lifts a function of three values into a function of three `Result`s, which applies it if
+/// all of them are `Ok`, or returns the first error otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result;
+/// let parse = |s: &str| s.parse::<u8>().map_err(|_| format!("bad part {:?}", s));
+/// let rgb = result::lift3(|r, g, b| [r, g, b]);
+/// assert_eq!(rgb(parse("255"), parse("128"), parse("0")), Ok([255, 128, 0]));
+/// assert_eq!(rgb(parse("255"), parse("x"), parse("y")), Err("bad part \"x\"".to_owned()));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn lift3<A, B, C, D, E, F>(
+    f: F,
+) -> impl Fn(Result<A, E>, Result<B, E>, Result<C, E>) -> Result<D, E>
+where
+    F: Fn(A, B, C) -> D,
+{
+    move |a, b, c| Ok(f(a?, b?, c?))
+}
+
+/* ACCUMULATION ***************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Collects keyed results into a map of the successes, or if any of them failed, a map of all
+/// of the errors instead. Each value keeps the key it was paired with.
+///
+/// If a key appears more than once, the last value for it is kept.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::collect_errors;
+/// let form = vec![("age", "29"), ("height", "180")];
+/// let parsed = collect_errors(form.into_iter().map(|(k, v)| (k, v.parse::<u32>())));
+/// assert_eq!(parsed.unwrap()["height"], 180);
+///
+/// let form = vec![("age", "old"), ("height", "180"), ("weight", "")];
+/// let errors = collect_errors(form.into_iter().map(|(k, v)| (k, v.parse::<u32>())))
+///     .unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert!(errors.contains_key("age"));
+/// assert!(errors.contains_key("weight"));
+/// ```
+#[cfg(feature = "std")]
+pub fn collect_errors<I, K, T, E>(iter: I) -> Result<HashMap<K, T>, HashMap<K, E>>
+where
+    I: IntoIterator<Item = (K, Result<T, E>)>,
+    K: Eq + Hash,
+{
+    let mut successes = HashMap::new();
+    let mut errors = HashMap::new();
+    for (key, result) in iter {
+        match result {
+            Ok(value) if errors.is_empty() => {
+                successes.insert(key, value);
+            }
+            Ok(..) => {}
+            Err(error) => {
+                errors.insert(key, error);
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(successes)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Collects the values of some `Result`s into a `Vec`, if every one of them is `Ok`.
+/// Otherwise, the first error is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::sequence;
+/// assert_eq!(sequence(vec![Ok::<u32, &str>(1), Ok(2)]), Ok(vec![1, 2]));
+/// assert_eq!(sequence(vec![Ok(1), Err("a"), Err("b")]), Err("a"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sequence<I, T, E>(results: I) -> Result<Vec<T>, E>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    results.into_iter().collect()
+}
+
+/// Creates a function which applies a fallible function to every item, collecting the values
+/// into a `Vec` if it succeeded for every item. Otherwise, the first error is returned, and
+/// the items after it are not visited.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::traverse;
+/// let parse_all = traverse(str::parse::<u32>);
+/// assert_eq!(parse_all(vec!["1", "2"]), Ok(vec![1, 2]));
+/// assert!(parse_all(vec!["1", "x"]).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn traverse<I, A, T, E, F>(f: F) -> impl Fn(I) -> Result<Vec<T>, E>
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> Result<T, E>,
+{
+    move |iter| iter.into_iter().map(&f).collect()
+}
+
+/// Splits some `Result`s into the values of those which are `Ok`, and the errors of those
+/// which are `Err`, each in their original order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::partition_results;
+/// let parsed = vec!["1", "x", "3"].into_iter().map(str::parse::<u32>);
+/// let (numbers, errors) = partition_results(parsed);
+/// assert_eq!(numbers, vec![1, 3]);
+/// assert_eq!(errors.len(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn partition_results<I, T, E>(results: I) -> (Vec<T>, Vec<E>)
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for result in results {
+        match result {
+            Ok(t) => oks.push(t),
+            Err(e) => errs.push(e),
+        }
+    }
+    (oks, errs)
+}
+
+/* RETRIES ********************************************************************/
+#[cfg(feature = "time")]
+use core::fmt::{self, Formatter};
+#[cfg(feature = "time")]
+use core::hash::{BuildHasher, Hasher};
+#[cfg(feature = "time")]
+use std::collections::hash_map::RandomState;
+#[cfg(feature = "time")]
+use std::time::Duration;
+
+/// Describes when, and how soon, a failed operation should be tried again.
+///
+/// A new policy makes a number of attempts in total, one immediately after another, retrying
+/// after any error. Each part of that can be changed with the builder methods.
+///
+/// Requires the `time` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::RetryPolicy;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::<std::io::Error>::new(5)
+///     .with_delay(Duration::from_millis(100))
+///     .with_backoff(2.0)
+///     .with_max_delay(Duration::from_millis(300));
+/// assert_eq!(policy.delay_before(1), Duration::from_millis(100));
+/// assert_eq!(policy.delay_before(2), Duration::from_millis(200));
+/// assert_eq!(policy.delay_before(3), Duration::from_millis(300));
+/// ```
+#[cfg(feature = "time")]
+pub struct RetryPolicy<E> {
+    max_attempts: u32,
+    delay: Duration,
+    multiplier: f64,
+    max_delay: Duration,
+    jitter: f64,
+    retryable: Box<dyn Fn(&E) -> bool + Send + Sync>,
+}
+
+#[cfg(feature = "time")]
+impl<E> RetryPolicy<E> {
+    /// Creates a policy which makes at most `max_attempts` attempts, including the first. At
+    /// least one attempt is always made.
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            delay: Duration::from_secs(0),
+            multiplier: 1.0,
+            max_delay: Duration::MAX,
+            jitter: 0.0,
+            retryable: Box::new(|_| true),
+        }
+    }
+
+    /// Waits for `delay` before the first retry.
+    pub fn with_delay(self, delay: Duration) -> Self {
+        RetryPolicy { delay, ..self }
+    }
+
+    /// Multiplies the delay by `multiplier` after each retry, for exponential backoff.
+    pub fn with_backoff(self, multiplier: f64) -> Self {
+        RetryPolicy { multiplier, ..self }
+    }
+
+    /// Never waits longer than `max_delay` between attempts.
+    pub fn with_max_delay(self, max_delay: Duration) -> Self {
+        RetryPolicy { max_delay, ..self }
+    }
+
+    /// Shortens each delay by a random amount of up to `fraction` of it, so that many clients
+    /// retrying at once spread out rather than retrying in lockstep. The fraction is clamped
+    /// between `0.0` and `1.0`.
+    pub fn with_jitter(self, fraction: f64) -> Self {
+        RetryPolicy {
+            jitter: fraction.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Only retries after errors which satisfy the predicate. Other errors are returned
+    /// immediately.
+    pub fn retry_if<P>(self, retryable: P) -> Self
+    where
+        P: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        RetryPolicy {
+            retryable: Box::new(retryable),
+            ..self
+        }
+    }
+
+    /// The greatest number of attempts this policy makes, including the first.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The delay before the given retry, counting from 1, before any jitter is applied.
+    pub fn delay_before(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.powi(retry.saturating_sub(1) as i32);
+        Duration::try_from_secs_f64(self.delay.as_secs_f64() * factor)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay)
+    }
+
+    /// Decides whether to retry after the given number of failed attempts, the last of which
+    /// failed with `error`.
+    pub fn should_retry(&self, attempts: u32, error: &E) -> bool {
+        attempts < self.max_attempts && (self.retryable)(error)
+    }
+
+    pub(crate) fn jittered_delay_before(&self, retry: u32) -> Duration {
+        let delay = self.delay_before(retry);
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        let bits = RandomState::new().build_hasher().finish();
+        let random = (bits >> 11) as f64 / (1u64 << 53) as f64;
+        delay.mul_f64(1.0 - self.jitter * random)
+    }
+}
+
+#[cfg(feature = "time")]
+impl<E> fmt::Debug for RetryPolicy<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("delay", &self.delay)
+            .field("multiplier", &self.multiplier)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs an asynchronous operation, retrying it according to a
+/// [RetryPolicy](crate::result::RetryPolicy) until it succeeds, fails with an error which should
+/// not be retried, or runs out of attempts. The last error is returned if it never succeeds.
+///
+/// Requires the `async` and `time` features.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::{retry_async, RetryPolicy};
+/// # use futures::executor::block_on;
+/// # use std::cell::Cell;
+/// # use std::time::Duration;
+/// let attempts = Cell::new(0);
+/// let flaky = || {
+///     attempts.set(attempts.get() + 1);
+///     let attempt = attempts.get();
+///     async move { if attempt < 3 { Err("unavailable") } else { Ok(attempt) } }
+/// };
+/// let policy = RetryPolicy::new(5)
+///     .with_delay(Duration::from_millis(1))
+///     .with_backoff(2.0)
+///     .with_jitter(0.5)
+///     .retry_if(|error: &&str| *error == "unavailable");
+/// assert_eq!(block_on(retry_async(&policy, flaky)), Ok(3));
+///
+/// let policy = RetryPolicy::new(5).retry_if(|error: &&str| *error == "unavailable");
+/// let forbidden = || async { Err::<(), _>("forbidden") };
+/// assert_eq!(block_on(retry_async(&policy, forbidden)), Err("forbidden"));
+/// ```
+#[cfg(all(feature = "async", feature = "time"))]
+pub async fn retry_async<T, E, Fut, F>(policy: &RetryPolicy<E>, f: F) -> Result<T, E>
+where
+    F: Fn() -> Fut,
+    Fut: core::future::Future<Output = Result<T, E>>,
+{
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(error) if policy.should_retry(attempts, &error) => {
+                let delay = policy.jittered_delay_before(attempts);
+                if delay > Duration::from_secs(0) {
+                    futures_timer::Delay::new(delay).await;
+                }
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/* ASYNCHRONOUS TRAVERSALS ****************************************************/
+#[cfg(feature = "async")]
+use core::future::Future;
+
+/// Applies an asynchronous fallible function to every item, one at a time, collecting the
+/// values if it succeeded for every item. Otherwise, the first error is returned, and the
+/// items after it are not visited. To run them concurrently instead, see
+/// [traverse_limit](crate::future::traverse_limit).
+///
+/// Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::traverse_async;
+/// # use futures::executor::block_on;
+/// async fn fetch(id: u32) -> Result<String, String> {
+///     if id < 10 { Ok(format!("user {}", id)) } else { Err(format!("no user {}", id)) }
+/// }
+/// assert_eq!(
+///     block_on(traverse_async(fetch, vec![1, 2])),
+///     Ok(vec!["user 1".to_owned(), "user 2".to_owned()]),
+/// );
+/// assert_eq!(block_on(traverse_async(fetch, vec![1, 20, 30])), Err("no user 20".to_owned()));
+/// ```
+#[cfg(feature = "async")]
+pub async fn traverse_async<A, T, E, Fut, F, I>(f: F, iter: I) -> Result<Vec<T>, E>
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut values = Vec::new();
+    for item in iter {
+        values.push(f(item).await?);
+    }
+    Ok(values)
+}
+
+/// Applies an asynchronous fallible function to every item, one at a time, collecting the
+/// values if it succeeded for every item. Every item is visited; if any of them fail, all of
+/// the errors are collected in the original order instead.
+///
+/// Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::traverse_async_accumulate;
+/// # use futures::executor::block_on;
+/// async fn validate(name: &str) -> Result<&str, String> {
+///     if name.is_empty() { Err("empty name".to_owned()) } else { Ok(name) }
+/// }
+/// assert_eq!(block_on(traverse_async_accumulate(validate, vec!["a", "b"])), Ok(vec!["a", "b"]));
+/// assert_eq!(
+///     block_on(traverse_async_accumulate(validate, vec!["", "b", ""])),
+///     Err(vec!["empty name".to_owned(), "empty name".to_owned()]),
+/// );
+/// ```
+#[cfg(feature = "async")]
+pub async fn traverse_async_accumulate<A, T, E, Fut, F, I>(f: F, iter: I) -> Result<Vec<T>, Vec<E>>
+where
+    I: IntoIterator<Item = A>,
+    F: Fn(A) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for item in iter {
+        match f(item).await {
+            Ok(value) if errors.is_empty() => values.push(value),
+            Ok(..) => {}
+            Err(error) => errors.push(error),
+        }
+    }
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
+
+/* TRACING ********************************************************************/
+#[cfg(any(feature = "log", feature = "tracing"))]
+use crate::function::warn_event;
+#[cfg(any(feature = "log", feature = "tracing"))]
+use core::fmt::Debug;
+
+/// Creates a function which logs the error of a failed result under the given label, then
+/// returns the result unchanged. Successful results are not logged. The event is emitted at the
+/// warn level through [log](https://crates.io/crates/log) and
+/// [tracing](https://crates.io/crates/tracing), according to which features are enabled.
+///
+/// Requires the `log` or `tracing` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::result::trace_err;
+/// let parsed: Vec<Result<u32, _>> = vec!["1", "x"]
+///     .into_iter()
+///     .map(str::parse)
+///     .map(trace_err("parse"))
+///     .collect();
+/// assert_eq!(parsed[0], Ok(1));
+/// assert!(parsed[1].is_err());
+/// ```
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub fn trace_err<'a, T, E: Debug>(label: &'a str) -> impl Fn(Result<T, E>) -> Result<T, E> + 'a {
+    move |result| {
+        if let Err(error) = &result {
+            warn_event(label, error);
+        }
+        result
+    }
+}
@@ -0,0 +1,192 @@
+//! Lazy sequences, which may be infinite, and curried functions for consuming them, so that
+//! they can be used in point-free pipelines.
+//!
+//! No item of a sequence is computed until it is needed, so an infinite sequence is fine as
+//! long as only a finite part of it is consumed.
+//!
+//! ```rust
+//! # use fp_std::function::compose2;
+//! # use fp_std::seq::{iterate, take, take_while};
+//! let small_powers = compose2(take_while(|n: &u32| *n < 100), take(10));
+//! let powers: Vec<u32> = small_powers(iterate(|n| n * 3, 1)).collect();
+//! assert_eq!(powers, vec![1, 3, 9, 27, 81]);
+//! ```
+
+/* CONSTRUCTORS ***************************************************************/
+use core::iter::{self, Cycle, Take, TakeWhile};
+
+/// Creates the infinite sequence of `init`, `f(init)`, `f(f(init))`, and so on.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::seq::iterate;
+/// let doubling: Vec<u32> = iterate(|n| n * 2, 1).take(5).collect();
+/// assert_eq!(doubling, vec![1, 2, 4, 8, 16]);
+/// ```
+pub fn iterate<T, F>(f: F, init: T) -> impl Iterator<Item = T>
+where
+    F: Fn(&T) -> T,
+{
+    iter::successors(Some(init), move |previous| Some(f(previous)))
+}
+
+/// Creates a sequence by repeatedly producing an item and the next seed from a seed, until
+/// the function returns `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::seq::unfold;
+/// let digits: Vec<u32> = unfold(|n: u32| if n == 0 { None } else { Some((n % 10, n / 10)) }, 472)
+///     .collect();
+/// assert_eq!(digits, vec![2, 7, 4]);
+/// ```
+pub fn unfold<S, T, F>(f: F, seed: S) -> impl Iterator<Item = T>
+where
+    F: Fn(S) -> Option<(T, S)>,
+{
+    let mut seed = Some(seed);
+    iter::from_fn(move || {
+        let (item, next) = f(seed.take()?)?;
+        seed = Some(next);
+        Some(item)
+    })
+}
+
+/// Creates the infinite sequence which repeats the items of a list over and over. If the
+/// list is empty, so is the sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::seq::cycle;
+/// let turns: Vec<&str> = cycle(vec!["ann", "bo"]).take(5).collect();
+/// assert_eq!(turns, vec!["ann", "bo", "ann", "bo", "ann"]);
+/// ```
+pub fn cycle<I>(iter: I) -> Cycle<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+{
+    iter.into_iter().cycle()
+}
+
+/// Creates the infinite sequence of the values which a function returns each time it is
+/// called.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::seq::repeat_with;
+/// let mut next_id = 0;
+/// let ids: Vec<u32> = repeat_with(|| {
+///     next_id += 1;
+///     next_id
+/// })
+/// .take(3)
+/// .collect();
+/// assert_eq!(ids, vec![1, 2, 3]);
+/// ```
+pub fn repeat_with<T, F>(f: F) -> impl Iterator<Item = T>
+where
+    F: FnMut() -> T,
+{
+    iter::repeat_with(f)
+}
+
+/* CONSUMERS ******************************************************************/
+
+/// Creates a function which takes the first `n` items of a sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::seq::{repeat_with, take};
+/// let first_three = take(3);
+/// assert_eq!(first_three(repeat_with(|| 'x')).collect::<String>(), "xxx");
+/// ```
+pub fn take<I>(n: usize) -> impl Fn(I) -> Take<I::IntoIter>
+where
+    I: IntoIterator,
+{
+    move |iter| iter.into_iter().take(n)
+}
+
+/// Creates a function which takes the items of a sequence for as long as they satisfy a
+/// predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::seq::{iterate, take_while};
+/// let below_ten = take_while(|n: &u32| *n < 10);
+/// assert_eq!(below_ten(iterate(|n| n + 4, 1)).collect::<Vec<_>>(), vec![1, 5, 9]);
+/// ```
+pub fn take_while<I, P>(pred: P) -> impl Fn(I) -> TakeWhile<I::IntoIter, P>
+where
+    I: IntoIterator,
+    P: Fn(&I::Item) -> bool + Clone,
+{
+    move |iter| iter.into_iter().take_while(pred.clone())
+}
+
+/// A sequence which combines the items of two other sequences, pairwise, with a function.
+/// Constructed by [zip_with](crate::seq::zip_with).
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipWith<I, J, F> {
+    left: I,
+    right: J,
+    f: F,
+}
+
+impl<I, J, C, F> Iterator for ZipWith<I, J, F>
+where
+    I: Iterator,
+    J: Iterator,
+    F: Fn(I::Item, J::Item) -> C,
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<C> {
+        let a = self.left.next()?;
+        let b = self.right.next()?;
+        Some((self.f)(a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_min, left_max) = self.left.size_hint();
+        let (right_min, right_max) = self.right.size_hint();
+        let max = match (left_max, right_max) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (left_min.min(right_min), max)
+    }
+}
+
+/// Creates a function which combines the items of two sequences, pairwise, with a function,
+/// stopping when either runs out. Unlike [list::zip_with](crate::list::zip_with), the result
+/// is another lazy sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::seq::{iterate, zip_with};
+/// let label = zip_with(|n: u32, name: &str| format!("{}. {}", n, name));
+/// let lines: Vec<String> = label(iterate(|n| n + 1, 1), vec!["ann", "bo"]).collect();
+/// assert_eq!(lines, vec!["1. ann", "2. bo"]);
+/// ```
+pub fn zip_with<I, J, C, F>(f: F) -> impl Fn(I, J) -> ZipWith<I::IntoIter, J::IntoIter, F>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    F: Fn(I::Item, J::Item) -> C + Clone,
+{
+    move |left, right| ZipWith {
+        left: left.into_iter(),
+        right: right.into_iter(),
+        f: f.clone(),
+    }
+}
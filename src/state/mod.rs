@@ -0,0 +1,273 @@
+//! Computations which thread a state from one step to the next, so that interpreters and
+//! parsers can be built from small steps without passing the state around by hand.
+//!
+//! ```rust
+//! # use fp_std::state::StateResult;
+//! // A stack machine, which fails if it runs out of operands.
+//! fn pop() -> StateResult<Vec<i64>, i64, &'static str> {
+//!     StateResult::new(|mut stack: Vec<i64>| match stack.pop() {
+//!         Some(n) => Ok((n, stack)),
+//!         None => Err("stack underflow"),
+//!     })
+//! }
+//!
+//! fn push(n: i64) -> StateResult<Vec<i64>, (), &'static str> {
+//!     StateResult::modify(move |mut stack: Vec<i64>| {
+//!         stack.push(n);
+//!         stack
+//!     })
+//! }
+//!
+//! let add = pop().and_then(|a| pop().and_then(move |b| push(a + b)));
+//! assert_eq!(add.exec(vec![1, 2, 3]), Ok(vec![1, 5]));
+//! assert_eq!(add.exec(vec![1]), Err("stack underflow"));
+//! ```
+
+/* FALLIBLE STATE *************************************************************/
+use alloc::boxed::Box;
+use core::fmt::{self, Debug, Formatter};
+
+type RunResult<S, A, E> = dyn Fn(S) -> Result<(A, S), E>;
+
+/// A fallible computation which takes a state of type `S`, and produces an `A` along with the
+/// next state, or an error `E`.
+///
+/// Combining computations with [and_then](crate::state::StateResult::and_then) passes the
+/// state produced by each to the next, and stops at the first error, discarding the state.
+pub struct StateResult<S, A, E> {
+    run: Box<RunResult<S, A, E>>,
+}
+
+impl<S: 'static, A: 'static, E: 'static> StateResult<S, A, E> {
+    /// Creates a computation from a fallible function of the state.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(S) -> Result<(A, S), E> + 'static,
+    {
+        StateResult { run: Box::new(f) }
+    }
+
+    /// Creates a computation which extracts a value from the state, leaving it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::state::StateResult;
+    /// let depth = StateResult::<Vec<u8>, usize, ()>::gets(Vec::len);
+    /// assert_eq!(depth.run(vec![1, 2]), Ok((2, vec![1, 2])));
+    /// ```
+    pub fn gets<F>(f: F) -> Self
+    where
+        F: Fn(&S) -> A + 'static,
+    {
+        StateResult::new(move |s| Ok((f(&s), s)))
+    }
+
+    /// Creates a computation which succeeds with a value, leaving the state unchanged.
+    pub fn ok(a: A) -> Self
+    where
+        A: Clone,
+    {
+        StateResult::new(move |s| Ok((a.clone(), s)))
+    }
+
+    /// Creates a computation which fails with an error.
+    pub fn err(e: E) -> Self
+    where
+        E: Clone,
+    {
+        StateResult::new(move |_| Err(e.clone()))
+    }
+
+    /// Creates a computation which produces a result, leaving the state unchanged if it is
+    /// `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::state::StateResult;
+    /// let limit = StateResult::<u32, u16, _>::from_result("64".parse::<u16>());
+    /// assert_eq!(limit.run(0), Ok((64, 0)));
+    /// ```
+    pub fn from_result(result: Result<A, E>) -> Self
+    where
+        A: Clone,
+        E: Clone,
+    {
+        StateResult::new(move |s| result.clone().map(|a| (a, s)))
+    }
+
+    /// Runs the computation with an initial state, returning its value and the final state.
+    pub fn run(&self, s: S) -> Result<(A, S), E> {
+        (self.run)(s)
+    }
+
+    /// Runs the computation with an initial state, returning only its value.
+    pub fn eval(&self, s: S) -> Result<A, E> {
+        self.run(s).map(|(a, _)| a)
+    }
+
+    /// Runs the computation with an initial state, returning only the final state.
+    pub fn exec(&self, s: S) -> Result<S, E> {
+        self.run(s).map(|(_, s)| s)
+    }
+
+    /// Converts the computation back into the function it runs.
+    pub fn into_fn(self) -> impl Fn(S) -> Result<(A, S), E> {
+        move |s| self.run(s)
+    }
+
+    /// Creates a computation which transforms the value of this one, if it succeeds.
+    pub fn map<B, F>(self, f: F) -> StateResult<S, B, E>
+    where
+        B: 'static,
+        F: Fn(A) -> B + 'static,
+    {
+        StateResult::new(move |s| self.run(s).map(|(a, s)| (f(a), s)))
+    }
+
+    /// Creates a computation which transforms the error of this one, if it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::state::StateResult;
+    /// let next = StateResult::new(|n: u8| n.checked_add(1).map(|m| (n, m)).ok_or(()))
+    ///     .map_err(|()| "counter overflowed");
+    /// assert_eq!(next.run(1), Ok((1, 2)));
+    /// assert_eq!(next.run(255), Err("counter overflowed"));
+    /// ```
+    pub fn map_err<D, F>(self, f: F) -> StateResult<S, A, D>
+    where
+        D: 'static,
+        F: Fn(E) -> D + 'static,
+    {
+        StateResult::new(move |s| self.run(s).map_err(&f))
+    }
+
+    /// Creates a computation which, if this one succeeds, runs the computation that a function
+    /// of its value returns, with the state this one produced.
+    pub fn and_then<B, F>(self, f: F) -> StateResult<S, B, E>
+    where
+        B: 'static,
+        F: Fn(A) -> StateResult<S, B, E> + 'static,
+    {
+        StateResult::new(move |s| {
+            let (a, s) = self.run(s)?;
+            f(a).run(s)
+        })
+    }
+
+    /// Creates a computation which, if this one succeeds, transforms its value with a fallible
+    /// function which does not need the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::state::StateResult;
+    /// let byte = StateResult::new(|s: &'static str| Ok((&s[..2], &s[2..])))
+    ///     .and_then_result(|hex| u8::from_str_radix(hex, 16));
+    /// assert_eq!(byte.run("ff00"), Ok((255, "00")));
+    /// assert!(byte.run("zz").is_err());
+    /// ```
+    pub fn and_then_result<B, F>(self, f: F) -> StateResult<S, B, E>
+    where
+        B: 'static,
+        F: Fn(A) -> Result<B, E> + 'static,
+    {
+        StateResult::new(move |s| {
+            let (a, s) = self.run(s)?;
+            Ok((f(a)?, s))
+        })
+    }
+
+    /// Creates a computation which runs this one and then `next`, keeping the value of `next`.
+    pub fn then<B>(self, next: StateResult<S, B, E>) -> StateResult<S, B, E>
+    where
+        B: 'static,
+    {
+        StateResult::new(move |s| {
+            let (_, s) = self.run(s)?;
+            next.run(s)
+        })
+    }
+}
+
+impl<S: Clone + 'static, E: 'static> StateResult<S, S, E> {
+    /// Creates a computation which produces a copy of the state, leaving it unchanged.
+    pub fn get() -> Self {
+        StateResult::new(|s: S| Ok((s.clone(), s)))
+    }
+}
+
+impl<S: 'static, E: 'static> StateResult<S, (), E> {
+    /// Creates a computation which replaces the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::state::StateResult;
+    /// let reset = StateResult::<u32, (), ()>::put(0);
+    /// assert_eq!(reset.exec(7), Ok(0));
+    /// ```
+    pub fn put(s: S) -> Self
+    where
+        S: Clone,
+    {
+        StateResult::new(move |_| Ok(((), s.clone())))
+    }
+
+    /// Creates a computation which transforms the state.
+    pub fn modify<F>(f: F) -> Self
+    where
+        F: Fn(S) -> S + 'static,
+    {
+        StateResult::new(move |s| Ok(((), f(s))))
+    }
+}
+
+impl<S, A, E> Debug for StateResult<S, A, E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("StateResult(..)")
+    }
+}
+
+/* ZOOMING ********************************************************************/
+#[cfg(feature = "std")]
+use crate::error::Error;
+#[cfg(feature = "std")]
+use crate::lens::LensMut;
+
+#[cfg(feature = "std")]
+impl<S: 'static, A: 'static, E: 'static> StateResult<S, A, E> {
+    /// Creates a computation on a larger state, which runs this one on the part of it that a
+    /// lens focuses on, and puts the resulting part back in its place. If the lens focuses on
+    /// nothing, the computation fails with [MissingFocus](crate::error::Error::MissingFocus).
+    ///
+    /// Since the larger state is owned, the part is set with
+    /// [set_owned](crate::lens::LensMut::set_owned), so the rest of it is not cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::error::Error;
+    /// # use fp_std::state::StateResult;
+    /// # use fp_std::tuple::LensSecond;
+    /// let tick = StateResult::<u32, u32, Error>::new(|n| Ok((n, n + 1)));
+    /// let tick_second = tick.zoom(LensSecond);
+    /// assert_eq!(tick_second.run(("clock", 4)), Ok((4, ("clock", 5))));
+    /// ```
+    pub fn zoom<Outer, L>(self, _lens: L) -> StateResult<Outer, A, E>
+    where
+        Outer: 'static,
+        S: Clone,
+        E: From<Error>,
+        L: LensMut<Outer, S>,
+    {
+        StateResult::new(move |mut outer| {
+            let s = L::get_mut(&mut outer).ok_or(Error::MissingFocus)?.clone();
+            let (a, s) = self.run(s)?;
+            Ok((a, L::set_owned(s, outer)))
+        })
+    }
+}
@@ -0,0 +1,310 @@
+//! Functions for composing [streams](futures::Stream), mirroring the functions available for
+//! iterators.
+//!
+//! Requires the `stream` feature.
+
+/* BASIC FUNCTIONS ************************************************************/
+use futures::stream::{self, Stream, StreamExt, Zip};
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Lifts a function to transform every item of a stream.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::stream::map;
+/// # use futures::executor::block_on;
+/// # use futures::stream::{self, StreamExt};
+/// fn double(x: usize) -> usize { x * 2 }
+/// let double_all = map(double);
+/// let doubled: Vec<_> = block_on(double_all(stream::iter(vec![1, 2, 3])).collect());
+/// assert_eq!(doubled, vec![2, 4, 6]);
+/// ```
+pub fn map<S, B, F>(f: F) -> impl Fn(S) -> stream::Map<S, F>
+where
+    S: Stream,
+    F: Fn(S::Item) -> B + Clone,
+{
+    move |stream| stream.map(f.clone())
+}
+
+pin_project! {
+    /// A stream which transforms the items of another stream, keeping only those for which the
+    /// transformation produced a value. Constructed by [filter_map](crate::stream::filter_map).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct FilterMap<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, B, F> Stream for FilterMap<S, F>
+where
+    S: Stream,
+    F: Fn(S::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<B>> {
+        let mut this = self.project();
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if let Some(output) = (this.f)(item) {
+                        return Poll::Ready(Some(output));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Lifts a function to transform every item of a stream, discarding the items for which it
+/// produces `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::stream::filter_map;
+/// # use futures::executor::block_on;
+/// # use futures::stream::{self, StreamExt};
+/// let parse_all = filter_map(|s: &str| s.parse::<u32>().ok());
+/// let parsed: Vec<_> = block_on(parse_all(stream::iter(vec!["1", "x", "3"])).collect());
+/// assert_eq!(parsed, vec![1, 3]);
+/// ```
+pub fn filter_map<S, B, F>(f: F) -> impl Fn(S) -> FilterMap<S, F>
+where
+    S: Stream,
+    F: Fn(S::Item) -> Option<B> + Clone,
+{
+    move |stream| FilterMap {
+        stream,
+        f: f.clone(),
+    }
+}
+
+pin_project! {
+    /// A future which folds every item of a stream into an accumulator. Constructed by
+    /// [fold](crate::stream::fold).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Fold<S, B, F> {
+        #[pin]
+        stream: S,
+        acc: Option<B>,
+        f: F,
+    }
+}
+
+impl<S, B, F> Future for Fold<S, B, F>
+where
+    S: Stream,
+    F: Fn(B, S::Item) -> B,
+{
+    type Output = B;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<B> {
+        let mut this = self.project();
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let acc = this.acc.take().expect("Fold polled after completion");
+                    *this.acc = Some((this.f)(acc, item));
+                }
+                Poll::Ready(None) => {
+                    let acc = this.acc.take().expect("Fold polled after completion");
+                    return Poll::Ready(acc);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Folds every item of a stream into an accumulator, starting from `init`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::stream::fold;
+/// # use futures::executor::block_on;
+/// # use futures::stream;
+/// # use std::ops::Add;
+/// let sum = fold(0, usize::add);
+/// assert_eq!(block_on(sum(stream::iter(vec![1, 2, 3]))), 6);
+/// ```
+pub fn fold<S, B, F>(init: B, f: F) -> impl Fn(S) -> Fold<S, B, F>
+where
+    S: Stream,
+    B: Clone,
+    F: Fn(B, S::Item) -> B + Clone,
+{
+    move |stream| Fold {
+        stream,
+        acc: Some(init.clone()),
+        f: f.clone(),
+    }
+}
+
+pin_project! {
+    /// A stream which combines the items of two other streams pairwise. Constructed by
+    /// [zip_with](crate::stream::zip_with).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ZipWith<S1: Stream, S2: Stream, F> {
+        #[pin]
+        zip: Zip<S1, S2>,
+        f: F,
+    }
+}
+
+impl<S1, S2, C, F> Stream for ZipWith<S1, S2, F>
+where
+    S1: Stream,
+    S2: Stream,
+    F: Fn(S1::Item, S2::Item) -> C,
+{
+    type Item = C;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<C>> {
+        let this = self.project();
+        let f = this.f;
+        this.zip
+            .poll_next(cx)
+            .map(|item| item.map(|(a, b)| f(a, b)))
+    }
+}
+
+/// Lifts a 2-arity function to combine the items of two streams pairwise. The resulting stream
+/// ends when either input stream ends.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::stream::zip_with;
+/// # use futures::executor::block_on;
+/// # use futures::stream::{self, StreamExt};
+/// # use std::ops::Add;
+/// let add_pairwise = zip_with(usize::add);
+/// let sums: Vec<_> = block_on(
+///     add_pairwise(stream::iter(vec![1, 2, 3]), stream::iter(vec![10, 20])).collect(),
+/// );
+/// assert_eq!(sums, vec![11, 22]);
+/// ```
+pub fn zip_with<S1, S2, C, F>(f: F) -> impl Fn(S1, S2) -> ZipWith<S1, S2, F>
+where
+    S1: Stream,
+    S2: Stream,
+    F: Fn(S1::Item, S2::Item) -> C + Clone,
+{
+    move |a, b| ZipWith {
+        zip: a.zip(b),
+        f: f.clone(),
+    }
+}
+
+/* CHUNKING *******************************************************************/
+use futures_timer::Delay;
+use std::time::Duration;
+
+pin_project! {
+    /// A stream which collects the items of another stream into chunks, emitting each chunk
+    /// once it is full or once its time limit has passed. Constructed by
+    /// [chunks_timeout](crate::stream::chunks_timeout).
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunksTimeout<S: Stream> {
+        #[pin]
+        stream: S,
+        size: usize,
+        duration: Duration,
+        chunk: Vec<S::Item>,
+        delay: Option<Delay>,
+        done: bool,
+    }
+}
+
+impl<S: Stream> Stream for ChunksTimeout<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.chunk.is_empty() {
+                        *this.delay = Some(Delay::new(*this.duration));
+                    }
+                    this.chunk.push(item);
+                    if this.chunk.len() >= *this.size {
+                        *this.delay = None;
+                        return Poll::Ready(Some(std::mem::take(this.chunk)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    *this.delay = None;
+                    if this.chunk.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    return Poll::Ready(Some(std::mem::take(this.chunk)));
+                }
+                Poll::Pending => break,
+            }
+        }
+        let elapsed = match this.delay {
+            Some(delay) => Pin::new(delay).poll(cx).is_ready(),
+            None => false,
+        };
+        if elapsed {
+            *this.delay = None;
+            Poll::Ready(Some(std::mem::take(this.chunk)))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Collects the items of a stream into chunks of at most `size` items. A chunk which is not yet
+/// full is emitted anyway once `duration` has passed since its first item arrived.
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::stream::chunks_timeout;
+/// # use futures::executor::block_on;
+/// # use futures::stream::{self, StreamExt};
+/// # use std::time::Duration;
+/// let batch = chunks_timeout(2, Duration::from_millis(100));
+/// let chunks: Vec<_> = block_on(batch(stream::iter(vec![1, 2, 3, 4, 5])).collect());
+/// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+///
+/// let batch_slow = chunks_timeout(2, Duration::from_millis(10));
+/// let slow = stream::iter(vec![1]).chain(stream::pending());
+/// let first = block_on(batch_slow(slow).next());
+/// assert_eq!(first, Some(vec![1]));
+/// ```
+pub fn chunks_timeout<S>(size: usize, duration: Duration) -> impl Fn(S) -> ChunksTimeout<S>
+where
+    S: Stream,
+{
+    assert!(size > 0, "chunk size must be greater than zero");
+    move |stream| ChunksTimeout {
+        stream,
+        size,
+        duration,
+        chunk: Vec::with_capacity(size),
+        delay: None,
+        done: false,
+    }
+}
@@ -0,0 +1,495 @@
+//! Functions for manipulating strings.
+
+/* OPERATIONS *****************************************************************/
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Creates a function which splits a string at each occurrence of a separator.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::split;
+/// let fields: Vec<Vec<&str>> = vec!["a,b", "c"].into_iter().map(split(",")).collect();
+/// assert_eq!(fields, vec![vec!["a", "b"], vec!["c"]]);
+/// ```
+pub fn split<'a>(sep: &'a str) -> impl Fn(&str) -> Vec<&str> + 'a {
+    move |s| s.split(sep).collect()
+}
+
+/// Creates a function which replaces every occurrence of `from` in a string with `to`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::replace;
+/// let kebab = replace("_", "-");
+/// assert_eq!(kebab("snake_case_name"), "snake-case-name");
+/// ```
+pub fn replace<'a>(from: &'a str, to: &'a str) -> impl Fn(&str) -> String + 'a {
+    move |s| s.replace(from, to)
+}
+
+/// Removes leading and trailing whitespace from a string.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::trim;
+/// let lines: Vec<&str> = " a \n b".lines().map(trim).collect();
+/// assert_eq!(lines, vec!["a", "b"]);
+/// ```
+pub fn trim(s: &str) -> &str {
+    s.trim()
+}
+
+/// Converts a string to lowercase.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::to_lowercase;
+/// assert_eq!(to_lowercase("Hello"), "hello");
+/// ```
+pub fn to_lowercase(s: &str) -> String {
+    s.to_lowercase()
+}
+
+/// Converts a string to uppercase.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::to_uppercase;
+/// assert_eq!(to_uppercase("Hello"), "HELLO");
+/// ```
+pub fn to_uppercase(s: &str) -> String {
+    s.to_uppercase()
+}
+
+/// Creates a function which checks whether a string starts with a prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::starts_with;
+/// let is_comment = starts_with("#");
+/// let code: Vec<&str> = vec!["# note", "x = 1"].into_iter().filter(|s| !is_comment(s)).collect();
+/// assert_eq!(code, vec!["x = 1"]);
+/// ```
+pub fn starts_with(prefix: &str) -> impl Fn(&str) -> bool + '_ {
+    move |s| s.starts_with(prefix)
+}
+
+/// Creates a function which checks whether a string ends with a suffix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::ends_with;
+/// let is_rust = ends_with(".rs");
+/// assert!(is_rust("main.rs"));
+/// assert!(!is_rust("Cargo.toml"));
+/// ```
+pub fn ends_with(suffix: &str) -> impl Fn(&str) -> bool + '_ {
+    move |s| s.ends_with(suffix)
+}
+
+/// Creates a function which checks whether a string contains another.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::contains;
+/// let mentions_error = contains("error");
+/// assert!(mentions_error("an error occurred"));
+/// assert!(!mentions_error("all good"));
+/// ```
+pub fn contains(needle: &str) -> impl Fn(&str) -> bool + '_ {
+    move |s| s.contains(needle)
+}
+
+/// Creates a function which appends a suffix to a string.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::concat;
+/// let files: Vec<String> = vec!["main", "lib"].into_iter().map(concat(".rs")).collect();
+/// assert_eq!(files, vec!["main.rs", "lib.rs"]);
+/// ```
+pub fn concat(suffix: &str) -> impl Fn(&str) -> String + '_ {
+    move |s| {
+        let mut out = String::with_capacity(s.len() + suffix.len());
+        out.push_str(s);
+        out.push_str(suffix);
+        out
+    }
+}
+
+/// Creates a function which joins strings together, with a separator between each of them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::function::compose2;
+/// # use fp_std::string::{join, split};
+/// let csv_to_tsv = compose2(join("\t"), split(","));
+/// assert_eq!(csv_to_tsv("a,b,c"), "a\tb\tc");
+/// ```
+pub fn join<I>(sep: &str) -> impl Fn(I) -> String + '_
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    move |strings| {
+        let mut out = String::new();
+        for (i, s) in strings.into_iter().enumerate() {
+            if i > 0 {
+                out.push_str(sep);
+            }
+            out.push_str(s.as_ref());
+        }
+        out
+    }
+}
+
+/* SANITIZATION ***************************************************************/
+
+fn fold_accent(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ð' | 'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+/// Converts a string into a slug, suitable for use in a URL: lowercase words separated by
+/// single dashes.
+///
+/// Accented Latin letters are replaced by their unaccented letters, and any other letters and
+/// digits are kept. Everything else separates words, and is dropped from the start and end.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::slugify;
+/// assert_eq!(slugify("  Hello, World!  "), "hello-world");
+/// assert_eq!(slugify("Crème Brûlée — 2nd edition"), "creme-brulee-2nd-edition");
+/// assert_eq!(slugify("Straße"), "strasse");
+/// assert_eq!(slugify("?!"), "");
+/// ```
+pub fn slugify(s: &str) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut separated = false;
+    for c in s.chars().flat_map(char::to_lowercase) {
+        let folded = fold_accent(c);
+        if folded.is_none() && !c.is_alphanumeric() {
+            separated = true;
+            continue;
+        }
+        if separated && !slug.is_empty() {
+            slug.push('-');
+        }
+        separated = false;
+        match folded {
+            Some(folded) => slug.push_str(folded),
+            None => slug.push(c),
+        }
+    }
+    slug
+}
+
+/// Converts a string into a name which can be used for a file on common filesystems.
+///
+/// Path separators, control characters, and characters which Windows does not allow in file
+/// names are replaced by `_`. Whitespace is trimmed from each end, and dots from the end. A
+/// name which Windows reserves for a device, such as `CON` or `com1.txt`, is prefixed with
+/// `_`, and a name which would be empty, `.`, or `..` becomes `_`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::sanitize_filename;
+/// assert_eq!(sanitize_filename("report: Q1/Q2?.pdf"), "report_ Q1_Q2_.pdf");
+/// assert_eq!(sanitize_filename(" notes. "), "notes");
+/// assert_eq!(sanitize_filename("NUL.txt"), "_NUL.txt");
+/// assert_eq!(sanitize_filename(".."), "_");
+/// ```
+pub fn sanitize_filename(s: &str) -> String {
+    const INVALID: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+    const RESERVED: &[&str] = &[
+        "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+        "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+    ];
+    let replaced: String = s
+        .chars()
+        .map(|c| {
+            if c.is_control() || INVALID.contains(&c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let name = replaced.trim().trim_end_matches('.').trim_end();
+    let stem = name.split('.').next().unwrap_or(name);
+    if name.is_empty() {
+        String::from("_")
+    } else if RESERVED
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+    {
+        let mut prefixed = String::with_capacity(name.len() + 1);
+        prefixed.push('_');
+        prefixed.push_str(name);
+        prefixed
+    } else {
+        String::from(name)
+    }
+}
+
+/* TEMPLATES ******************************************************************/
+#[cfg(feature = "std")]
+use crate::error::Error;
+#[cfg(feature = "std")]
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::fmt::{Display, Write};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "std")]
+enum Segment {
+    Text(String),
+    Key(String),
+}
+
+#[cfg(feature = "std")]
+fn parse_template(source: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = source;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            text.push(c);
+            rest = &rest[2..];
+            continue;
+        }
+        match (c, rest.find('}')) {
+            ('{', Some(end)) => {
+                if !text.is_empty() {
+                    segments.push(Segment::Text(core::mem::take(&mut text)));
+                }
+                segments.push(Segment::Key(String::from(&rest[1..end])));
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    segments
+}
+
+/// Creates a function which fills in a template from a map of values. Each `{key}` in the
+/// template is replaced by the value of that key, and `{{` and `}}` stand for literal braces.
+///
+/// Keys which are missing from the map are left in place, braces and all, so that they are
+/// easy to spot. Use [try_template](crate::string::try_template) to treat them as errors
+/// instead.
+///
+/// The template is parsed once, when the function is created.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::template;
+/// # use std::collections::HashMap;
+/// let greeting = template("Hello, {name}! {{{count}}} new, {unknown} old.");
+/// let mut values = HashMap::new();
+/// values.insert("name", "Cameron".to_owned());
+/// values.insert("count", 3.to_string());
+/// assert_eq!(greeting(&values), "Hello, Cameron! {3} new, {unknown} old.");
+/// ```
+#[cfg(feature = "std")]
+pub fn template<K, V>(source: &str) -> impl Fn(&HashMap<K, V>) -> String
+where
+    K: Borrow<str> + Eq + Hash,
+    V: Display,
+{
+    let segments = parse_template(source);
+    move |values| {
+        let mut output = String::new();
+        for segment in &segments {
+            match segment {
+                Segment::Text(text) => output.push_str(text),
+                Segment::Key(key) => match values.get(key.as_str()) {
+                    Some(value) => write!(output, "{}", value).unwrap(),
+                    None => write!(output, "{{{}}}", key).unwrap(),
+                },
+            }
+        }
+        output
+    }
+}
+
+/// Creates a function which fills in a template from a map of values, as with
+/// [template](crate::string::template), but fails with
+/// [MissingKey](crate::error::Error::MissingKey) if any key is missing from the map.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::error::Error;
+/// # use fp_std::string::try_template;
+/// # use std::collections::HashMap;
+/// let greeting = try_template("Hello, {name}!");
+/// let mut values = HashMap::new();
+/// assert_eq!(greeting(&values), Err(Error::MissingKey));
+/// values.insert("name", "Cameron");
+/// assert_eq!(greeting(&values), Ok("Hello, Cameron!".to_owned()));
+/// ```
+#[cfg(feature = "std")]
+pub fn try_template<K, V>(source: &str) -> impl Fn(&HashMap<K, V>) -> Result<String, Error>
+where
+    K: Borrow<str> + Eq + Hash,
+    V: Display,
+{
+    let segments = parse_template(source);
+    move |values| {
+        let mut output = String::new();
+        for segment in &segments {
+            match segment {
+                Segment::Text(text) => output.push_str(text),
+                Segment::Key(key) => {
+                    let value = values.get(key.as_str()).ok_or(Error::MissingKey)?;
+                    write!(output, "{}", value).unwrap();
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+/* SIMILARITY *****************************************************************/
+
+/// Computes the Levenshtein distance between two strings: the fewest insertions, deletions, and
+/// substitutions of single characters needed to turn one into the other.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::levenshtein;
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("", "abc"), 3);
+/// assert_eq!(levenshtein("same", "same"), 0);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = Vec::with_capacity(b.len() + 1);
+    for (i, ca) in a.chars().enumerate() {
+        current.clear();
+        current.push(i + 1);
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Computes the Damerau-Levenshtein distance between two strings, which is the
+/// [Levenshtein distance](crate::string::levenshtein) where swapping two adjacent characters
+/// also counts as a single edit.
+///
+/// This is the restricted form of the distance, also known as the optimal string alignment
+/// distance, in which no part of the string is edited more than once.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::{damerau_levenshtein, levenshtein};
+/// assert_eq!(damerau_levenshtein("teh", "the"), 1);
+/// assert_eq!(levenshtein("teh", "the"), 2);
+/// assert_eq!(damerau_levenshtein("ca", "abc"), 3);
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = alloc::vec![alloc::vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+/// Scores how similar two strings are, from `0.0` for entirely different strings to `1.0` for
+/// equal ones. The score is the [Levenshtein distance](crate::string::levenshtein) between
+/// them, relative to the length of the longer string.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::string::similarity;
+/// let commands = ["build", "check", "clean", "test"];
+/// let best = commands
+///     .iter()
+///     .max_by(|a, b| similarity("chek", a).total_cmp(&similarity("chek", b)))
+///     .unwrap();
+/// assert_eq!(*best, "check");
+/// assert_eq!(similarity("", ""), 1.0);
+/// assert_eq!(similarity("abc", "xyz"), 0.0);
+/// ```
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / longest as f64
+}
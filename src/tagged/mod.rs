@@ -0,0 +1,262 @@
+//! Zero-cost newtypes distinguished only by a phantom tag.
+//!
+//! ```rust
+//! # use fp_std::tag;
+//! # use fp_std::tagged::Tagged;
+//! tag!(pub User, pub Order);
+//! type UserId = Tagged<User, u64>;
+//! type OrderId = Tagged<Order, u64>;
+//!
+//! fn lookup(id: UserId) -> u64 { *id }
+//! let user = UserId::new(3);
+//! let order = OrderId::new(3);
+//! assert_eq!(lookup(user), 3);
+//! // lookup(order); // mismatched types
+//! # let _ = order;
+//! ```
+
+/* TAGGED VALUES **************************************************************/
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// Declares uninhabited types to be used as tags for [Tagged](crate::tagged::Tagged) values.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tag;
+/// # use fp_std::tagged::Tagged;
+/// tag!(
+///     /// Tags values which are measured in metres.
+///     pub Metres,
+///     Seconds,
+/// );
+/// let distance: Tagged<Metres, f64> = Tagged::new(3.5);
+/// let time: Tagged<Seconds, f64> = Tagged::new(2.0);
+/// assert_eq!(*distance / *time, 1.75);
+/// ```
+#[macro_export]
+macro_rules! tag {
+    ($($(#[$meta:meta])* $vis:vis $name:ident),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $vis enum $name {}
+        )+
+    };
+}
+
+/// A value of type `T`, tagged with the type `Tag` so that it is not interchangeable with
+/// values of the same type but a different tag.
+///
+/// The tag exists only at the type level, so no trait implementation places any requirements
+/// on it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tag;
+/// # use fp_std::tagged::Tagged;
+/// tag!(Name);
+/// let name: Tagged<Name, String> = Tagged::new("Cameron".to_owned());
+/// assert_eq!(name.len(), 7);
+/// assert_eq!(name.into_inner(), "Cameron");
+/// ```
+pub struct Tagged<Tag, T> {
+    value: T,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag, T> Tagged<Tag, T> {
+    /// Tags a value.
+    pub fn new(value: T) -> Self {
+        Tagged {
+            value,
+            tag: PhantomData,
+        }
+    }
+
+    /// Removes the tag, returning the underlying value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Transforms the tagged value, keeping the same tag.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tag;
+    /// # use fp_std::tagged::Tagged;
+    /// tag!(Count);
+    /// let count: Tagged<Count, u32> = Tagged::new(3);
+    /// assert_eq!(count.map(|x| x * 2), Tagged::new(6));
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Tagged<Tag, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Tagged::new(f(self.value))
+    }
+
+    /// Replaces the tag of this value with a different one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tag;
+    /// # use fp_std::tagged::Tagged;
+    /// tag!(Unverified, Verified);
+    /// let email: Tagged<Unverified, &str> = Tagged::new("me@example.com");
+    /// let email: Tagged<Verified, &str> = email.retag();
+    /// assert_eq!(*email, "me@example.com");
+    /// ```
+    pub fn retag<NewTag>(self) -> Tagged<NewTag, T> {
+        Tagged::new(self.value)
+    }
+
+    /// Borrows the tagged value, keeping the tag.
+    pub fn as_ref(&self) -> Tagged<Tag, &T> {
+        Tagged::new(&self.value)
+    }
+
+    /// Mutably borrows the tagged value, keeping the tag.
+    pub fn as_mut(&mut self) -> Tagged<Tag, &mut T> {
+        Tagged::new(&mut self.value)
+    }
+}
+
+impl<Tag, T: Clone> Tagged<Tag, &T> {
+    /// Clones the borrowed value, keeping the tag.
+    pub fn cloned(self) -> Tagged<Tag, T> {
+        Tagged::new(self.value.clone())
+    }
+}
+
+impl<Tag, T> Deref for Tagged<Tag, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<Tag, T> DerefMut for Tagged<Tag, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<Tag, T> From<T> for Tagged<Tag, T> {
+    fn from(value: T) -> Self {
+        Tagged::new(value)
+    }
+}
+
+impl<Tag, T: Clone> Clone for Tagged<Tag, T> {
+    fn clone(&self) -> Self {
+        Tagged::new(self.value.clone())
+    }
+}
+
+impl<Tag, T: Copy> Copy for Tagged<Tag, T> {}
+
+impl<Tag, T: Default> Default for Tagged<Tag, T> {
+    fn default() -> Self {
+        Tagged::new(T::default())
+    }
+}
+
+impl<Tag, T: Debug> Debug for Tagged<Tag, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<Tag, T: Display> Display for Tagged<Tag, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<Tag, T: PartialEq> PartialEq for Tagged<Tag, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Tag, T: Eq> Eq for Tagged<Tag, T> {}
+
+impl<Tag, T: PartialOrd> PartialOrd for Tagged<Tag, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<Tag, T: Ord> Ord for Tagged<Tag, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<Tag, T: Hash> Hash for Tagged<Tag, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+/* FUNCTIONS ******************************************************************/
+
+/// Tags a value. This is [Tagged::new](crate::tagged::Tagged::new), usable where the tag can
+/// be inferred.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tag;
+/// # use fp_std::tagged::{tagged, Tagged};
+/// tag!(Score);
+/// let scores: Vec<Tagged<Score, u32>> = vec![1, 2, 3].into_iter().map(tagged).collect();
+/// assert_eq!(*scores[1], 2);
+/// ```
+pub fn tagged<Tag, T>(value: T) -> Tagged<Tag, T> {
+    Tagged::new(value)
+}
+
+/// Removes the tag from a value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tag;
+/// # use fp_std::tagged::{untag, Tagged};
+/// tag!(Score);
+/// let scores: Vec<Tagged<Score, u32>> = vec![Tagged::new(1), Tagged::new(2)];
+/// let raw: Vec<u32> = scores.into_iter().map(untag).collect();
+/// assert_eq!(raw, vec![1, 2]);
+/// ```
+pub fn untag<Tag, T>(tagged: Tagged<Tag, T>) -> T {
+    tagged.into_inner()
+}
+
+/// Lifts a function to operate on tagged values, preserving the tag.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tag;
+/// # use fp_std::tagged::{map, Tagged};
+/// tag!(Score);
+/// fn double(x: u32) -> u32 { x * 2 }
+/// let score: Tagged<Score, u32> = Tagged::new(4);
+/// assert_eq!(map(double)(score), Tagged::new(8));
+/// ```
+pub fn map<Tag, A, B, F>(f: F) -> impl Fn(Tagged<Tag, A>) -> Tagged<Tag, B>
+where
+    F: Fn(A) -> B,
+{
+    move |tagged| tagged.map(&f)
+}
@@ -0,0 +1,119 @@
+//! Trampolines, for writing recursive functions which do not grow the stack.
+//!
+//! Instead of calling itself, a trampolined function returns a
+//! [Bounce](crate::trampoline::Bounce) describing the call it would make, and a driver loop
+//! makes the calls one after another.
+//!
+//! ```rust
+//! # use fp_std::trampoline::Bounce;
+//! fn factorial(n: u64, acc: u64) -> Bounce<u64> {
+//!     if n == 0 {
+//!         Bounce::Done(acc)
+//!     } else {
+//!         Bounce::call(move || factorial(n - 1, acc.wrapping_mul(n)))
+//!     }
+//! }
+//!
+//! assert_eq!(factorial(5, 1).run(), 120);
+//! assert_eq!(factorial(1_000_000, 1).run(), 0);
+//! ```
+
+/* TRAMPOLINES ****************************************************************/
+use alloc::boxed::Box;
+use core::fmt::{self, Debug, Formatter};
+
+/// One step of a trampolined computation: either its result, or the call which continues it.
+pub enum Bounce<T> {
+    /// The computation has finished with this result.
+    Done(T),
+    /// The computation continues by calling this function.
+    Call(Box<dyn FnOnce() -> Bounce<T>>),
+}
+
+impl<T> Bounce<T> {
+    /// Creates a step which continues the computation by calling `f`.
+    pub fn call<F>(f: F) -> Self
+    where
+        F: FnOnce() -> Bounce<T> + 'static,
+    {
+        Bounce::Call(Box::new(f))
+    }
+
+    /// Runs the computation to completion, making each call in turn, and returns its result.
+    ///
+    /// # Examples
+    ///
+    /// Mutually recursive functions can be trampolined too:
+    ///
+    /// ```rust
+    /// # use fp_std::trampoline::Bounce;
+    /// fn is_even(n: u32) -> Bounce<bool> {
+    ///     if n == 0 { Bounce::Done(true) } else { Bounce::call(move || is_odd(n - 1)) }
+    /// }
+    ///
+    /// fn is_odd(n: u32) -> Bounce<bool> {
+    ///     if n == 0 { Bounce::Done(false) } else { Bounce::call(move || is_even(n - 1)) }
+    /// }
+    ///
+    /// assert!(is_even(100_000).run());
+    /// assert!(is_odd(100_001).run());
+    /// ```
+    pub fn run(self) -> T {
+        let mut bounce = self;
+        loop {
+            match bounce {
+                Bounce::Done(t) => return t,
+                Bounce::Call(f) => bounce = f(),
+            }
+        }
+    }
+
+    /// Whether the computation has finished.
+    pub fn is_done(&self) -> bool {
+        matches!(self, Bounce::Done(..))
+    }
+}
+
+impl<T: Debug> Debug for Bounce<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Bounce::Done(t) => f.debug_tuple("Done").field(t).finish(),
+            Bounce::Call(..) => f.write_str("Call(..)"),
+        }
+    }
+}
+
+/// Creates a function which runs a trampolined function to completion.
+///
+/// Functions which are not tail recursive can be trampolined by keeping their pending work in
+/// an explicit stack, as in this Ackermann function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::trampoline::{trampoline, Bounce};
+/// fn ackermann((mut stack, n): (Vec<u64>, u64)) -> Bounce<u64> {
+///     match stack.pop() {
+///         None => Bounce::Done(n),
+///         Some(0) => Bounce::call(move || ackermann((stack, n + 1))),
+///         Some(m) if n == 0 => {
+///             stack.push(m - 1);
+///             Bounce::call(move || ackermann((stack, 1)))
+///         }
+///         Some(m) => {
+///             stack.extend([m - 1, m]);
+///             Bounce::call(move || ackermann((stack, n - 1)))
+///         }
+///     }
+/// }
+///
+/// let ackermann = trampoline(ackermann);
+/// assert_eq!(ackermann((vec![2], 3)), 9);
+/// assert_eq!(ackermann((vec![3], 5)), 253);
+/// ```
+pub fn trampoline<A, B, F>(f: F) -> impl Fn(A) -> B
+where
+    F: Fn(A) -> Bounce<B>,
+{
+    move |a| f(a).run()
+}
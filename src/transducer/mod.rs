@@ -0,0 +1,367 @@
+//! Transducers: transformations of [reducers](crate::reducer), which describe how items are
+//! transformed separately from both where the items come from and what they are accumulated
+//! into.
+//!
+//! A transducer is built once, and may then be applied to any reducer. Since each item passes
+//! through every step of the transformation before the next item is considered, no
+//! intermediate collections are allocated.
+//!
+//! ```rust
+//! # use fp_std::reducer::{into_vec, sum};
+//! # use fp_std::transducer::{compose, filter, map, take, transduce};
+//! let squares = map(|x: u32| x * x);
+//! let first_three_even = compose(filter(|x: &u32| x % 2 == 0), take(3));
+//! let first_even_squares = compose(squares, first_three_even);
+//!
+//! assert_eq!(transduce(first_even_squares, into_vec())(1..), vec![4, 16, 36]);
+//! assert_eq!(transduce(first_even_squares, sum())(vec![1, 2, 3, 4]), 20);
+//! ```
+
+/* TRANSDUCERS ****************************************************************/
+use crate::reducer::{reduce, Reducer};
+use core::ops::ControlFlow;
+
+/// A transformation of a reducer `R` into another reducer.
+///
+/// Transducers are usually applied with [transduce](crate::transducer::transduce), and
+/// combined with [compose](crate::transducer::compose).
+pub trait Transducer<R> {
+    /// The reducer which this transducer produces.
+    type Output;
+
+    /// Transforms a reducer.
+    fn transduce(self, reducer: R) -> Self::Output;
+}
+
+/// Creates a function which transforms every item of a list with a transducer, and reduces
+/// the results with a reducer.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::count;
+/// # use fp_std::transducer::{filter, transduce};
+/// let count_blank = transduce(filter(|line: &&str| line.trim().is_empty()), count());
+/// assert_eq!(count_blank("a\n\n b\n \n".lines()), 2);
+/// ```
+pub fn transduce<I, A, B, T, R>(transducer: T, reducer: R) -> impl Fn(I) -> B
+where
+    I: IntoIterator<Item = A>,
+    T: Transducer<R>,
+    T::Output: Reducer<A, B>,
+{
+    reduce(transducer.transduce(reducer))
+}
+
+/// A transducer which applies one transducer and then another. See
+/// [compose](crate::transducer::compose).
+#[derive(Clone, Copy, Debug)]
+pub struct Compose<T, U> {
+    first: T,
+    second: U,
+}
+
+impl<T, U, R> Transducer<R> for Compose<T, U>
+where
+    U: Transducer<R>,
+    T: Transducer<U::Output>,
+{
+    type Output = T::Output;
+
+    fn transduce(self, reducer: R) -> T::Output {
+        self.first.transduce(self.second.transduce(reducer))
+    }
+}
+
+/// Combines two transducers into one which transforms each item with `first`, and then
+/// transforms the results with `second`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::into_vec;
+/// # use fp_std::transducer::{compose, filter, map, transduce};
+/// let tidy = compose(map(str::trim), filter(|s: &&str| !s.is_empty()));
+/// assert_eq!(transduce(tidy, into_vec())(vec![" a ", "  ", "b"]), vec!["a", "b"]);
+/// ```
+pub const fn compose<T, U>(first: T, second: U) -> Compose<T, U> {
+    Compose { first, second }
+}
+
+/* STANDARD TRANSDUCERS *******************************************************/
+
+/// A transducer which transforms every item. See [map](crate::transducer::map).
+#[derive(Clone, Copy, Debug)]
+pub struct Map<F> {
+    f: F,
+}
+
+impl<F, R> Transducer<R> for Map<F> {
+    type Output = Mapped<F, R>;
+
+    fn transduce(self, reducer: R) -> Mapped<F, R> {
+        Mapped { f: self.f, reducer }
+    }
+}
+
+/// A reducer which transforms every item before passing it on to another reducer. See
+/// [map](crate::transducer::map).
+#[derive(Clone, Copy, Debug)]
+pub struct Mapped<F, R> {
+    f: F,
+    reducer: R,
+}
+
+impl<A, B, C, F, R> Reducer<A, C> for Mapped<F, R>
+where
+    F: Fn(A) -> B,
+    R: Reducer<B, C>,
+{
+    type State = R::State;
+
+    fn init(&self) -> R::State {
+        self.reducer.init()
+    }
+
+    fn step(&self, state: R::State, item: A) -> ControlFlow<R::State, R::State> {
+        self.reducer.step(state, (self.f)(item))
+    }
+
+    fn complete(&self, state: R::State) -> C {
+        self.reducer.complete(state)
+    }
+}
+
+/// Creates a transducer which transforms every item with a function.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::into_vec;
+/// # use fp_std::transducer::{map, transduce};
+/// let shout = transduce(map(|c: char| c.to_ascii_uppercase()), into_vec());
+/// assert_eq!(shout("abc".chars()), vec!['A', 'B', 'C']);
+/// ```
+pub const fn map<F>(f: F) -> Map<F> {
+    Map { f }
+}
+
+/// A transducer which keeps only the items which satisfy a predicate. See
+/// [filter](crate::transducer::filter).
+#[derive(Clone, Copy, Debug)]
+pub struct Filter<P> {
+    pred: P,
+}
+
+impl<P, R> Transducer<R> for Filter<P> {
+    type Output = Filtered<P, R>;
+
+    fn transduce(self, reducer: R) -> Filtered<P, R> {
+        Filtered {
+            pred: self.pred,
+            reducer,
+        }
+    }
+}
+
+/// A reducer which passes on only the items which satisfy a predicate to another reducer. See
+/// [filter](crate::transducer::filter).
+#[derive(Clone, Copy, Debug)]
+pub struct Filtered<P, R> {
+    pred: P,
+    reducer: R,
+}
+
+impl<A, C, P, R> Reducer<A, C> for Filtered<P, R>
+where
+    P: Fn(&A) -> bool,
+    R: Reducer<A, C>,
+{
+    type State = R::State;
+
+    fn init(&self) -> R::State {
+        self.reducer.init()
+    }
+
+    fn step(&self, state: R::State, item: A) -> ControlFlow<R::State, R::State> {
+        if (self.pred)(&item) {
+            self.reducer.step(state, item)
+        } else {
+            ControlFlow::Continue(state)
+        }
+    }
+
+    fn complete(&self, state: R::State) -> C {
+        self.reducer.complete(state)
+    }
+}
+
+/// Creates a transducer which keeps only the items which satisfy a predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::sum;
+/// # use fp_std::transducer::{filter, transduce};
+/// assert_eq!(transduce(filter(|x: &i32| *x > 0), sum())(vec![3, -1, 4]), 7);
+/// ```
+pub const fn filter<P>(pred: P) -> Filter<P> {
+    Filter { pred }
+}
+
+/// A transducer which keeps only the first `n` items. See [take](crate::transducer::take).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Take {
+    n: usize,
+}
+
+impl<R> Transducer<R> for Take {
+    type Output = Taken<R>;
+
+    fn transduce(self, reducer: R) -> Taken<R> {
+        Taken { n: self.n, reducer }
+    }
+}
+
+/// A reducer which passes on only the first `n` items to another reducer, and then stops the
+/// reduction. See [take](crate::transducer::take).
+#[derive(Clone, Copy, Debug)]
+pub struct Taken<R> {
+    n: usize,
+    reducer: R,
+}
+
+impl<A, C, R> Reducer<A, C> for Taken<R>
+where
+    R: Reducer<A, C>,
+{
+    type State = (R::State, usize);
+
+    fn init(&self) -> Self::State {
+        (self.reducer.init(), self.n)
+    }
+
+    fn step(&self, (state, left): Self::State, item: A) -> ControlFlow<Self::State, Self::State> {
+        if left == 0 {
+            return ControlFlow::Break((state, 0));
+        }
+        match self.reducer.step(state, item) {
+            ControlFlow::Continue(state) if left > 1 => ControlFlow::Continue((state, left - 1)),
+            ControlFlow::Continue(state) | ControlFlow::Break(state) => {
+                ControlFlow::Break((state, 0))
+            }
+        }
+    }
+
+    fn complete(&self, (state, _): Self::State) -> C {
+        self.reducer.complete(state)
+    }
+}
+
+/// Creates a transducer which keeps only the first `n` items, and stops the reduction once it
+/// has them, so that no more items are consumed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::into_vec;
+/// # use fp_std::transducer::{take, transduce};
+/// assert_eq!(transduce(take(2), into_vec())(1..), vec![1, 2]);
+/// assert_eq!(transduce(take(0), into_vec())(1..), Vec::<u32>::new());
+/// ```
+pub const fn take(n: usize) -> Take {
+    Take { n }
+}
+
+/* PARTITIONING ***************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A transducer which groups items into chunks. See [partition](crate::transducer::partition).
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Partition {
+    size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<R> Transducer<R> for Partition {
+    type Output = Partitioned<R>;
+
+    fn transduce(self, reducer: R) -> Partitioned<R> {
+        Partitioned {
+            size: self.size,
+            reducer,
+        }
+    }
+}
+
+/// A reducer which passes on chunks of items to another reducer. See
+/// [partition](crate::transducer::partition).
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct Partitioned<R> {
+    size: usize,
+    reducer: R,
+}
+
+#[cfg(feature = "alloc")]
+impl<A, C, R> Reducer<A, C> for Partitioned<R>
+where
+    R: Reducer<Vec<A>, C>,
+{
+    type State = (R::State, Vec<A>);
+
+    fn init(&self) -> Self::State {
+        (self.reducer.init(), Vec::with_capacity(self.size))
+    }
+
+    fn step(
+        &self,
+        (state, mut chunk): Self::State,
+        item: A,
+    ) -> ControlFlow<Self::State, Self::State> {
+        chunk.push(item);
+        if chunk.len() < self.size {
+            return ControlFlow::Continue((state, chunk));
+        }
+        match self.reducer.step(state, chunk) {
+            ControlFlow::Continue(state) => {
+                ControlFlow::Continue((state, Vec::with_capacity(self.size)))
+            }
+            ControlFlow::Break(state) => ControlFlow::Break((state, Vec::new())),
+        }
+    }
+
+    fn complete(&self, (state, chunk): Self::State) -> C {
+        if chunk.is_empty() {
+            return self.reducer.complete(state);
+        }
+        match self.reducer.step(state, chunk) {
+            ControlFlow::Continue(state) | ControlFlow::Break(state) => {
+                self.reducer.complete(state)
+            }
+        }
+    }
+}
+
+/// Creates a transducer which groups items, in order, into chunks of `size` items. The last
+/// chunk has fewer items if there are not enough to fill it.
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::reducer::into_vec;
+/// # use fp_std::transducer::{partition, transduce};
+/// let pairs = transduce(partition(2), into_vec());
+/// assert_eq!(pairs(1..=5), vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn partition(size: usize) -> Partition {
+    assert!(size > 0, "chunk size must be greater than zero");
+    Partition { size }
+}
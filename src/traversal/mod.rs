@@ -0,0 +1,533 @@
+//! Traversals: optics which focus on any number of parts of a structure at once, so that all of
+//! them can be read or updated together.
+
+/* TRAVERSALS *****************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// An optic which focuses on some number of values of type `A` within an `S`.
+///
+/// Unlike a [Lens](fp_core::lens::Lens), which is implemented by a type, a traversal is a
+/// value, so that it can be configured when it is constructed.
+pub trait Traversal<S, A> {
+    /// Calls a function with each focused value, in order.
+    fn for_each<'a, F>(&self, s: &'a S, f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a;
+
+    /// Replaces each focused value with the result of a function of it.
+    fn over<F>(&self, s: S, f: F) -> S
+    where
+        F: FnMut(A) -> A;
+
+    /// Collects references to each focused value, in order.
+    #[cfg(feature = "alloc")]
+    fn to_vec<'a>(&self, s: &'a S) -> Vec<&'a A>
+    where
+        A: 'a,
+    {
+        let mut values = Vec::new();
+        self.for_each(s, |a| values.push(a));
+        values
+    }
+}
+
+/* FIELDS *********************************************************************/
+
+/// Types whose fields, or some of them, all have the type `A`, and so can be traversed
+/// together. See [each_field](crate::traversal::each_field).
+///
+/// With the `derive` feature, this can be derived for structs. Either every field must have the
+/// same type, or the type of the fields to visit must be given with `#[traverse(Type)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// # use fp_std::traversal::{each_field, Traversal, Traverse};
+/// #[derive(Traverse, Debug, PartialEq)]
+/// struct Margins { top: f64, right: f64, bottom: f64, left: f64 }
+///
+/// #[derive(Traverse, Debug, PartialEq)]
+/// #[traverse(f64)]
+/// struct Label { text: &'static str, width: f64, height: f64 }
+///
+/// let margins = Margins { top: 1.0, right: 2.0, bottom: 1.0, left: 2.0 };
+/// assert_eq!(
+///     each_field().over(margins, |x| x * 2.0),
+///     Margins { top: 2.0, right: 4.0, bottom: 2.0, left: 4.0 },
+/// );
+///
+/// let label = Label { text: "OK", width: 3.0, height: 1.5 };
+/// assert_eq!(each_field().to_vec(&label), vec![&3.0, &1.5]);
+/// # }
+/// ```
+pub trait Traverse<A>: Sized {
+    /// Calls a function with each field of type `A`, in order.
+    fn for_each_field<'a, F>(&'a self, f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a;
+
+    /// Replaces each field of type `A` with the result of a function of it.
+    fn map_fields<F>(self, f: F) -> Self
+    where
+        F: FnMut(A) -> A;
+}
+
+#[cfg(feature = "derive")]
+pub use fp_std_derive::Traverse;
+
+/// A traversal of every field of a [Traverse](crate::traversal::Traverse) type. Constructed
+/// by [each_field](crate::traversal::each_field).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EachField;
+
+impl<S, A> Traversal<S, A> for EachField
+where
+    S: Traverse<A>,
+{
+    fn for_each<'a, F>(&self, s: &'a S, f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        s.for_each_field(f)
+    }
+
+    fn over<F>(&self, s: S, f: F) -> S
+    where
+        F: FnMut(A) -> A,
+    {
+        s.map_fields(f)
+    }
+}
+
+/// Creates a traversal of every field of a [Traverse](crate::traversal::Traverse) type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{each_field, Traversal, Traverse};
+/// struct Rgb(u8, u8, u8);
+///
+/// impl Traverse<u8> for Rgb {
+///     fn for_each_field<'a, F>(&'a self, mut f: F)
+///     where
+///         F: FnMut(&'a u8),
+///     {
+///         f(&self.0);
+///         f(&self.1);
+///         f(&self.2);
+///     }
+///
+///     fn map_fields<F>(self, mut f: F) -> Self
+///     where
+///         F: FnMut(u8) -> u8,
+///     {
+///         Rgb(f(self.0), f(self.1), f(self.2))
+///     }
+/// }
+///
+/// let dimmed = each_field().over(Rgb(200, 100, 50), |c| c / 2);
+/// assert_eq!(each_field().to_vec(&dimmed), vec![&100, &50, &25]);
+/// ```
+pub fn each_field() -> EachField {
+    EachField
+}
+
+/* COLLECTIONS ****************************************************************/
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// A traversal of every item of a collection: a `Vec`, an array, a boxed slice, or an
+/// `Option`, which has one item or none. Constructed by [each](crate::traversal::each).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Each;
+
+#[cfg(feature = "alloc")]
+impl<A> Traversal<Vec<A>, A> for Each {
+    fn for_each<'a, F>(&self, s: &'a Vec<A>, f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        s.iter().for_each(f)
+    }
+
+    fn over<F>(&self, s: Vec<A>, f: F) -> Vec<A>
+    where
+        F: FnMut(A) -> A,
+    {
+        s.into_iter().map(f).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A> Traversal<Box<[A]>, A> for Each {
+    fn for_each<'a, F>(&self, s: &'a Box<[A]>, f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        s.iter().for_each(f)
+    }
+
+    fn over<F>(&self, s: Box<[A]>, f: F) -> Box<[A]>
+    where
+        F: FnMut(A) -> A,
+    {
+        Vec::from(s).into_iter().map(f).collect()
+    }
+}
+
+impl<A, const N: usize> Traversal<[A; N], A> for Each {
+    fn for_each<'a, F>(&self, s: &'a [A; N], f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        s.iter().for_each(f)
+    }
+
+    fn over<F>(&self, s: [A; N], f: F) -> [A; N]
+    where
+        F: FnMut(A) -> A,
+    {
+        s.map(f)
+    }
+}
+
+impl<A> Traversal<Option<A>, A> for Each {
+    fn for_each<'a, F>(&self, s: &'a Option<A>, f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        s.iter().for_each(f)
+    }
+
+    fn over<F>(&self, s: Option<A>, f: F) -> Option<A>
+    where
+        F: FnMut(A) -> A,
+    {
+        s.map(f)
+    }
+}
+
+/// Creates a traversal of every item of a collection.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{each, Traversal};
+/// assert_eq!(each().over(vec![1, 2, 3], |x| x * 2), vec![2, 4, 6]);
+/// assert_eq!(each().over([1, 2], |x| x + 1), [2, 3]);
+/// assert_eq!(each().over(Some(1), |x| x + 1), Some(2));
+///
+/// let boxed: Box<[u32]> = vec![1, 2].into_boxed_slice();
+/// assert_eq!(each().to_vec(&boxed), vec![&1, &2]);
+/// ```
+pub fn each() -> Each {
+    Each
+}
+
+/* COMPOSITION ****************************************************************/
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use fp_core::lens::Lens;
+
+/// A traversal which focuses through `Outer` on some number of `B`s, and then through `Inner` on
+/// some number of `A`s within each of them. See
+/// [compose_traversals](crate::traversal::compose_traversals).
+///
+/// Since the signature of [for_each](crate::traversal::Traversal::for_each) offers no way to
+/// relate the lifetime of the intermediate type `B` to anything else, `B` must be `'static`.
+/// The same goes for the compositions of traversals with lenses.
+#[derive(Debug)]
+pub struct ComposeTraversal<Outer, Inner, B> {
+    outer: Outer,
+    inner: Inner,
+    focus: PhantomData<fn() -> B>,
+}
+
+impl<S, A, B, Outer, Inner> Traversal<S, A> for ComposeTraversal<Outer, Inner, B>
+where
+    B: 'static,
+    Outer: Traversal<S, B>,
+    Inner: Traversal<B, A>,
+{
+    fn for_each<'a, F>(&self, s: &'a S, mut f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        self.outer.for_each(s, |b| self.inner.for_each(b, &mut f))
+    }
+
+    fn over<F>(&self, s: S, mut f: F) -> S
+    where
+        F: FnMut(A) -> A,
+    {
+        self.outer.over(s, |b| self.inner.over(b, &mut f))
+    }
+}
+
+/// Composes two traversals, so that the result focuses through `outer`, and then through
+/// `inner`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{compose_traversals, each, Traversal};
+/// let grid = vec![vec![1, 2], vec![3]];
+/// let cells = compose_traversals(each(), each());
+/// assert_eq!(cells.to_vec(&grid), vec![&1, &2, &3]);
+/// assert_eq!(cells.over(grid, |x| x * 10), vec![vec![10, 20], vec![30]]);
+/// ```
+pub fn compose_traversals<Outer, Inner, B>(
+    outer: Outer,
+    inner: Inner,
+) -> ComposeTraversal<Outer, Inner, B> {
+    ComposeTraversal {
+        outer,
+        inner,
+        focus: PhantomData,
+    }
+}
+
+/// A traversal which focuses through a traversal `T` on some number of `B`s, and then through
+/// a lens `L` on part of each of them. See
+/// [compose_traversal_lens](crate::traversal::compose_traversal_lens).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct TraversalLens<T, L, B> {
+    traversal: T,
+    lens: PhantomData<fn() -> L>,
+    focus: PhantomData<fn() -> B>,
+}
+
+#[cfg(feature = "std")]
+impl<S, A, B, T, L> Traversal<S, A> for TraversalLens<T, L, B>
+where
+    A: Clone,
+    B: 'static,
+    T: Traversal<S, B>,
+    L: Lens<B, A>,
+{
+    fn for_each<'a, F>(&self, s: &'a S, mut f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        self.traversal
+            .for_each(s, |b| L::get(b).into_iter().for_each(&mut f))
+    }
+
+    fn over<F>(&self, s: S, mut f: F) -> S
+    where
+        F: FnMut(A) -> A,
+    {
+        self.traversal.over(s, |b| match L::get(&b).cloned() {
+            Some(a) => L::set(f(a), &b),
+            None => b,
+        })
+    }
+}
+
+/// Composes a traversal with a lens, to focus on part of each value that the traversal focuses
+/// on.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{compose_traversal_lens, each, Traversal};
+/// # use fp_std::tuple::LensSecond;
+/// let scores = compose_traversal_lens(each(), LensSecond);
+/// let players = vec![("ann", 3), ("bo", 5)];
+/// assert_eq!(scores.to_vec(&players), vec![&3, &5]);
+/// assert_eq!(scores.over(players, |x| x + 1), vec![("ann", 4), ("bo", 6)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn compose_traversal_lens<T, L, B>(traversal: T, _lens: L) -> TraversalLens<T, L, B> {
+    TraversalLens {
+        traversal,
+        lens: PhantomData,
+        focus: PhantomData,
+    }
+}
+
+/// A traversal which focuses through a lens `L` on a `B`, and then through a traversal `T` on
+/// some number of values within it. See
+/// [compose_lens_traversal](crate::traversal::compose_lens_traversal).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct LensTraversal<L, T, B> {
+    lens: PhantomData<fn() -> L>,
+    traversal: T,
+    focus: PhantomData<fn() -> B>,
+}
+
+#[cfg(feature = "std")]
+impl<S, A, B, L, T> Traversal<S, A> for LensTraversal<L, T, B>
+where
+    B: Clone + 'static,
+    L: Lens<S, B>,
+    T: Traversal<B, A>,
+{
+    fn for_each<'a, F>(&self, s: &'a S, f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        if let Some(b) = L::get(s) {
+            self.traversal.for_each(b, f);
+        }
+    }
+
+    fn over<F>(&self, s: S, f: F) -> S
+    where
+        F: FnMut(A) -> A,
+    {
+        match L::get(&s).cloned() {
+            Some(b) => L::set(self.traversal.over(b, f), &s),
+            None => s,
+        }
+    }
+}
+
+/// Composes a lens with a traversal, to focus on the values within the part of a structure
+/// that the lens focuses on.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{compose_lens_traversal, each, Traversal};
+/// # use fp_std::tuple::LensSecond;
+/// let members = compose_lens_traversal(LensSecond, each());
+/// let team = ("red", vec!["ann", "cy"]);
+/// assert_eq!(members.to_vec(&team), vec![&"ann", &"cy"]);
+/// assert_eq!(members.over(team, |s| &s[..1]), ("red", vec!["a", "c"]));
+/// ```
+#[cfg(feature = "std")]
+pub fn compose_lens_traversal<L, T, B>(_lens: L, traversal: T) -> LensTraversal<L, T, B> {
+    LensTraversal {
+        lens: PhantomData,
+        traversal,
+        focus: PhantomData,
+    }
+}
+
+/* FILTERING ******************************************************************/
+
+/// A traversal which focuses on only those values focused on by a traversal `T` which satisfy
+/// a predicate `P`. See [filtered](crate::traversal::filtered).
+#[derive(Clone, Copy, Debug)]
+pub struct Filtered<T, P> {
+    traversal: T,
+    pred: P,
+}
+
+impl<S, A, T, P> Traversal<S, A> for Filtered<T, P>
+where
+    T: Traversal<S, A>,
+    P: Fn(&A) -> bool,
+{
+    fn for_each<'a, F>(&self, s: &'a S, mut f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        self.traversal.for_each(s, |a| {
+            if (self.pred)(a) {
+                f(a)
+            }
+        })
+    }
+
+    fn over<F>(&self, s: S, mut f: F) -> S
+    where
+        F: FnMut(A) -> A,
+    {
+        self.traversal
+            .over(s, |a| if (self.pred)(&a) { f(a) } else { a })
+    }
+}
+
+/// Refines a traversal to focus only on the values which satisfy a predicate, so that only
+/// those are visited or updated.
+///
+/// The result is only a lawful traversal if updating a value never changes whether it
+/// satisfies the predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{each, filtered, Traversal, TraversalExt};
+/// # use fp_std::tuple::LensSecond;
+/// let negatives = filtered(each(), |x: &i32| *x < 0);
+/// assert_eq!(negatives.to_vec(&vec![1, -2, 3, -4]), vec![&-2, &-4]);
+/// assert_eq!(negatives.over(vec![1, -2, 3, -4], |x| x * 10), vec![1, -20, 3, -40]);
+///
+/// let active_scores = each()
+///     .filtered(|user: &(bool, u32)| user.0)
+///     .then_lens(LensSecond);
+/// let users = vec![(true, 1), (false, 2), (true, 3)];
+/// assert_eq!(active_scores.over(users, |x| x + 100), vec![(true, 101), (false, 2), (true, 103)]);
+/// ```
+pub fn filtered<T, P>(traversal: T, pred: P) -> Filtered<T, P> {
+    Filtered { traversal, pred }
+}
+
+/* METHODS ********************************************************************/
+
+/// Exposes the combinators of this module as methods, so that traversals can be built up in
+/// the order they focus. Each method behaves exactly as the free function it refers to.
+///
+/// This is implemented for the traversals of this crate. Another implementor of
+/// [Traversal](crate::traversal::Traversal) can gain these methods with an empty
+/// `impl TraversalExt for MyTraversal {}`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{each, Traversal, TraversalExt};
+/// let grid = vec![vec![1, -2], vec![-3]];
+/// let negative_cells = each().then(each()).filtered(|x: &i32| *x < 0);
+/// assert_eq!(negative_cells.to_vec(&grid), vec![&-2, &-3]);
+/// ```
+pub trait TraversalExt: Sized {
+    /// Refines this traversal to focus only on the values which satisfy a predicate. See
+    /// [filtered](crate::traversal::filtered).
+    fn filtered<P>(self, pred: P) -> Filtered<Self, P> {
+        filtered(self, pred)
+    }
+
+    /// Composes this traversal with another, which focuses within each of the values this one
+    /// focuses on. See [compose_traversals](crate::traversal::compose_traversals).
+    fn then<Inner, B>(self, inner: Inner) -> ComposeTraversal<Self, Inner, B> {
+        compose_traversals(self, inner)
+    }
+
+    /// Composes this traversal with a lens, which focuses on part of each of the values this
+    /// one focuses on. See [compose_traversal_lens](crate::traversal::compose_traversal_lens).
+    #[cfg(feature = "std")]
+    fn then_lens<L, B>(self, lens: L) -> TraversalLens<Self, L, B> {
+        compose_traversal_lens(self, lens)
+    }
+}
+
+impl TraversalExt for EachField {}
+
+impl TraversalExt for Each {}
+
+impl<Outer, Inner, B> TraversalExt for ComposeTraversal<Outer, Inner, B> {}
+
+#[cfg(feature = "std")]
+impl<T, L, B> TraversalExt for TraversalLens<T, L, B> {}
+
+#[cfg(feature = "std")]
+impl<L, T, B> TraversalExt for LensTraversal<L, T, B> {}
+
+impl<T, P> TraversalExt for Filtered<T, P> {}
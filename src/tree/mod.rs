@@ -0,0 +1,253 @@
+//! Rose trees, whose nodes each hold a value and any number of children, and functions for
+//! visiting and editing them by path.
+//!
+//! A path is the list of child indices which leads from the root to a node, so the root
+//! itself is at the empty path, its first child at `[0]`, and that child's second child at
+//! `[0, 1]`.
+//!
+//! ```rust
+//! # use fp_std::tree::Tree;
+//! let mut menu = Tree::new("root", vec![
+//!     Tree::new("file", vec![Tree::leaf("open"), Tree::leaf("save")]),
+//!     Tree::new("edit", vec![Tree::leaf("undo")]),
+//! ]);
+//!
+//! let path = menu.find_path(|item| *item == "save").unwrap();
+//! assert_eq!(path, vec![0, 1]);
+//! menu.modify_at_path(&path, |node| node.children.push(Tree::leaf("save as")));
+//! assert_eq!(menu.get(&[0, 1, 0]).map(|node| node.value), Some("save as"));
+//! ```
+
+/* TREES **********************************************************************/
+use alloc::vec::Vec;
+
+/// A tree whose nodes each hold a value, and any number of child trees, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tree::Tree;
+/// let tree = Tree::new(1, vec![Tree::leaf(2), Tree::new(3, vec![Tree::leaf(4)])]);
+/// assert_eq!(tree.map(|n| n * 10).fold(|n, sums: Vec<u32>| n + sums.iter().sum::<u32>()), 100);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tree<T> {
+    /// The value at the root of the tree.
+    pub value: T,
+    /// The subtrees below the root.
+    pub children: Vec<Tree<T>>,
+}
+
+impl<T> Tree<T> {
+    /// Creates a tree from its root value and its children.
+    pub const fn new(value: T, children: Vec<Tree<T>>) -> Self {
+        Tree { value, children }
+    }
+
+    /// Creates a tree of one node, with no children.
+    pub const fn leaf(value: T) -> Self {
+        Tree::new(value, Vec::new())
+    }
+
+    /// Transforms the value of every node, keeping the shape of the tree.
+    pub fn map<U, F>(self, mut f: F) -> Tree<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map_with_path(|_, value| f(value))
+    }
+
+    /// Folds the tree from its leaves up to its root, combining the value of each node with
+    /// the results of folding each of its children.
+    pub fn fold<B, F>(&self, mut f: F) -> B
+    where
+        F: FnMut(&T, Vec<B>) -> B,
+    {
+        self.fold_with_path(|_, value, children| f(value, children))
+    }
+
+    /// Borrows the subtree at a path, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tree::Tree;
+    /// let tree = Tree::new('a', vec![Tree::new('b', vec![Tree::leaf('c')])]);
+    /// assert_eq!(tree.get(&[0, 0]), Some(&Tree::leaf('c')));
+    /// assert_eq!(tree.get(&[]).map(|node| node.value), Some('a'));
+    /// assert_eq!(tree.get(&[1]), None);
+    /// ```
+    pub fn get(&self, path: &[usize]) -> Option<&Tree<T>> {
+        path.iter().try_fold(self, |node, &i| node.children.get(i))
+    }
+
+    /// Mutably borrows the subtree at a path, if there is one.
+    pub fn get_mut(&mut self, path: &[usize]) -> Option<&mut Tree<T>> {
+        path.iter()
+            .try_fold(self, |node, &i| node.children.get_mut(i))
+    }
+
+    /// Folds the tree from its leaves up to its root, like [fold](crate::tree::Tree::fold),
+    /// also passing the path of each node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tree::Tree;
+    /// let tree = Tree::new("a", vec![Tree::leaf("b"), Tree::new("c", vec![Tree::leaf("d")])]);
+    /// let deepest = tree.fold_with_path(|path, _, depths: Vec<usize>| {
+    ///     depths.into_iter().max().unwrap_or(path.len())
+    /// });
+    /// assert_eq!(deepest, 2);
+    /// ```
+    pub fn fold_with_path<B, F>(&self, mut f: F) -> B
+    where
+        F: FnMut(&[usize], &T, Vec<B>) -> B,
+    {
+        fn go<T, B, F>(node: &Tree<T>, path: &mut Vec<usize>, f: &mut F) -> B
+        where
+            F: FnMut(&[usize], &T, Vec<B>) -> B,
+        {
+            let children = node
+                .children
+                .iter()
+                .enumerate()
+                .map(|(i, child)| {
+                    path.push(i);
+                    let b = go(child, path, f);
+                    path.pop();
+                    b
+                })
+                .collect();
+            f(path, &node.value, children)
+        }
+        go(self, &mut Vec::new(), &mut f)
+    }
+
+    /// Visits every node, each before its children, passing its path and value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tree::Tree;
+    /// let outline = Tree::new("intro", vec![Tree::leaf("scope"), Tree::leaf("terms")]);
+    /// let mut lines = vec![];
+    /// outline.for_each_with_path(|path, title| {
+    ///     lines.push(format!("{}{}", "  ".repeat(path.len()), title))
+    /// });
+    /// assert_eq!(lines, vec!["intro", "  scope", "  terms"]);
+    /// ```
+    pub fn for_each_with_path<F>(&self, mut f: F)
+    where
+        F: FnMut(&[usize], &T),
+    {
+        fn go<T, F>(node: &Tree<T>, path: &mut Vec<usize>, f: &mut F)
+        where
+            F: FnMut(&[usize], &T),
+        {
+            f(path, &node.value);
+            for (i, child) in node.children.iter().enumerate() {
+                path.push(i);
+                go(child, path, f);
+                path.pop();
+            }
+        }
+        go(self, &mut Vec::new(), &mut f)
+    }
+
+    /// Transforms the value of every node, each before its children, passing its path,
+    /// keeping the shape of the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tree::Tree;
+    /// let numbered = Tree::new("a", vec![Tree::leaf("b"), Tree::leaf("c")])
+    ///     .map_with_path(|path, title| format!("{:?} {}", path, title));
+    /// assert_eq!(numbered.get(&[1]).map(|node| node.value.as_str()), Some("[1] c"));
+    /// ```
+    pub fn map_with_path<U, F>(self, mut f: F) -> Tree<U>
+    where
+        F: FnMut(&[usize], T) -> U,
+    {
+        fn go<T, U, F>(node: Tree<T>, path: &mut Vec<usize>, f: &mut F) -> Tree<U>
+        where
+            F: FnMut(&[usize], T) -> U,
+        {
+            let value = f(path, node.value);
+            let children = node
+                .children
+                .into_iter()
+                .enumerate()
+                .map(|(i, child)| {
+                    path.push(i);
+                    let child = go(child, path, f);
+                    path.pop();
+                    child
+                })
+                .collect();
+            Tree::new(value, children)
+        }
+        go(self, &mut Vec::new(), &mut f)
+    }
+
+    /// Finds the path of the first node, visiting each before its children, whose value
+    /// satisfies a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tree::Tree;
+    /// let tree = Tree::new(1, vec![Tree::new(2, vec![Tree::leaf(4)]), Tree::leaf(4)]);
+    /// assert_eq!(tree.find_path(|n| *n == 4), Some(vec![0, 0]));
+    /// assert_eq!(tree.find_path(|n| *n == 1), Some(vec![]));
+    /// assert_eq!(tree.find_path(|n| *n == 3), None);
+    /// ```
+    pub fn find_path<P>(&self, pred: P) -> Option<Vec<usize>>
+    where
+        P: Fn(&T) -> bool,
+    {
+        fn go<T, P>(node: &Tree<T>, path: &mut Vec<usize>, pred: &P) -> bool
+        where
+            P: Fn(&T) -> bool,
+        {
+            if pred(&node.value) {
+                return true;
+            }
+            for (i, child) in node.children.iter().enumerate() {
+                path.push(i);
+                if go(child, path, pred) {
+                    return true;
+                }
+                path.pop();
+            }
+            false
+        }
+        let mut path = Vec::new();
+        if go(self, &mut path, &pred) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    /// Applies a function to the subtree at a path, returning its result, or `None` if there
+    /// is no subtree at that path. The function may change the value of the subtree, or its
+    /// children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tree::Tree;
+    /// let mut tree = Tree::new(0, vec![Tree::leaf(1)]);
+    /// assert_eq!(tree.modify_at_path(&[0], |node| node.value += 10), Some(()));
+    /// assert_eq!(tree.modify_at_path(&[0, 3], |node| node.value += 10), None);
+    /// assert_eq!(tree, Tree::new(0, vec![Tree::leaf(11)]));
+    /// ```
+    pub fn modify_at_path<R, F>(&mut self, path: &[usize], f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Tree<T>) -> R,
+    {
+        self.get_mut(path).map(f)
+    }
+}
@@ -2,7 +2,8 @@
 
 /* BASIC FUNCTIONS ************************************************************/
 
-/// Constructs a tuple of two copies of the same value.
+/// Constructs a tuple of two copies of the same value. For use in constant expressions, see
+/// [constants::duplicate](crate::constants::duplicate).
 ///
 /// # Examples
 ///
@@ -21,11 +22,27 @@ pub fn duplicate<A: Clone>(a: A) -> (A, A) {
 /// ```rust
 /// # use fp_std::tuple::cons;
 /// assert_eq!(cons(1, 2), (1, 2));
+///
+/// const PAIR: (u8, char) = cons(1, 'a');
+/// assert_eq!(PAIR, (1, 'a'));
 /// ```
-pub fn cons<A, B>(a: A, b: B) -> (A, B) {
+pub const fn cons<A, B>(a: A, b: B) -> (A, B) {
     (a, b)
 }
 
+/// Swaps the elements of a 2-tuple. For use in constant expressions, see
+/// [constants::swap](crate::constants::swap).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::swap;
+/// assert_eq!(swap((1, "a")), ("a", 1));
+/// ```
+pub fn swap<A, B>((a, b): (A, B)) -> (B, A) {
+    (b, a)
+}
+
 /// Spreads a 2-tuple into the arguments of a 2-arity function.
 ///
 /// # Examples
@@ -43,7 +60,458 @@ where
     move |(a, b)| f(a, b)
 }
 
-/// Returns the first element in a 2-tuple.
+/// Spreads a 2-tuple into the arguments of a 2-arity function which may mutate its state. See
+/// [spread](crate::tuple::spread).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::spread_mut;
+/// let mut totals = std::collections::HashMap::new();
+/// vec![("a", 1), ("b", 2), ("a", 3)]
+///     .into_iter()
+///     .for_each(spread_mut(|k, v| *totals.entry(k).or_insert(0) += v));
+/// assert_eq!(totals["a"], 4);
+/// ```
+pub fn spread_mut<A, B, C, F>(mut f: F) -> impl FnMut((A, B)) -> C
+where
+    F: FnMut(A, B) -> C,
+{
+    move |(a, b)| f(a, b)
+}
+
+/// Spreads a 2-tuple into the arguments of a 2-arity function which can only be called once.
+/// See [spread](crate::tuple::spread).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::spread_once;
+/// let mut items = vec!["a"];
+/// let insert = spread_once(move |index: usize, item: &str| {
+///     items.insert(index, item);
+///     items
+/// });
+/// assert_eq!(insert((0, "b")), vec!["b", "a"]);
+/// ```
+pub fn spread_once<A, B, C, F>(f: F) -> impl FnOnce((A, B)) -> C
+where
+    F: FnOnce(A, B) -> C,
+{
+    move |(a, b)| f(a, b)
+}
+
+macro_rules! spread_n {
+    ($(#[$meta:meta])* [$name:ident, $name_mut:ident, $name_once:ident]($($t:ident $v:ident),+)) => {
+        $(#[$meta])*
+        pub fn $name<$($t,)+ Output, Function>(f: Function) -> impl Fn(($($t,)+)) -> Output
+        where
+            Function: Fn($($t),+) -> Output,
+        {
+            move |($($v,)+)| f($($v),+)
+        }
+
+        #[doc = concat!(
+            "Spreads a tuple into the arguments of a function which may mutate its state. See [",
+            stringify!($name), "](crate::tuple::", stringify!($name), ")."
+        )]
+        pub fn $name_mut<$($t,)+ Output, Function>(
+            mut f: Function,
+        ) -> impl FnMut(($($t,)+)) -> Output
+        where
+            Function: FnMut($($t),+) -> Output,
+        {
+            move |($($v,)+)| f($($v),+)
+        }
+
+        #[doc = concat!(
+            "Spreads a tuple into the arguments of a function which can only be called once. See [",
+            stringify!($name), "](crate::tuple::", stringify!($name), ")."
+        )]
+        pub fn $name_once<$($t,)+ Output, Function>(f: Function) -> impl FnOnce(($($t,)+)) -> Output
+        where
+            Function: FnOnce($($t),+) -> Output,
+        {
+            move |($($v,)+)| f($($v),+)
+        }
+    };
+}
+
+spread_n!(
+    /// Spreads a 3-tuple into the arguments of a 3-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread3;
+    /// let sum = spread3(|a: u32, b: u32, c: u32| a + b + c);
+    /// assert_eq!(sum((1, 2, 3)), 6);
+    /// ```
+    [spread3, spread3_mut, spread3_once](A a, B b, C c)
+);
+
+spread_n!(
+    /// Spreads a 4-tuple into the arguments of a 4-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread4;
+    /// let sum = spread4(|a: u32, b: u32, c: u32, d: u32| a + b + c + d);
+    /// assert_eq!(sum((1, 2, 3, 4)), 10);
+    /// ```
+    [spread4, spread4_mut, spread4_once](A a, B b, C c, D d)
+);
+
+spread_n!(
+    /// Spreads a 5-tuple into the arguments of a 5-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread5;
+    /// let sum = spread5(|a: u32, b: u32, c: u32, d: u32, e: u32| a + b + c + d + e);
+    /// assert_eq!(sum((1, 2, 3, 4, 5)), 15);
+    /// ```
+    [spread5, spread5_mut, spread5_once](A a, B b, C c, D d, E e)
+);
+
+spread_n!(
+    /// Spreads a 6-tuple into the arguments of a 6-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread6;
+    /// let sum = spread6(|a: u32, b: u32, c: u32, d: u32, e: u32, f: u32| a + b + c + d + e + f);
+    /// assert_eq!(sum((1, 2, 3, 4, 5, 6)), 21);
+    /// ```
+    [spread6, spread6_mut, spread6_once](A a, B b, C c, D d, E e, F f)
+);
+
+spread_n!(
+    /// Spreads a 7-tuple into the arguments of a 7-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread7;
+    /// let sum = spread7(|a: u32, b: u32, c: u32, d: u32, e: u32, f: u32, g: u32| {
+    ///     a + b + c + d + e + f + g
+    /// });
+    /// assert_eq!(sum((1, 2, 3, 4, 5, 6, 7)), 28);
+    /// ```
+    [spread7, spread7_mut, spread7_once](A a, B b, C c, D d, E e, F f, G g)
+);
+
+spread_n!(
+    /// Spreads an 8-tuple into the arguments of an 8-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread8;
+    /// let sum = spread8(|a: u32, b: u32, c: u32, d: u32, e: u32, f: u32, g: u32, h: u32| {
+    ///     a + b + c + d + e + f + g + h
+    /// });
+    /// assert_eq!(sum((1, 2, 3, 4, 5, 6, 7, 8)), 36);
+    /// ```
+    [spread8, spread8_mut, spread8_once](A a, B b, C c, D d, E e, F f, G g, H h)
+);
+
+spread_n!(
+    /// Spreads a 9-tuple into the arguments of a 9-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread9;
+    /// let ends = spread9(|first: u32, _, _, _, _, _, _, _, last: u32| first + last);
+    /// assert_eq!(ends((1, 2, 3, 4, 5, 6, 7, 8, 9)), 10);
+    /// ```
+    [spread9, spread9_mut, spread9_once](A a, B b, C c, D d, E e, F f, G g, H h, I i)
+);
+
+spread_n!(
+    /// Spreads a 10-tuple into the arguments of a 10-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread10;
+    /// let ends = spread10(|first: u32, _, _, _, _, _, _, _, _, last: u32| first + last);
+    /// assert_eq!(ends((1, 2, 3, 4, 5, 6, 7, 8, 9, 10)), 11);
+    /// ```
+    [spread10, spread10_mut, spread10_once](A a, B b, C c, D d, E e, F f, G g, H h, I i, J j)
+);
+
+spread_n!(
+    /// Spreads an 11-tuple into the arguments of an 11-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread11;
+    /// let ends = spread11(|first: u32, _, _, _, _, _, _, _, _, _, last: u32| first + last);
+    /// assert_eq!(ends((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)), 12);
+    /// ```
+    [spread11, spread11_mut, spread11_once](A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k)
+);
+
+spread_n!(
+    /// Spreads a 12-tuple into the arguments of a 12-arity function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::spread12;
+    /// let ends = spread12(|first: u32, _, _, _, _, _, _, _, _, _, _, last: u32| first + last);
+    /// assert_eq!(ends((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)), 13);
+    /// ```
+    [spread12, spread12_mut, spread12_once](
+        A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l
+    )
+);
+
+/// Gathers the arguments of a 2-arity function into a 2-tuple, which is passed to a function
+/// of a tuple. This is the inverse of [spread](crate::tuple::spread).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::gather;
+/// let area = gather(|(w, h): (u32, u32)| w * h);
+/// assert_eq!(area(3, 4), 12);
+/// ```
+pub fn gather<A, B, C, F>(f: F) -> impl Fn(A, B) -> C
+where
+    F: Fn((A, B)) -> C,
+{
+    move |a, b| f((a, b))
+}
+
+/// Gathers the arguments of a 2-arity function into a 2-tuple, which is passed to a function
+/// of a tuple which may mutate its state. See [gather](crate::tuple::gather).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::gather_mut;
+/// let mut pairs = vec![];
+/// {
+///     let mut record = gather_mut(|pair: (&str, u32)| pairs.push(pair));
+///     record("a", 1);
+///     record("b", 2);
+/// }
+/// assert_eq!(pairs, vec![("a", 1), ("b", 2)]);
+/// ```
+pub fn gather_mut<A, B, C, F>(mut f: F) -> impl FnMut(A, B) -> C
+where
+    F: FnMut((A, B)) -> C,
+{
+    move |a, b| f((a, b))
+}
+
+/// Gathers the arguments of a 2-arity function into a 2-tuple, which is passed to a function
+/// of a tuple which can only be called once. See [gather](crate::tuple::gather).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::gather_once;
+/// let name = String::from("ann");
+/// let greet = gather_once(move |(greeting, punctuation): (&str, char)| {
+///     format!("{}, {}{}", greeting, name, punctuation)
+/// });
+/// assert_eq!(greet("hello", '!'), "hello, ann!");
+/// ```
+pub fn gather_once<A, B, C, F>(f: F) -> impl FnOnce(A, B) -> C
+where
+    F: FnOnce((A, B)) -> C,
+{
+    move |a, b| f((a, b))
+}
+
+macro_rules! gather_n {
+    ($(#[$meta:meta])* [$name:ident, $name_mut:ident, $name_once:ident]($($t:ident $v:ident),+)) => {
+        $(#[$meta])*
+        pub fn $name<$($t,)+ Output, Function>(f: Function) -> impl Fn($($t),+) -> Output
+        where
+            Function: Fn(($($t,)+)) -> Output,
+        {
+            move |$($v),+| f(($($v,)+))
+        }
+
+        #[doc = concat!(
+            "Gathers the arguments of a function into a tuple, which is passed to a function ",
+            "which may mutate its state. See [",
+            stringify!($name), "](crate::tuple::", stringify!($name), ")."
+        )]
+        pub fn $name_mut<$($t,)+ Output, Function>(mut f: Function) -> impl FnMut($($t),+) -> Output
+        where
+            Function: FnMut(($($t,)+)) -> Output,
+        {
+            move |$($v),+| f(($($v,)+))
+        }
+
+        #[doc = concat!(
+            "Gathers the arguments of a function into a tuple, which is passed to a function ",
+            "which can only be called once. See [",
+            stringify!($name), "](crate::tuple::", stringify!($name), ")."
+        )]
+        pub fn $name_once<$($t,)+ Output, Function>(f: Function) -> impl FnOnce($($t),+) -> Output
+        where
+            Function: FnOnce(($($t,)+)) -> Output,
+        {
+            move |$($v),+| f(($($v,)+))
+        }
+    };
+}
+
+gather_n!(
+    /// Gathers the arguments of a 3-arity function into a 3-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread3](crate::tuple::spread3).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather3;
+    /// let sum = gather3(|(a, b, c): (u32, u32, u32)| a + b + c);
+    /// assert_eq!(sum(1, 2, 3), 6);
+    /// ```
+    [gather3, gather3_mut, gather3_once](A a, B b, C c)
+);
+
+gather_n!(
+    /// Gathers the arguments of a 4-arity function into a 4-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread4](crate::tuple::spread4).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather4;
+    /// let sum = gather4(|(a, b, c, d): (u32, u32, u32, u32)| a + b + c + d);
+    /// assert_eq!(sum(1, 2, 3, 4), 10);
+    /// ```
+    [gather4, gather4_mut, gather4_once](A a, B b, C c, D d)
+);
+
+gather_n!(
+    /// Gathers the arguments of a 5-arity function into a 5-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread5](crate::tuple::spread5).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather5;
+    /// let sum = gather5(|(a, b, c, d, e): (u32, u32, u32, u32, u32)| a + b + c + d + e);
+    /// assert_eq!(sum(1, 2, 3, 4, 5), 15);
+    /// ```
+    [gather5, gather5_mut, gather5_once](A a, B b, C c, D d, E e)
+);
+
+gather_n!(
+    /// Gathers the arguments of a 6-arity function into a 6-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread6](crate::tuple::spread6).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather6;
+    /// let ends = gather6(|(first, .., last)| (first, last));
+    /// assert_eq!(ends(1, 2, 3, 4, 5, 6), (1, 6));
+    /// ```
+    [gather6, gather6_mut, gather6_once](A a, B b, C c, D d, E e, F f)
+);
+
+gather_n!(
+    /// Gathers the arguments of a 7-arity function into a 7-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread7](crate::tuple::spread7).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather7;
+    /// let ends = gather7(|(first, .., last)| (first, last));
+    /// assert_eq!(ends(1, 2, 3, 4, 5, 6, 7), (1, 7));
+    /// ```
+    [gather7, gather7_mut, gather7_once](A a, B b, C c, D d, E e, F f, G g)
+);
+
+gather_n!(
+    /// Gathers the arguments of an 8-arity function into an 8-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread8](crate::tuple::spread8).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather8;
+    /// let ends = gather8(|(first, .., last)| (first, last));
+    /// assert_eq!(ends(1, 2, 3, 4, 5, 6, 7, 8), (1, 8));
+    /// ```
+    [gather8, gather8_mut, gather8_once](A a, B b, C c, D d, E e, F f, G g, H h)
+);
+
+gather_n!(
+    /// Gathers the arguments of a 9-arity function into a 9-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread9](crate::tuple::spread9).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather9;
+    /// let ends = gather9(|(first, .., last)| (first, last));
+    /// assert_eq!(ends(1, 2, 3, 4, 5, 6, 7, 8, 9), (1, 9));
+    /// ```
+    [gather9, gather9_mut, gather9_once](A a, B b, C c, D d, E e, F f, G g, H h, I i)
+);
+
+gather_n!(
+    /// Gathers the arguments of a 10-arity function into a 10-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread10](crate::tuple::spread10).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather10;
+    /// let ends = gather10(|(first, .., last)| (first, last));
+    /// assert_eq!(ends(1, 2, 3, 4, 5, 6, 7, 8, 9, 10), (1, 10));
+    /// ```
+    [gather10, gather10_mut, gather10_once](A a, B b, C c, D d, E e, F f, G g, H h, I i, J j)
+);
+
+gather_n!(
+    /// Gathers the arguments of an 11-arity function into an 11-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread11](crate::tuple::spread11).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather11;
+    /// let ends = gather11(|(first, .., last)| (first, last));
+    /// assert_eq!(ends(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11), (1, 11));
+    /// ```
+    [gather11, gather11_mut, gather11_once](A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k)
+);
+
+gather_n!(
+    /// Gathers the arguments of a 12-arity function into a 12-tuple, which is passed to a
+    /// function of a tuple. This is the inverse of [spread12](crate::tuple::spread12).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::tuple::gather12;
+    /// let ends = gather12(|(first, .., last)| (first, last));
+    /// assert_eq!(ends(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12), (1, 12));
+    /// ```
+    [gather12, gather12_mut, gather12_once](
+        A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l
+    )
+);
+
+/// Returns the first element in a 2-tuple. For use in constant expressions, see
+/// [constants::first](crate::constants::first).
 ///
 /// # Examples
 ///
@@ -55,7 +523,8 @@ pub fn first<A, B>((a, ..): (A, B)) -> A {
     a
 }
 
-/// Returns the second element in a 2-tuple.
+/// Returns the second element in a 2-tuple. For use in constant expressions, see
+/// [constants::second](crate::constants::second).
 ///
 /// # Examples
 ///
@@ -99,7 +568,456 @@ where
     move |(a, b)| (a, f(b))
 }
 
+/// Transforms the first element in a 2-tuple with a function which may mutate its state. See
+/// [map_first](crate::tuple::map_first).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::map_first_mut;
+/// let mut next = 0;
+/// let numbered: Vec<_> = vec![("", 'a'), ("", 'b')]
+///     .into_iter()
+///     .map(map_first_mut(|_: &str| {
+///         next += 1;
+///         next
+///     }))
+///     .collect();
+/// assert_eq!(numbered, vec![(1, 'a'), (2, 'b')]);
+/// ```
+pub fn map_first_mut<A, B, C, F>(mut f: F) -> impl FnMut((A, B)) -> (C, B)
+where
+    F: FnMut(A) -> C,
+{
+    move |(a, b)| (f(a), b)
+}
+
+/// Transforms the second element in a 2-tuple with a function which may mutate its state.
+/// See [map_second](crate::tuple::map_second).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::map_second_mut;
+/// let mut running = 0;
+/// let totals: Vec<_> = vec![("a", 1), ("b", 2)]
+///     .into_iter()
+///     .map(map_second_mut(|x: u32| {
+///         running += x;
+///         running
+///     }))
+///     .collect();
+/// assert_eq!(totals, vec![("a", 1), ("b", 3)]);
+/// ```
+pub fn map_second_mut<A, B, C, F>(mut f: F) -> impl FnMut((A, B)) -> (A, C)
+where
+    F: FnMut(B) -> C,
+{
+    move |(a, b)| (a, f(b))
+}
+
+/// Transforms the first element in a 2-tuple with a function which can only be called once.
+/// See [map_first](crate::tuple::map_first).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::map_first_once;
+/// let suffix = String::from("s");
+/// assert_eq!(map_first_once(move |s: String| s + &suffix)(("cat".to_owned(), 2)).0, "cats");
+/// ```
+pub fn map_first_once<A, B, C, F>(f: F) -> impl FnOnce((A, B)) -> (C, B)
+where
+    F: FnOnce(A) -> C,
+{
+    move |(a, b)| (f(a), b)
+}
+
+/// Transforms the second element in a 2-tuple with a function which can only be called once.
+/// See [map_second](crate::tuple::map_second).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::map_second_once;
+/// let extra = vec![3];
+/// let extend = map_second_once(move |mut v: Vec<u32>| {
+///     v.extend(extra);
+///     v
+/// });
+/// assert_eq!(extend(("a", vec![1, 2])), ("a", vec![1, 2, 3]));
+/// ```
+pub fn map_second_once<A, B, C, F>(f: F) -> impl FnOnce((A, B)) -> (A, C)
+where
+    F: FnOnce(B) -> C,
+{
+    move |(a, b)| (a, f(b))
+}
+
+/// Transforms the first element of a 2-tuple in place, leaving the second untouched. This
+/// avoids moving the tuple in and out of a function, like [map_first](crate::tuple::map_first)
+/// does.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::map_first_in_place;
+/// let mut entries = vec![(1, "a"), (2, "b")];
+/// entries.iter_mut().for_each(map_first_in_place(|x: &mut u32| *x *= 10));
+/// assert_eq!(entries, vec![(10, "a"), (20, "b")]);
+/// ```
+pub fn map_first_in_place<A, B, F>(f: F) -> impl Fn(&mut (A, B))
+where
+    F: Fn(&mut A),
+{
+    move |(a, _)| f(a)
+}
+
+/// Transforms the second element of a 2-tuple in place, leaving the first untouched. This
+/// avoids moving the tuple in and out of a function, like
+/// [map_second](crate::tuple::map_second) does.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::map_second_in_place;
+/// let mut entries = vec![("a", vec![1]), ("b", vec![])];
+/// entries.iter_mut().for_each(map_second_in_place(|v: &mut Vec<u32>| v.push(0)));
+/// assert_eq!(entries, vec![("a", vec![1, 0]), ("b", vec![0])]);
+/// ```
+pub fn map_second_in_place<A, B, F>(f: F) -> impl Fn(&mut (A, B))
+where
+    F: Fn(&mut B),
+{
+    move |(_, b)| f(b)
+}
+
+/// Transforms both elements of a 2-tuple at once, the first with `f` and the second with `g`.
+/// This is the same as [function::split](crate::function::split).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::bimap;
+/// let entries: Vec<(String, usize)> = vec![("a", "xyz"), ("b", "")]
+///     .into_iter()
+///     .map(bimap(str::to_uppercase, str::len))
+///     .collect();
+/// assert_eq!(entries, vec![("A".to_owned(), 3), ("B".to_owned(), 0)]);
+/// ```
+pub fn bimap<A, B, C, D, F, G>(f: F, g: G) -> impl Fn((A, B)) -> (C, D)
+where
+    F: Fn(A) -> C,
+    G: Fn(B) -> D,
+{
+    move |(a, b)| (f(a), g(b))
+}
+
+/// Pairs up the elements of two 2-tuples: the first elements of both, and the second elements
+/// of both.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::zip;
+/// let (names, ages) = zip(("ann", 31), ("bo", 27));
+/// assert_eq!(names, ("ann", "bo"));
+/// assert_eq!(ages, (31, 27));
+/// ```
+pub fn zip<A, B, C, D>((a, b): (A, B), (c, d): (C, D)) -> ((A, C), (B, D)) {
+    ((a, c), (b, d))
+}
+
+/// Splits a list of pairs into a collection of their first elements and a collection of their
+/// second elements, in order. This is the same as `Iterator::unzip`, for any list.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::unzip;
+/// let (names, ages): (Vec<&str>, Vec<u32>) = unzip(vec![("ann", 31), ("bo", 27)]);
+/// assert_eq!(names, vec!["ann", "bo"]);
+/// assert_eq!(ages, vec![31, 27]);
+/// ```
+pub fn unzip<I, A, B, FromA, FromB>(iter: I) -> (FromA, FromB)
+where
+    I: IntoIterator<Item = (A, B)>,
+    FromA: Default + Extend<A>,
+    FromB: Default + Extend<B>,
+{
+    iter.into_iter().unzip()
+}
+
+/* ELEMENTS *******************************************************************/
+
+/// Tuples which have an element at index `N`, counting from zero. This is implemented for
+/// tuples of up to 12 elements, and allows functions such as [third](crate::tuple::third) to
+/// work with tuples of any size.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::Nth;
+/// let mut tuple = (1, 'a', "b");
+/// assert_eq!(Nth::<1>::nth(&tuple), &'a');
+/// *Nth::<0>::nth_mut(&mut tuple) += 1;
+/// assert_eq!(Nth::<2>::map_nth(tuple, str::len), (2, 'a', 1));
+/// ```
+pub trait Nth<const N: usize> {
+    /// The type of the element at index `N`.
+    type Element;
+
+    /// The type of this tuple once the element at index `N` has been replaced by a `B`.
+    type Replaced<B>;
+
+    /// Returns the element at index `N`, discarding the rest.
+    fn into_nth(self) -> Self::Element;
+
+    /// Borrows the element at index `N`.
+    fn nth(&self) -> &Self::Element;
+
+    /// Mutably borrows the element at index `N`.
+    fn nth_mut(&mut self) -> &mut Self::Element;
+
+    /// Transforms the element at index `N`, keeping the rest.
+    fn map_nth<B, F>(self, f: F) -> Self::Replaced<B>
+    where
+        F: FnOnce(Self::Element) -> B;
+}
+
+macro_rules! nth_impls {
+    ([$($before:ident)*] [] [$($index:tt)*]) => {};
+    ([$($before:ident)*] [$t:ident $($after:ident)*] [$n:tt $($index:tt)*]) => {
+        impl<$($before,)* $t, $($after,)*> Nth<$n> for ($($before,)* $t, $($after,)*) {
+            type Element = $t;
+            type Replaced<Replacement> = ($($before,)* Replacement, $($after,)*);
+
+            fn into_nth(self) -> $t {
+                self.$n
+            }
+
+            fn nth(&self) -> &$t {
+                &self.$n
+            }
+
+            fn nth_mut(&mut self) -> &mut $t {
+                &mut self.$n
+            }
+
+            #[allow(non_snake_case)]
+            fn map_nth<Replacement, Function>(self, f: Function) -> Self::Replaced<Replacement>
+            where
+                Function: FnOnce($t) -> Replacement,
+            {
+                let ($($before,)* $t, $($after,)*) = self;
+                ($($before,)* f($t), $($after,)*)
+            }
+        }
+
+        nth_impls!([$($before)* $t] [$($after)*] [$($index)*]);
+    };
+}
+
+nth_impls!([][A][0]);
+nth_impls!([] [A B] [0 1]);
+nth_impls!([] [A B C] [0 1 2]);
+nth_impls!([] [A B C D] [0 1 2 3]);
+nth_impls!([] [A B C D E] [0 1 2 3 4]);
+nth_impls!([] [A B C D E F] [0 1 2 3 4 5]);
+nth_impls!([] [A B C D E F G] [0 1 2 3 4 5 6]);
+nth_impls!([] [A B C D E F G H] [0 1 2 3 4 5 6 7]);
+nth_impls!([] [A B C D E F G H I] [0 1 2 3 4 5 6 7 8]);
+nth_impls!([] [A B C D E F G H I J] [0 1 2 3 4 5 6 7 8 9]);
+nth_impls!([] [A B C D E F G H I J K] [0 1 2 3 4 5 6 7 8 9 10]);
+nth_impls!([] [A B C D E F G H I J K L] [0 1 2 3 4 5 6 7 8 9 10 11]);
+
+macro_rules! elements {
+    ($n:tt, $ordinal:literal, $get:ident, $map:ident, $example:literal) => {
+        #[doc = concat!("Returns the ", $ordinal, " element of a tuple of any size.")]
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        #[doc = concat!("# use fp_std::tuple::", stringify!($get), ";")]
+        #[doc = concat!("let tuple = ", $example, ";")]
+        #[doc = concat!("assert_eq!(", stringify!($get), "(tuple), tuple.", stringify!($n), ");")]
+        /// ```
+        pub fn $get<T: Nth<$n>>(tuple: T) -> T::Element {
+            tuple.into_nth()
+        }
+
+        #[doc = concat!("Transforms the ", $ordinal, " element of a tuple of any size.")]
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        #[doc = concat!("# use fp_std::tuple::", stringify!($map), ";")]
+        #[doc = concat!("let tuple = ", $example, ";")]
+        #[doc = concat!("let mapped = ", stringify!($map), "(|x: i32| x * 10)(tuple);")]
+        #[doc = concat!(
+                    "assert_eq!(mapped.", stringify!($n), ", tuple.", stringify!($n), " * 10);"
+                )]
+        /// ```
+        pub fn $map<T, B, F>(f: F) -> impl Fn(T) -> T::Replaced<B>
+        where
+            T: Nth<$n>,
+            F: Fn(T::Element) -> B,
+        {
+            move |tuple| tuple.map_nth(&f)
+        }
+    };
+}
+
+elements!(2, "third", third, map_third, "(1, 2, 3)");
+elements!(3, "fourth", fourth, map_fourth, "(1, 2, 3, 4)");
+elements!(4, "fifth", fifth, map_fifth, "(1, 2, 3, 4, 5)");
+elements!(5, "sixth", sixth, map_sixth, "(1, 2, 3, 4, 5, 6)");
+elements!(6, "seventh", seventh, map_seventh, "(1, 2, 3, 4, 5, 6, 7)");
+elements!(7, "eighth", eighth, map_eighth, "(1, 2, 3, 4, 5, 6, 7, 8)");
+elements!(8, "ninth", ninth, map_ninth, "(1, 2, 3, 4, 5, 6, 7, 8, 9)");
+elements!(
+    9,
+    "tenth",
+    tenth,
+    map_tenth,
+    "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10)"
+);
+elements!(
+    10,
+    "eleventh",
+    eleventh,
+    map_eleventh,
+    "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)"
+);
+elements!(
+    11,
+    "twelfth",
+    twelfth,
+    map_twelfth,
+    "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)"
+);
+
+/* TRAVERSALS *****************************************************************/
+
+/// Applies separate optional functions to each element of a 2-tuple, succeeding only if both
+/// of them do.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::traverse_both;
+/// let parse = traverse_both(|s: &str| s.parse::<u8>().ok(), |s: &str| s.chars().next());
+/// assert_eq!(parse(("3", "abc")), Some((3, 'a')));
+/// assert_eq!(parse(("x", "abc")), None);
+/// assert_eq!(parse(("3", "")), None);
+/// ```
+pub fn traverse_both<A, B, C, D, F, G>(f: F, g: G) -> impl Fn((A, B)) -> Option<(C, D)>
+where
+    F: Fn(A) -> Option<C>,
+    G: Fn(B) -> Option<D>,
+{
+    move |(a, b)| Some((f(a)?, g(b)?))
+}
+
+/// Applies separate fallible functions to each element of a 2-tuple, succeeding only if both
+/// of them do. If the first function fails, the second is not called.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::try_traverse_both;
+/// let parse = try_traverse_both(|s: &str| s.parse::<u8>(), |s: &str| s.parse::<i8>());
+/// assert_eq!(parse(("3", "-4")), Ok((3, -4)));
+/// assert!(parse(("x", "y")).is_err());
+/// ```
+pub fn try_traverse_both<A, B, C, D, E, F, G>(f: F, g: G) -> impl Fn((A, B)) -> Result<(C, D), E>
+where
+    F: Fn(A) -> Result<C, E>,
+    G: Fn(B) -> Result<D, E>,
+{
+    move |(a, b)| Ok((f(a)?, g(b)?))
+}
+
+/// Applies separate fallible functions to each element of a 2-tuple. Both functions are always
+/// called; if either of them fails, all of the errors are collected instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::traverse_both_accumulate;
+/// let parse = traverse_both_accumulate(
+///     |s: &str| s.parse::<u8>().map_err(|_| "bad width"),
+///     |s: &str| s.parse::<u8>().map_err(|_| "bad height"),
+/// );
+/// assert_eq!(parse(("3", "4")), Ok((3, 4)));
+/// assert_eq!(parse(("x", "y")), Err(vec!["bad width", "bad height"]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn traverse_both_accumulate<A, B, C, D, E, F, G>(
+    f: F,
+    g: G,
+) -> impl Fn((A, B)) -> Result<(C, D), alloc::vec::Vec<E>>
+where
+    F: Fn(A) -> Result<C, E>,
+    G: Fn(B) -> Result<D, E>,
+{
+    move |(a, b)| match (f(a), g(b)) {
+        (Ok(c), Ok(d)) => Ok((c, d)),
+        (Err(e), Ok(_)) | (Ok(_), Err(e)) => Err(alloc::vec![e]),
+        (Err(e1), Err(e2)) => Err(alloc::vec![e1, e2]),
+    }
+}
+
+use crate::traversal::{Traversal, TraversalExt};
+
+/// A [Traversal](crate::traversal::Traversal) of both elements of a 2-tuple whose elements have
+/// the same type, first then second. Constructed by [lens_both](crate::tuple::lens_both).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BothElements;
+
+impl<A> Traversal<(A, A), A> for BothElements {
+    fn for_each<'a, F>(&self, s: &'a (A, A), mut f: F)
+    where
+        F: FnMut(&'a A),
+        A: 'a,
+    {
+        f(&s.0);
+        f(&s.1);
+    }
+
+    fn over<F>(&self, (a, b): (A, A), mut f: F) -> (A, A)
+    where
+        F: FnMut(A) -> A,
+    {
+        let a = f(a);
+        (a, f(b))
+    }
+}
+
+impl TraversalExt for BothElements {}
+
+/// Creates a traversal of both elements of a 2-tuple whose elements have the same type, so that
+/// one function can update both of them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::traversal::{each, Traversal, TraversalExt};
+/// # use fp_std::tuple::lens_both;
+/// assert_eq!(lens_both().over((1, 2), |x| x * 10), (10, 20));
+/// assert_eq!(lens_both().to_vec(&("a", "b")), vec![&"a", &"b"]);
+///
+/// let points = vec![(1, 2), (3, 4)];
+/// assert_eq!(each().then(lens_both()).over(points, |x| -x), vec![(-1, -2), (-3, -4)]);
+/// ```
+pub fn lens_both() -> BothElements {
+    BothElements
+}
+
 /* LENSES *********************************************************************/
+#[cfg(feature = "std")]
+use crate::lens::LensMut;
+#[cfg(feature = "std")]
 use fp_core::lens::Lens;
 
 /// A [Lens](fp_core::lens::Lens) for the first element in a 2-tuple.
@@ -112,8 +1030,10 @@ use fp_core::lens::Lens;
 /// assert_eq!(LensFirst::get(&(1, 2)), Some(&1));
 /// assert_eq!(LensFirst::set(3, &(1, 2)), (3, 2));
 /// ```
+#[cfg(feature = "std")]
 pub struct LensFirst;
 
+#[cfg(feature = "std")]
 impl<A, B: Clone> Lens<(A, B), A> for LensFirst {
     fn get((a, ..): &(A, B)) -> Option<&A> {
         Some(a)
@@ -124,6 +1044,17 @@ impl<A, B: Clone> Lens<(A, B), A> for LensFirst {
     }
 }
 
+#[cfg(feature = "std")]
+impl<A, B> LensMut<(A, B), A> for LensFirst {
+    fn get_mut((a, ..): &mut (A, B)) -> Option<&mut A> {
+        Some(a)
+    }
+
+    fn set_mut(s: &mut (A, B), a: A) {
+        s.0 = a;
+    }
+}
+
 /// A [Lens](fp_core::lens::Lens) for the second element in a 2-tuple.
 ///
 /// # Examples
@@ -134,8 +1065,10 @@ impl<A, B: Clone> Lens<(A, B), A> for LensFirst {
 /// assert_eq!(LensSecond::get(&(1, 2)), Some(&2));
 /// assert_eq!(LensSecond::set(3, &(1, 2)), (1, 3));
 /// ```
+#[cfg(feature = "std")]
 pub struct LensSecond;
 
+#[cfg(feature = "std")]
 impl<A: Clone, B> Lens<(A, B), B> for LensSecond {
     fn get((.., b): &(A, B)) -> Option<&B> {
         Some(b)
@@ -145,3 +1078,122 @@ impl<A: Clone, B> Lens<(A, B), B> for LensSecond {
         (a.clone(), b)
     }
 }
+
+#[cfg(feature = "std")]
+impl<A, B> LensMut<(A, B), B> for LensSecond {
+    fn get_mut((.., b): &mut (A, B)) -> Option<&mut B> {
+        Some(b)
+    }
+
+    fn set_mut(s: &mut (A, B), b: B) {
+        s.1 = b;
+    }
+}
+
+macro_rules! nth_lens {
+    ($n:tt, $ordinal:literal, $lens:ident, $example:literal) => {
+        #[doc = concat!("A [Lens](fp_core::lens::Lens) for the ", $ordinal, " element of a tuple")]
+        /// of any size. Setting the element clones the whole tuple.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        #[doc = concat!("# use fp_std::tuple::", stringify!($lens), ";")]
+        /// use fp_core::lens::Lens;
+        #[doc = concat!("let tuple = ", $example, ";")]
+        #[doc = concat!(
+            "assert_eq!(", stringify!($lens), "::get(&tuple), Some(&tuple.", stringify!($n), "));"
+        )]
+        #[doc = concat!(
+            "assert_eq!(", stringify!($lens), "::set(0, &tuple).", stringify!($n), ", 0);"
+        )]
+        /// ```
+        #[cfg(feature = "std")]
+        pub struct $lens;
+
+        #[cfg(feature = "std")]
+        impl<T: Nth<$n> + Clone> Lens<T, T::Element> for $lens {
+            fn get(tuple: &T) -> Option<&T::Element> {
+                Some(tuple.nth())
+            }
+
+            fn set(element: T::Element, tuple: &T) -> T {
+                let mut tuple = tuple.clone();
+                *tuple.nth_mut() = element;
+                tuple
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<T: Nth<$n>> LensMut<T, T::Element> for $lens {
+            fn get_mut(tuple: &mut T) -> Option<&mut T::Element> {
+                Some(tuple.nth_mut())
+            }
+
+            fn set_mut(tuple: &mut T, element: T::Element) {
+                *tuple.nth_mut() = element;
+            }
+        }
+    };
+}
+
+nth_lens!(2, "third", LensThird, "(1, 2, 3)");
+nth_lens!(3, "fourth", LensFourth, "(1, 2, 3, 4)");
+nth_lens!(4, "fifth", LensFifth, "(1, 2, 3, 4, 5)");
+nth_lens!(5, "sixth", LensSixth, "(1, 2, 3, 4, 5, 6)");
+nth_lens!(6, "seventh", LensSeventh, "(1, 2, 3, 4, 5, 6, 7)");
+nth_lens!(7, "eighth", LensEighth, "(1, 2, 3, 4, 5, 6, 7, 8)");
+nth_lens!(8, "ninth", LensNinth, "(1, 2, 3, 4, 5, 6, 7, 8, 9)");
+nth_lens!(9, "tenth", LensTenth, "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10)");
+nth_lens!(
+    10,
+    "eleventh",
+    LensEleventh,
+    "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)"
+);
+nth_lens!(
+    11,
+    "twelfth",
+    LensTwelfth,
+    "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)"
+);
+
+/// A [Lens](fp_core::lens::Lens) for the element at index `N` of a tuple of any size, counting
+/// from zero. Setting the element clones the whole tuple.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::tuple::LensNth;
+/// use fp_core::lens::Lens;
+/// let tuple = (1, 'a', "b");
+/// assert_eq!(LensNth::<1>::get(&tuple), Some(&'a'));
+/// assert_eq!(LensNth::<0>::set(2, &tuple), (2, 'a', "b"));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LensNth<const N: usize>;
+
+#[cfg(feature = "std")]
+impl<T: Nth<N> + Clone, const N: usize> Lens<T, T::Element> for LensNth<N> {
+    fn get(tuple: &T) -> Option<&T::Element> {
+        Some(tuple.nth())
+    }
+
+    fn set(element: T::Element, tuple: &T) -> T {
+        let mut tuple = tuple.clone();
+        *tuple.nth_mut() = element;
+        tuple
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Nth<N>, const N: usize> LensMut<T, T::Element> for LensNth<N> {
+    fn get_mut(tuple: &mut T) -> Option<&mut T::Element> {
+        Some(tuple.nth_mut())
+    }
+
+    fn set_mut(tuple: &mut T, element: T::Element) {
+        *tuple.nth_mut() = element;
+    }
+}
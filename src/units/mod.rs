@@ -0,0 +1,296 @@
+//! Quantities tagged with their units of measure, so that values measured in different units
+//! cannot be mixed up.
+//!
+//! ```rust
+//! # use fp_std::{tag, unit};
+//! # use fp_std::units::{convert, Quantity};
+//! tag!(pub Length);
+//! unit!(pub Metres: Length = 1.0, pub Feet: Length = 0.3048);
+//!
+//! let run: Quantity<f64, Metres> = Quantity::new(400.0);
+//! let sprint: Quantity<f64, Metres> = Quantity::new(100.0);
+//! let total = run + sprint;
+//! assert_eq!(total, Quantity::new(500.0));
+//!
+//! let in_feet: Quantity<f64, Feet> = convert::<Metres, Feet>()(Quantity::new(0.3048));
+//! assert!((in_feet.into_inner() - 1.0).abs() < 1e-9);
+//! // run + in_feet; // mismatched types
+//! ```
+
+/* UNITS **********************************************************************/
+
+/// A unit of measure for some dimension, such as length or time.
+///
+/// Units are usually declared with the [unit!](crate::unit) macro.
+pub trait Unit {
+    /// The dimension that this unit measures. Only quantities of the same dimension can be
+    /// converted between units.
+    type Dimension;
+
+    /// The size of this unit, as a multiple of some base unit shared by every unit of the same
+    /// dimension.
+    const SCALE: f64;
+}
+
+/// Declares uninhabited types to be used as [units](crate::units::Unit) for
+/// [Quantity](crate::units::Quantity) values, each with its dimension and its scale relative
+/// to the base unit of that dimension.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::{tag, unit};
+/// # use fp_std::units::Unit;
+/// tag!(pub Time);
+/// unit!(
+///     /// The base unit of time.
+///     pub Seconds: Time = 1.0,
+///     pub Minutes: Time = 60.0,
+/// );
+/// assert_eq!(Minutes::SCALE, 60.0);
+/// ```
+#[macro_export]
+macro_rules! unit {
+    ($($(#[$meta:meta])* $vis:vis $name:ident : $dimension:ty = $scale:expr),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $vis enum $name {}
+
+            impl $crate::units::Unit for $name {
+                type Dimension = $dimension;
+                const SCALE: f64 = $scale;
+            }
+        )+
+    };
+}
+
+/* QUANTITIES *****************************************************************/
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// A value of type `T` measured in the unit `U`.
+///
+/// As with [Tagged](crate::tagged::Tagged), the unit exists only at the type level, so no trait
+/// implementation places any requirements on it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::{tag, unit};
+/// # use fp_std::units::Quantity;
+/// tag!(Mass);
+/// unit!(Grams: Mass = 1.0);
+/// let flour: Quantity<u32, Grams> = Quantity::new(250);
+/// assert_eq!(flour * 2, Quantity::new(500));
+/// assert_eq!(flour.into_inner(), 250);
+/// ```
+pub struct Quantity<T, U> {
+    value: T,
+    unit: PhantomData<fn() -> U>,
+}
+
+impl<T, U> Quantity<T, U> {
+    /// Creates a quantity of the given value.
+    pub const fn new(value: T) -> Self {
+        Quantity {
+            value,
+            unit: PhantomData,
+        }
+    }
+
+    /// Returns the value of this quantity, without its unit.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Borrows the value of this quantity, without its unit.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Transforms the value of this quantity, keeping the same unit.
+    pub fn map<V, F>(self, f: F) -> Quantity<V, U>
+    where
+        F: FnOnce(T) -> V,
+    {
+        Quantity::new(f(self.value))
+    }
+}
+
+impl<U: Unit> Quantity<f64, U> {
+    /// Converts this quantity into another unit of the same dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::{tag, unit};
+    /// # use fp_std::units::Quantity;
+    /// tag!(Time);
+    /// unit!(Seconds: Time = 1.0, Minutes: Time = 60.0);
+    /// let wait: Quantity<f64, Minutes> = Quantity::new(1.5);
+    /// assert_eq!(wait.convert::<Seconds>(), Quantity::new(90.0));
+    /// ```
+    pub fn convert<V>(self) -> Quantity<f64, V>
+    where
+        V: Unit<Dimension = U::Dimension>,
+    {
+        Quantity::new(self.value * U::SCALE / V::SCALE)
+    }
+}
+
+impl<T: Clone, U> Clone for Quantity<T, U> {
+    fn clone(&self) -> Self {
+        Quantity::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, U> Copy for Quantity<T, U> {}
+
+impl<T: Default, U> Default for Quantity<T, U> {
+    fn default() -> Self {
+        Quantity::new(T::default())
+    }
+}
+
+impl<T: Debug, U> Debug for Quantity<T, U> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: Display, U> Display for Quantity<T, U> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for Quantity<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, U> Eq for Quantity<T, U> {}
+
+impl<T: PartialOrd, U> PartialOrd for Quantity<T, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, U> Ord for Quantity<T, U> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, U> Hash for Quantity<T, U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+/* ARITHMETIC *****************************************************************/
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+impl<T: Add<Output = T>, U> Add for Quantity<T, U> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Quantity::new(self.value + other.value)
+    }
+}
+
+impl<T: Sub<Output = T>, U> Sub for Quantity<T, U> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Quantity::new(self.value - other.value)
+    }
+}
+
+impl<T: AddAssign, U> AddAssign for Quantity<T, U> {
+    fn add_assign(&mut self, other: Self) {
+        self.value += other.value;
+    }
+}
+
+impl<T: SubAssign, U> SubAssign for Quantity<T, U> {
+    fn sub_assign(&mut self, other: Self) {
+        self.value -= other.value;
+    }
+}
+
+impl<T: Neg<Output = T>, U> Neg for Quantity<T, U> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quantity::new(-self.value)
+    }
+}
+
+/// Scales a quantity by a unitless factor.
+impl<T: Mul<Output = T>, U> Mul<T> for Quantity<T, U> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Quantity::new(self.value * factor)
+    }
+}
+
+/// Divides a quantity by a unitless divisor.
+impl<T: Div<Output = T>, U> Div<T> for Quantity<T, U> {
+    type Output = Self;
+
+    fn div(self, divisor: T) -> Self {
+        Quantity::new(self.value / divisor)
+    }
+}
+
+/// Divides two quantities of the same unit, producing their unitless ratio.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::{tag, unit};
+/// # use fp_std::units::Quantity;
+/// tag!(Length);
+/// unit!(Metres: Length = 1.0);
+/// let ratio: f64 = Quantity::<f64, Metres>::new(3.0) / Quantity::new(2.0);
+/// assert_eq!(ratio, 1.5);
+/// ```
+impl<T: Div<Output = T>, U> Div for Quantity<T, U> {
+    type Output = T;
+
+    fn div(self, other: Self) -> T {
+        self.value / other.value
+    }
+}
+
+/* CONVERSIONS ****************************************************************/
+
+/// Creates a function which converts quantities from the unit `From` into the unit `To`, both
+/// of which must measure the same dimension.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::{tag, unit};
+/// # use fp_std::units::{convert, Quantity};
+/// tag!(Length);
+/// unit!(Metres: Length = 1.0, Kilometres: Length = 1000.0);
+/// let to_km = convert::<Metres, Kilometres>();
+/// let legs: Vec<Quantity<f64, Metres>> = vec![Quantity::new(1500.0), Quantity::new(250.0)];
+/// let legs: Vec<Quantity<f64, Kilometres>> = legs.into_iter().map(to_km).collect();
+/// assert_eq!(legs, vec![Quantity::new(1.5), Quantity::new(0.25)]);
+/// ```
+pub fn convert<From, To>() -> impl Fn(Quantity<f64, From>) -> Quantity<f64, To>
+where
+    From: Unit,
+    To: Unit<Dimension = From::Dimension>,
+{
+    Quantity::convert
+}
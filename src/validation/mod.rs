@@ -0,0 +1,553 @@
+//! Validations: results which, when combined, accumulate all of their errors instead of
+//! stopping at the first one.
+//!
+//! ```rust
+//! # use fp_std::validation::{self, Validation};
+//! struct Signup {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! fn name(s: &str) -> Validation<Vec<String>, String> {
+//!     if s.is_empty() {
+//!         Validation::invalid("name is required".to_owned())
+//!     } else {
+//!         Validation::Valid(s.to_owned())
+//!     }
+//! }
+//!
+//! fn age(s: &str) -> Validation<Vec<String>, u32> {
+//!     Validation::from_result(s.parse().map_err(|_| vec![format!("{:?} is not an age", s)]))
+//! }
+//!
+//! let signup = validation::lift2(|name, age| Signup { name, age });
+//! assert!(signup(name("ann"), age("31")).is_valid());
+//! assert_eq!(
+//!     signup(name(""), age("old")).into_result().err(),
+//!     Some(vec!["name is required".to_owned(), "\"old\" is not an age".to_owned()]),
+//! );
+//! ```
+
+/* VALIDATIONS ****************************************************************/
+use fp_core::semigroup::Semigroup;
+
+/// Either a valid value, or the errors which made it invalid.
+///
+/// Unlike `Result`, combining two invalid validations with
+/// [zip](crate::validation::Validation::zip) or [ap](crate::validation::Validation::ap) keeps
+/// the errors of both, combining them as a [Semigroup](fp_core::semigroup::Semigroup), which is
+/// usually a `Vec` of errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validation<E, T> {
+    /// A valid value.
+    Valid(T),
+    /// The errors which made the value invalid.
+    Invalid(E),
+}
+
+impl<E, T> Validation<E, T> {
+    /// Creates an invalid validation with a single error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let missing: Validation<Vec<&str>, u32> = Validation::invalid("missing");
+    /// assert_eq!(missing, Validation::Invalid(vec!["missing"]));
+    /// ```
+    pub fn invalid<D>(error: D) -> Self
+    where
+        E: From<Vec<D>>,
+    {
+        Validation::Invalid(E::from(vec![error]))
+    }
+
+    /// Converts a `Result` into a validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let parse = |s: &str| Validation::from_result(s.parse::<u32>().map_err(|e| vec![e]));
+    /// assert_eq!(parse("12"), Validation::Valid(12));
+    /// assert!(parse("twelve").is_invalid());
+    /// ```
+    pub fn from_result(result: Result<T, E>) -> Self {
+        match result {
+            Ok(t) => Validation::Valid(t),
+            Err(e) => Validation::Invalid(e),
+        }
+    }
+
+    /// Converts this validation into a `Result`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let checked: Validation<Vec<&str>, u32> = Validation::invalid("too small");
+    /// assert_eq!(checked.into_result(), Err(vec!["too small"]));
+    /// assert_eq!(Validation::<Vec<&str>, _>::Valid(3).into_result(), Ok(3));
+    /// ```
+    pub fn into_result(self) -> Result<T, E> {
+        match self {
+            Validation::Valid(t) => Ok(t),
+            Validation::Invalid(e) => Err(e),
+        }
+    }
+
+    /// Checks whether the value is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// assert!(Validation::<Vec<&str>, _>::Valid(3).is_valid());
+    /// assert!(!Validation::<Vec<&str>, u32>::invalid("too small").is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Validation::Valid(..))
+    }
+
+    /// Checks whether the value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// assert!(Validation::<Vec<&str>, u32>::invalid("too small").is_invalid());
+    /// assert!(!Validation::<Vec<&str>, _>::Valid(3).is_invalid());
+    /// ```
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Validation::Invalid(..))
+    }
+
+    /// Transforms the valid value, leaving the errors of an invalid one unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let checked: Validation<Vec<&str>, u32> = Validation::Valid(3);
+    /// assert_eq!(checked.map(|n| n * 2), Validation::Valid(6));
+    /// let checked: Validation<Vec<&str>, u32> = Validation::invalid("too small");
+    /// assert_eq!(checked.map(|n| n * 2), Validation::Invalid(vec!["too small"]));
+    /// ```
+    pub fn map<U, F>(self, f: F) -> Validation<E, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Validation::Valid(t) => Validation::Valid(f(t)),
+            Validation::Invalid(e) => Validation::Invalid(e),
+        }
+    }
+
+    /// Transforms the errors of an invalid value, leaving a valid one unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let checked: Validation<Vec<&str>, u32> = Validation::invalid("too small");
+    /// assert_eq!(checked.map_err(|errors| errors.len()), Validation::Invalid(1));
+    /// ```
+    pub fn map_err<D, F>(self, f: F) -> Validation<D, T>
+    where
+        F: FnOnce(E) -> D,
+    {
+        match self {
+            Validation::Valid(t) => Validation::Valid(t),
+            Validation::Invalid(e) => Validation::Invalid(f(e)),
+        }
+    }
+
+    /// Combines two validations into a validation of both of their values, which is invalid
+    /// with the errors of each of them which is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let a: Validation<Vec<&str>, u32> = Validation::invalid("a");
+    /// let b: Validation<Vec<&str>, u32> = Validation::invalid("b");
+    /// assert_eq!(a.zip(b), Validation::Invalid(vec!["a", "b"]));
+    /// assert_eq!(
+    ///     Validation::<Vec<&str>, _>::Valid(1).zip(Validation::Valid(2)),
+    ///     Validation::Valid((1, 2)),
+    /// );
+    /// ```
+    pub fn zip<U>(self, other: Validation<E, U>) -> Validation<E, (T, U)>
+    where
+        E: Semigroup,
+    {
+        match (self, other) {
+            (Validation::Valid(t), Validation::Valid(u)) => Validation::Valid((t, u)),
+            (Validation::Valid(..), Validation::Invalid(e)) => Validation::Invalid(e),
+            (Validation::Invalid(e), Validation::Valid(..)) => Validation::Invalid(e),
+            (Validation::Invalid(d), Validation::Invalid(e)) => Validation::Invalid(d.combine(e)),
+        }
+    }
+
+    /// Applies a validated function to this validated value. If either is invalid, so is the
+    /// result, with the errors of the function before those of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let double = Validation::<Vec<&str>, _>::Valid(|n: u32| n * 2);
+    /// assert_eq!(Validation::Valid(4).ap(double), Validation::Valid(8));
+    /// ```
+    pub fn ap<U, F>(self, f: Validation<E, F>) -> Validation<E, U>
+    where
+        E: Semigroup,
+        F: FnOnce(T) -> U,
+    {
+        f.zip(self).map(|(f, t)| f(t))
+    }
+
+    /// Transforms the valid value into another validation, leaving the errors of an invalid
+    /// one unchanged. Since the second validation depends on the first value, this cannot
+    /// accumulate errors, and stops at the first invalid value like `Result::and_then`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Validation;
+    /// let even = |n: u32| {
+    ///     if n % 2 == 0 {
+    ///         Validation::Valid(n)
+    ///     } else {
+    ///         Validation::invalid("odd")
+    ///     }
+    /// };
+    /// let checked: Validation<Vec<&str>, u32> = Validation::Valid(4);
+    /// assert_eq!(checked.and_then(even), Validation::Valid(4));
+    /// assert_eq!(Validation::Valid(3).and_then(even), Validation::Invalid(vec!["odd"]));
+    /// ```
+    pub fn and_then<U, F>(self, f: F) -> Validation<E, U>
+    where
+        F: FnOnce(T) -> Validation<E, U>,
+    {
+        match self {
+            Validation::Valid(t) => f(t),
+            Validation::Invalid(e) => Validation::Invalid(e),
+        }
+    }
+}
+
+impl<E, T> From<Result<T, E>> for Validation<E, T> {
+    fn from(result: Result<T, E>) -> Self {
+        Validation::from_result(result)
+    }
+}
+
+impl<E, T> From<Validation<E, T>> for Result<T, E> {
+    fn from(validation: Validation<E, T>) -> Self {
+        validation.into_result()
+    }
+}
+
+/* COMBINATORS ****************************************************************/
+
+/// Lifts a function of two values into a function of two validations, which applies it if
+/// both of them are valid, or accumulates the errors of those which are not.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::validation::{self, Validation};
+/// let add = validation::lift2(|a: u32, b: u32| a + b);
+/// let one: Validation<Vec<&str>, u32> = Validation::Valid(1);
+/// assert_eq!(add(one.clone(), Validation::Valid(2)), Validation::Valid(3));
+/// assert_eq!(add(one, Validation::invalid("bad")), Validation::Invalid(vec!["bad"]));
+/// ```
+pub fn lift2<E, A, B, C, F>(f: F) -> impl Fn(Validation<E, A>, Validation<E, B>) -> Validation<E, C>
+where
+    E: Semigroup,
+    F: Fn(A, B) -> C,
+{
+    move |a, b| a.zip(b).map(|(a, b)| f(a, b))
+}
+
+/// Lifts a function of three values into a function of three validations, which applies it if
+/// all of them are valid, or accumulates the errors of those which are not.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::validation::{self, Validation};
+/// let hex = validation::lift3(|r: u8, g: u8, b: u8| format!("#{:02x}{:02x}{:02x}", r, g, b));
+/// let channel = |s: &str| Validation::from_result(s.parse().map_err(|_| vec![s.to_owned()]));
+/// assert_eq!(
+///     hex(channel("255"), channel("0"), channel("16")),
+///     Validation::Valid("#ff0010".to_owned()),
+/// );
+/// assert_eq!(
+///     hex(channel("256"), channel("0"), channel("-1")),
+///     Validation::Invalid(vec!["256".to_owned(), "-1".to_owned()]),
+/// );
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn lift3<E, A, B, C, D, F>(
+    f: F,
+) -> impl Fn(Validation<E, A>, Validation<E, B>, Validation<E, C>) -> Validation<E, D>
+where
+    E: Semigroup,
+    F: Fn(A, B, C) -> D,
+{
+    move |a, b, c| a.zip(b).zip(c).map(|((a, b), c)| f(a, b, c))
+}
+
+/// Collects the values of some validations into a `Vec`, if every one of them is valid.
+/// Otherwise, the errors of every invalid one are accumulated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::validation::{sequence, Validation};
+/// fn port(s: &str) -> Validation<Vec<&str>, u16> {
+///     Validation::from_result(s.parse().map_err(|_| vec![s]))
+/// }
+/// assert_eq!(
+///     sequence(vec!["80", "443"].into_iter().map(port)),
+///     Validation::Valid(vec![80, 443]),
+/// );
+/// assert_eq!(
+///     sequence(vec!["80", "x", "y"].into_iter().map(port)),
+///     Validation::Invalid(vec!["x", "y"]),
+/// );
+/// ```
+pub fn sequence<I, E, T>(iter: I) -> Validation<E, Vec<T>>
+where
+    I: IntoIterator<Item = Validation<E, T>>,
+    E: Semigroup,
+{
+    iter.into_iter()
+        .fold(Validation::Valid(Vec::new()), |acc, validation| {
+            acc.zip(validation).map(|(mut values, t)| {
+                values.push(t);
+                values
+            })
+        })
+}
+
+/* RULES **********************************************************************/
+use crate::nonempty::NonEmpty;
+use crate::predicate::Predicate;
+use fp_core::lens::Lens;
+use std::fmt::{self, Debug, Formatter};
+use std::rc::Rc;
+
+type Check<T, E> = dyn Fn(&T) -> Option<E>;
+
+/// A set of named rules which a value of type `T` must follow, each of which produces an
+/// error `E` when the value breaks it.
+///
+/// Validating a value checks it against every rule, so that all of the errors are reported
+/// at once. Rules for the fields of a type can be written once, and reused in the rules of
+/// each type which contains it with [field](crate::validation::Rules::field).
+///
+/// # Examples
+///
+/// ```rust
+/// # use fp_std::nonempty::NonEmpty;
+/// # use fp_std::tuple::LensFirst;
+/// # use fp_std::validation::{Rules, Validation};
+/// let name = Rules::new()
+///     .rule("required", |s: &String| !s.is_empty(), "name is required")
+///     .rule("short", |s: &String| s.len() <= 8, "name is too long");
+/// let signup = Rules::new()
+///     .field(LensFirst, name)
+///     .rule("adult", |&(_, age): &(String, u32)| age >= 18, "must be an adult");
+///
+/// let ann = ("ann".to_owned(), 31);
+/// assert_eq!(signup.validate(ann.clone()), Validation::Valid(ann));
+/// assert_eq!(
+///     signup.validate((String::new(), 12)),
+///     Validation::Invalid(NonEmpty::new("name is required", vec!["must be an adult"])),
+/// );
+/// assert_eq!(signup.names().collect::<Vec<_>>(), vec!["required", "short", "adult"]);
+/// ```
+pub struct Rules<T, E> {
+    rules: Vec<(&'static str, Box<Check<T, E>>)>,
+}
+
+impl<T: 'static, E: 'static> Rules<T, E> {
+    /// Creates a set of no rules, which every value follows.
+    pub fn new() -> Self {
+        Rules { rules: Vec::new() }
+    }
+
+    /// Adds a rule that values must satisfy a predicate, which produces a copy of `error` when
+    /// they do not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Rules;
+    /// let percent = Rules::new().rule("at most 100", |n: &u32| *n <= 100, "too large");
+    /// assert_eq!(percent.errors(&150), vec!["too large"]);
+    /// assert!(percent.errors(&50).is_empty());
+    /// ```
+    pub fn rule<P>(self, name: &'static str, pred: P, error: E) -> Self
+    where
+        P: Into<Predicate<T>>,
+        E: Clone,
+    {
+        self.rule_with(name, pred, move |_| error.clone())
+    }
+
+    /// Adds a rule that values must satisfy a predicate, which produces an error from the
+    /// value when it does not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Rules;
+    /// let port = Rules::new().rule_with(
+    ///     "privileged",
+    ///     |port: &u16| *port >= 1024,
+    ///     |port| format!("port {} is privileged", port),
+    /// );
+    /// assert_eq!(port.errors(&80), vec!["port 80 is privileged"]);
+    /// assert!(port.errors(&8080).is_empty());
+    /// ```
+    pub fn rule_with<P, F>(mut self, name: &'static str, pred: P, error_fn: F) -> Self
+    where
+        P: Into<Predicate<T>>,
+        F: Fn(&T) -> E + 'static,
+    {
+        let pred = pred.into();
+        self.rules.push((
+            name,
+            Box::new(move |t| {
+                if pred.test(t) {
+                    None
+                } else {
+                    Some(error_fn(t))
+                }
+            }),
+        ));
+        self
+    }
+
+    /// Adds the rules for the part of a value that a lens focuses on. If the lens focuses on
+    /// nothing, those rules do not apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::lens::LensIndex;
+    /// # use fp_std::validation::Rules;
+    /// let positive = Rules::new().rule("positive", |n: &i32| *n > 0, "not positive");
+    /// let head = Rules::new().field(LensIndex::<0>, positive);
+    /// assert_eq!(head.errors(&vec![-1, 2]), vec!["not positive"]);
+    /// assert!(head.errors(&vec![]).is_empty());
+    /// ```
+    pub fn field<L, A>(mut self, _lens: L, rules: Rules<A, E>) -> Self
+    where
+        L: Lens<T, A> + 'static,
+        A: 'static,
+    {
+        for (name, check) in rules.rules {
+            self.rules
+                .push((name, Box::new(move |t| L::get(t).and_then(&check))));
+        }
+        self
+    }
+
+    /// Combines two sets of rules into one which contains the rules of both, those of this one
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Rules;
+    /// let small = Rules::new().rule("small", |n: &u32| *n < 10, "too large");
+    /// let even = Rules::new().rule("even", |n: &u32| n % 2 == 0, "odd");
+    /// let small_even = small.and(even);
+    /// assert_eq!(small_even.names().collect::<Vec<_>>(), vec!["small", "even"]);
+    /// assert_eq!(small_even.errors(&11), vec!["too large", "odd"]);
+    /// ```
+    pub fn and(mut self, other: Self) -> Self {
+        self.rules.extend(other.rules);
+        self
+    }
+
+    /// Adapts these rules to values of another type, by first converting them with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::validation::Rules;
+    /// let short = Rules::new().rule("short", |len: &usize| *len <= 3, "too long");
+    /// let short_word = short.contramap(|word: &&str| word.len());
+    /// assert_eq!(short_word.errors(&"word"), vec!["too long"]);
+    /// ```
+    pub fn contramap<U, F>(self, f: F) -> Rules<U, E>
+    where
+        U: 'static,
+        F: Fn(&U) -> T + 'static,
+    {
+        let f = Rc::new(f);
+        Rules {
+            rules: self
+                .rules
+                .into_iter()
+                .map(|(name, check)| {
+                    let f = Rc::clone(&f);
+                    let check: Box<Check<U, E>> = Box::new(move |u| check(&f(u)));
+                    (name, check)
+                })
+                .collect(),
+        }
+    }
+
+    /// The names of the rules, in the order that they are checked.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.rules.iter().map(|(name, _)| *name)
+    }
+
+    /// Checks a value against every rule, returning the errors of those it breaks, in order.
+    pub fn errors(&self, value: &T) -> Vec<E> {
+        self.rules
+            .iter()
+            .filter_map(|(_, check)| check(value))
+            .collect()
+    }
+
+    /// Checks a value against every rule, producing a validation which is valid if the value
+    /// follows all of them, or invalid with the errors of those it breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::nonempty::NonEmpty;
+    /// # use fp_std::validation::{Rules, Validation};
+    /// let small = Rules::new().rule("small", |n: &u32| *n < 10, "too large");
+    /// assert_eq!(small.validate(3), Validation::Valid(3));
+    /// assert_eq!(small.validate(30), Validation::Invalid(NonEmpty::new("too large", vec![])));
+    /// ```
+    pub fn validate(&self, value: T) -> Validation<NonEmpty<E>, T> {
+        match NonEmpty::from_vec(self.errors(&value)) {
+            Ok(errors) => Validation::Invalid(errors),
+            Err(_) => Validation::Valid(value),
+        }
+    }
+}
+
+impl<T: 'static, E: 'static> Default for Rules<T, E> {
+    fn default() -> Self {
+        Rules::new()
+    }
+}
+
+impl<T, E> Debug for Rules<T, E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Rules")
+            .field(&self.rules.iter().map(|(name, _)| *name).collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -0,0 +1,109 @@
+//! Computations which produce a log alongside their value, so that the steps of a pipeline
+//! can record what they did without any side effects.
+//!
+//! The log may be any [Monoid](fp_core::monoid::Monoid), from a
+//! [Concat](crate::monoid::Concat) of messages to a [Sum](crate::monoid::Sum) of costs.
+//!
+//! ```rust
+//! # use fp_std::monoid::Concat;
+//! # use fp_std::writer::Writer;
+//! fn parse(s: &str) -> Writer<Concat<Vec<String>>, u32> {
+//!     let n = s.parse().unwrap_or(0);
+//!     Writer::tell(Concat(vec![format!("parsed {:?} as {}", s, n)])).map(move |_| n)
+//! }
+//!
+//! let (total, Concat(log)) = parse("2")
+//!     .and_then(|a| parse("x").map(move |b| a + b))
+//!     .run_writer();
+//! assert_eq!(total, 2);
+//! assert_eq!(log, vec!["parsed \"2\" as 2", "parsed \"x\" as 0"]);
+//! ```
+
+/* WRITERS ********************************************************************/
+use fp_core::monoid::Monoid;
+use fp_core::semigroup::Semigroup;
+
+/// A value of type `A`, along with the log `W` that was written while producing it.
+///
+/// Combining writers with [and_then](crate::writer::Writer::and_then) combines their logs, in
+/// order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Writer<W, A> {
+    /// The value produced.
+    pub value: A,
+    /// The log written while producing the value.
+    pub log: W,
+}
+
+impl<W, A> Writer<W, A> {
+    /// Creates a writer from a value and the log written while producing it.
+    pub const fn new(value: A, log: W) -> Self {
+        Writer { value, log }
+    }
+
+    /// Creates a writer of a value, with nothing written to the log.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::monoid::Sum;
+    /// # use fp_std::writer::Writer;
+    /// assert_eq!(Writer::<Sum<u32>, _>::pure('a').run_writer(), ('a', Sum(0)));
+    /// ```
+    pub fn pure(value: A) -> Self
+    where
+        W: Monoid,
+    {
+        Writer::new(value, W::empty())
+    }
+
+    /// Transforms the value, leaving the log unchanged.
+    pub fn map<B, F>(self, f: F) -> Writer<W, B>
+    where
+        F: FnOnce(A) -> B,
+    {
+        Writer::new(f(self.value), self.log)
+    }
+
+    /// Transforms the value into another writer, whose log is written after this one's.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::monoid::Sum;
+    /// # use fp_std::writer::Writer;
+    /// let cost = |n: u32| Writer::new(n * 2, Sum(n));
+    /// let doubled_twice = cost(1).and_then(cost);
+    /// assert_eq!(doubled_twice.run_writer(), (4, Sum(3)));
+    /// ```
+    pub fn and_then<B, F>(self, f: F) -> Writer<W, B>
+    where
+        W: Semigroup,
+        F: FnOnce(A) -> Writer<W, B>,
+    {
+        let next = f(self.value);
+        Writer::new(next.value, self.log.combine(next.log))
+    }
+
+    /// Splits the writer into its value and its log.
+    pub fn run_writer(self) -> (A, W) {
+        (self.value, self.log)
+    }
+}
+
+impl<W> Writer<W, ()> {
+    /// Creates a writer which writes to the log, and produces no value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fp_std::monoid::Concat;
+    /// # use fp_std::writer::Writer;
+    /// let greeting = Writer::tell(Concat("hello, ".to_owned()))
+    ///     .and_then(|_| Writer::tell(Concat("world".to_owned())));
+    /// assert_eq!(greeting.log, Concat("hello, world".to_owned()));
+    /// ```
+    pub const fn tell(log: W) -> Self {
+        Writer::new((), log)
+    }
+}